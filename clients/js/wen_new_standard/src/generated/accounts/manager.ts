@@ -15,10 +15,16 @@ import {
   fetchEncodedAccounts,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -33,13 +39,38 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 
-export type Manager = { discriminator: ReadonlyUint8Array };
+export type Manager = {
+  discriminator: ReadonlyUint8Array;
+  /** authority that can update the protocol config */
+  admin: Address;
+  /** cut of every royalty payment routed to the treasury, in basis points of the royalty amount */
+  protocolFeeBasisPoints: number;
+  /** receiver of the protocol fees */
+  treasury: Address;
+  /** state-changing instructions are rejected while paused, users can still exit */
+  paused: boolean;
+};
 
-export type ManagerArgs = {};
+export type ManagerArgs = {
+  /** authority that can update the protocol config */
+  admin: Address;
+  /** cut of every royalty payment routed to the treasury, in basis points of the royalty amount */
+  protocolFeeBasisPoints: number;
+  /** receiver of the protocol fees */
+  treasury: Address;
+  /** state-changing instructions are rejected while paused, users can still exit */
+  paused: boolean;
+};
 
 export function getManagerEncoder(): Encoder<ManagerArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['admin', getAddressEncoder()],
+      ['protocolFeeBasisPoints', getU16Encoder()],
+      ['treasury', getAddressEncoder()],
+      ['paused', getBooleanEncoder()],
+    ]),
     (value) => ({
       ...value,
       discriminator: new Uint8Array([221, 78, 171, 233, 213, 142, 113, 56]),
@@ -50,6 +81,10 @@ export function getManagerEncoder(): Encoder<ManagerArgs> {
 export function getManagerDecoder(): Decoder<Manager> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['admin', getAddressDecoder()],
+    ['protocolFeeBasisPoints', getU16Decoder()],
+    ['treasury', getAddressDecoder()],
+    ['paused', getBooleanDecoder()],
   ]);
 }
 
@@ -111,5 +146,5 @@ export async function fetchAllMaybeManager(
 }
 
 export function getManagerSize(): number {
-  return 8;
+  return 75;
 }
@@ -17,6 +17,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -48,8 +50,11 @@ export type TokenGroup = {
   mint: Address;
   /** The current number of group members */
   size: number;
-  /** The maximum number of group members */
+  /** The maximum number of group members, `u32::MAX` for unbounded groups */
   maxSize: number;
+  /** Whether the max size is locked for good */
+  maxSizeLocked: boolean;
+  advisoryMemberUpdateGate: boolean;
 };
 
 export type TokenGroupArgs = {
@@ -62,8 +67,11 @@ export type TokenGroupArgs = {
   mint: Address;
   /** The current number of group members */
   size: number;
-  /** The maximum number of group members */
+  /** The maximum number of group members, `u32::MAX` for unbounded groups */
   maxSize: number;
+  /** Whether the max size is locked for good */
+  maxSizeLocked: boolean;
+  advisoryMemberUpdateGate: boolean;
 };
 
 export function getTokenGroupEncoder(): Encoder<TokenGroupArgs> {
@@ -74,6 +82,8 @@ export function getTokenGroupEncoder(): Encoder<TokenGroupArgs> {
       ['mint', getAddressEncoder()],
       ['size', getU32Encoder()],
       ['maxSize', getU32Encoder()],
+      ['maxSizeLocked', getBooleanEncoder()],
+      ['advisoryMemberUpdateGate', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -89,6 +99,8 @@ export function getTokenGroupDecoder(): Decoder<TokenGroup> {
     ['mint', getAddressDecoder()],
     ['size', getU32Decoder()],
     ['maxSize', getU32Decoder()],
+    ['maxSizeLocked', getBooleanDecoder()],
+    ['advisoryMemberUpdateGate', getBooleanDecoder()],
   ]);
 }
 
@@ -150,5 +162,5 @@ export async function fetchAllMaybeTokenGroup(
 }
 
 export function getTokenGroupSize(): number {
-  return 80;
+  return 82;
 }
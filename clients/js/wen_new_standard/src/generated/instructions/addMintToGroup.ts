@@ -47,6 +47,9 @@ export type AddMintToGroupInstruction<
   TAccountGroup extends string | IAccountMeta<string> = string,
   TAccountMember extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountSubGroup extends string | IAccountMeta<string> = string,
+  TAccountAssetAuthority extends string | IAccountMeta<string> = string,
+  TAccountAssetTokenAccount extends string | IAccountMeta<string> = string,
   TAccountManager extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -76,6 +79,16 @@ export type AddMintToGroupInstruction<
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountSubGroup extends string
+        ? ReadonlyAccount<TAccountSubGroup>
+        : TAccountSubGroup,
+      TAccountAssetAuthority extends string
+        ? ReadonlySignerAccount<TAccountAssetAuthority> &
+            IAccountSignerMeta<TAccountAssetAuthority>
+        : TAccountAssetAuthority,
+      TAccountAssetTokenAccount extends string
+        ? ReadonlyAccount<TAccountAssetTokenAccount>
+        : TAccountAssetTokenAccount,
       TAccountManager extends string
         ? ReadonlyAccount<TAccountManager>
         : TAccountManager,
@@ -127,6 +140,9 @@ export type AddMintToGroupAsyncInput<
   TAccountGroup extends string = string,
   TAccountMember extends string = string,
   TAccountMint extends string = string,
+  TAccountSubGroup extends string = string,
+  TAccountAssetAuthority extends string = string,
+  TAccountAssetTokenAccount extends string = string,
   TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
@@ -136,6 +152,9 @@ export type AddMintToGroupAsyncInput<
   group: Address<TAccountGroup>;
   member?: Address<TAccountMember>;
   mint: Address<TAccountMint>;
+  subGroup?: Address<TAccountSubGroup>;
+  assetAuthority: TransactionSigner<TAccountAssetAuthority>;
+  assetTokenAccount?: Address<TAccountAssetTokenAccount>;
   manager?: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -147,6 +166,9 @@ export async function getAddMintToGroupInstructionAsync<
   TAccountGroup extends string,
   TAccountMember extends string,
   TAccountMint extends string,
+  TAccountSubGroup extends string,
+  TAccountAssetAuthority extends string,
+  TAccountAssetTokenAccount extends string,
   TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
@@ -157,6 +179,9 @@ export async function getAddMintToGroupInstructionAsync<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -169,6 +194,9 @@ export async function getAddMintToGroupInstructionAsync<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -184,6 +212,12 @@ export async function getAddMintToGroupInstructionAsync<
     group: { value: input.group ?? null, isWritable: true },
     member: { value: input.member ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
+    assetAuthority: { value: input.assetAuthority ?? null, isWritable: false },
+    assetTokenAccount: {
+      value: input.assetTokenAccount ?? null,
+      isWritable: false,
+    },
     manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -230,6 +264,9 @@ export async function getAddMintToGroupInstructionAsync<
       getAccountMeta(accounts.group),
       getAccountMeta(accounts.member),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.subGroup),
+      getAccountMeta(accounts.assetAuthority),
+      getAccountMeta(accounts.assetTokenAccount),
       getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -243,6 +280,9 @@ export async function getAddMintToGroupInstructionAsync<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -257,6 +297,9 @@ export type AddMintToGroupInput<
   TAccountGroup extends string = string,
   TAccountMember extends string = string,
   TAccountMint extends string = string,
+  TAccountSubGroup extends string = string,
+  TAccountAssetAuthority extends string = string,
+  TAccountAssetTokenAccount extends string = string,
   TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
@@ -266,6 +309,9 @@ export type AddMintToGroupInput<
   group: Address<TAccountGroup>;
   member: Address<TAccountMember>;
   mint: Address<TAccountMint>;
+  subGroup?: Address<TAccountSubGroup>;
+  assetAuthority: TransactionSigner<TAccountAssetAuthority>;
+  assetTokenAccount?: Address<TAccountAssetTokenAccount>;
   manager: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -277,6 +323,9 @@ export function getAddMintToGroupInstruction<
   TAccountGroup extends string,
   TAccountMember extends string,
   TAccountMint extends string,
+  TAccountSubGroup extends string,
+  TAccountAssetAuthority extends string,
+  TAccountAssetTokenAccount extends string,
   TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
@@ -287,6 +336,9 @@ export function getAddMintToGroupInstruction<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -298,6 +350,9 @@ export function getAddMintToGroupInstruction<
   TAccountGroup,
   TAccountMember,
   TAccountMint,
+  TAccountSubGroup,
+  TAccountAssetAuthority,
+  TAccountAssetTokenAccount,
   TAccountManager,
   TAccountSystemProgram,
   TAccountTokenProgram
@@ -312,6 +367,12 @@ export function getAddMintToGroupInstruction<
     group: { value: input.group ?? null, isWritable: true },
     member: { value: input.member ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
+    assetAuthority: { value: input.assetAuthority ?? null, isWritable: false },
+    assetTokenAccount: {
+      value: input.assetTokenAccount ?? null,
+      isWritable: false,
+    },
     manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -339,6 +400,9 @@ export function getAddMintToGroupInstruction<
       getAccountMeta(accounts.group),
       getAccountMeta(accounts.member),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.subGroup),
+      getAccountMeta(accounts.assetAuthority),
+      getAccountMeta(accounts.assetTokenAccount),
       getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -352,6 +416,9 @@ export function getAddMintToGroupInstruction<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -371,9 +438,12 @@ export type ParsedAddMintToGroupInstruction<
     group: TAccountMetas[2];
     member: TAccountMetas[3];
     mint: TAccountMetas[4];
-    manager: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
+    subGroup?: TAccountMetas[5] | undefined;
+    assetAuthority: TAccountMetas[6];
+    assetTokenAccount?: TAccountMetas[7] | undefined;
+    manager: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
   };
   data: AddMintToGroupInstructionData;
 };
@@ -386,7 +456,7 @@ export function parseAddMintToGroupInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedAddMintToGroupInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -396,6 +466,12 @@ export function parseAddMintToGroupInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === WEN_NEW_STANDARD_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -404,6 +480,9 @@ export function parseAddMintToGroupInstruction<
       group: getNextAccount(),
       member: getNextAccount(),
       mint: getNextAccount(),
+      subGroup: getNextOptionalAccount(),
+      assetAuthority: getNextAccount(),
+      assetTokenAccount: getNextOptionalAccount(),
       manager: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
@@ -52,6 +52,7 @@ export type AddRoyaltiesInstruction<
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountExtraMetasAccount extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -77,6 +78,9 @@ export type AddRoyaltiesInstruction<
       TAccountExtraMetasAccount extends string
         ? WritableAccount<TAccountExtraMetasAccount>
         : TAccountExtraMetasAccount,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -129,6 +133,7 @@ export type AddRoyaltiesAsyncInput<
   TAccountAuthority extends string = string,
   TAccountMint extends string = string,
   TAccountExtraMetasAccount extends string = string,
+  TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
@@ -136,6 +141,7 @@ export type AddRoyaltiesAsyncInput<
   authority: TransactionSigner<TAccountAuthority>;
   mint: Address<TAccountMint>;
   extraMetasAccount?: Address<TAccountExtraMetasAccount>;
+  manager?: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   args: AddRoyaltiesInstructionDataArgs['args'];
@@ -146,6 +152,7 @@ export async function getAddRoyaltiesInstructionAsync<
   TAccountAuthority extends string,
   TAccountMint extends string,
   TAccountExtraMetasAccount extends string,
+  TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
 >(
@@ -154,6 +161,7 @@ export async function getAddRoyaltiesInstructionAsync<
     TAccountAuthority,
     TAccountMint,
     TAccountExtraMetasAccount,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -164,6 +172,7 @@ export async function getAddRoyaltiesInstructionAsync<
     TAccountAuthority,
     TAccountMint,
     TAccountExtraMetasAccount,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -180,6 +189,7 @@ export async function getAddRoyaltiesInstructionAsync<
       value: input.extraMetasAccount ?? null,
       isWritable: true,
     },
+    manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -206,6 +216,16 @@ export async function getAddRoyaltiesInstructionAsync<
       ],
     });
   }
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -222,6 +242,7 @@ export async function getAddRoyaltiesInstructionAsync<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.extraMetasAccount),
+      getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
     ],
@@ -235,6 +256,7 @@ export async function getAddRoyaltiesInstructionAsync<
     TAccountAuthority,
     TAccountMint,
     TAccountExtraMetasAccount,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >;
@@ -247,6 +269,7 @@ export type AddRoyaltiesInput<
   TAccountAuthority extends string = string,
   TAccountMint extends string = string,
   TAccountExtraMetasAccount extends string = string,
+  TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
@@ -254,6 +277,7 @@ export type AddRoyaltiesInput<
   authority: TransactionSigner<TAccountAuthority>;
   mint: Address<TAccountMint>;
   extraMetasAccount: Address<TAccountExtraMetasAccount>;
+  manager: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   args: AddRoyaltiesInstructionDataArgs['args'];
@@ -264,6 +288,7 @@ export function getAddRoyaltiesInstruction<
   TAccountAuthority extends string,
   TAccountMint extends string,
   TAccountExtraMetasAccount extends string,
+  TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
 >(
@@ -272,6 +297,7 @@ export function getAddRoyaltiesInstruction<
     TAccountAuthority,
     TAccountMint,
     TAccountExtraMetasAccount,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -281,6 +307,7 @@ export function getAddRoyaltiesInstruction<
   TAccountAuthority,
   TAccountMint,
   TAccountExtraMetasAccount,
+  TAccountManager,
   TAccountSystemProgram,
   TAccountTokenProgram
 > {
@@ -296,6 +323,7 @@ export function getAddRoyaltiesInstruction<
       value: input.extraMetasAccount ?? null,
       isWritable: true,
     },
+    manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -324,6 +352,7 @@ export function getAddRoyaltiesInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.extraMetasAccount),
+      getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
     ],
@@ -337,6 +366,7 @@ export function getAddRoyaltiesInstruction<
     TAccountAuthority,
     TAccountMint,
     TAccountExtraMetasAccount,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >;
@@ -354,8 +384,9 @@ export type ParsedAddRoyaltiesInstruction<
     authority: TAccountMetas[1];
     mint: TAccountMetas[2];
     extraMetasAccount: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
-    tokenProgram: TAccountMetas[5];
+    manager: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+    tokenProgram: TAccountMetas[6];
   };
   data: AddRoyaltiesInstructionData;
 };
@@ -368,7 +399,7 @@ export function parseAddRoyaltiesInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedAddRoyaltiesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -385,6 +416,7 @@ export function parseAddRoyaltiesInstruction<
       authority: getNextAccount(),
       mint: getNextAccount(),
       extraMetasAccount: getNextAccount(),
+      manager: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
@@ -18,6 +18,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -113,15 +115,20 @@ export type ApproveTransferInstruction<
 export type ApproveTransferInstructionData = {
   discriminator: ReadonlyUint8Array;
   buyAmount: bigint;
+  groupPathLen: number;
 };
 
-export type ApproveTransferInstructionDataArgs = { buyAmount: number | bigint };
+export type ApproveTransferInstructionDataArgs = {
+  buyAmount: number | bigint;
+  groupPathLen: number;
+};
 
 export function getApproveTransferInstructionDataEncoder(): Encoder<ApproveTransferInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['buyAmount', getU64Encoder()],
+      ['groupPathLen', getU8Encoder()],
     ]),
     (value) => ({
       ...value,
@@ -134,6 +141,7 @@ export function getApproveTransferInstructionDataDecoder(): Decoder<ApproveTrans
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['buyAmount', getU64Decoder()],
+    ['groupPathLen', getU8Decoder()],
   ]);
 }
 
@@ -174,6 +182,7 @@ export type ApproveTransferAsyncInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   paymentTokenProgram?: Address<TAccountPaymentTokenProgram>;
   buyAmount: ApproveTransferInstructionDataArgs['buyAmount'];
+  groupPathLen: ApproveTransferInstructionDataArgs['groupPathLen'];
 };
 
 export async function getApproveTransferInstructionAsync<
@@ -356,6 +365,7 @@ export type ApproveTransferInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   paymentTokenProgram?: Address<TAccountPaymentTokenProgram>;
   buyAmount: ApproveTransferInstructionDataArgs['buyAmount'];
+  groupPathLen: ApproveTransferInstructionDataArgs['groupPathLen'];
 };
 
 export function getApproveTransferInstruction<
@@ -47,6 +47,12 @@ export type BurnMintAccountInstruction<
   TAccountMint extends string | IAccountMeta<string> = string,
   TAccountMintTokenAccount extends string | IAccountMeta<string> = string,
   TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountGroup extends string | IAccountMeta<string> = string,
+  TAccountMember extends string | IAccountMeta<string> = string,
+  TAccountSubGroup extends string | IAccountMeta<string> = string,
+  TAccountApproveAccount extends string | IAccountMeta<string> = string,
+  TAccountExtraMetasAccount extends string | IAccountMeta<string> = string,
+  TAccountRoyaltyConfig extends string | IAccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | IAccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
@@ -60,7 +66,8 @@ export type BurnMintAccountInstruction<
             IAccountSignerMeta<TAccountPayer>
         : TAccountPayer,
       TAccountUser extends string
-        ? ReadonlySignerAccount<TAccountUser> & IAccountSignerMeta<TAccountUser>
+        ? ReadonlySignerAccount<TAccountUser> &
+            IAccountSignerMeta<TAccountUser>
         : TAccountUser,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
@@ -71,6 +78,24 @@ export type BurnMintAccountInstruction<
       TAccountManager extends string
         ? ReadonlyAccount<TAccountManager>
         : TAccountManager,
+      TAccountGroup extends string
+        ? WritableAccount<TAccountGroup>
+        : TAccountGroup,
+      TAccountMember extends string
+        ? WritableAccount<TAccountMember>
+        : TAccountMember,
+      TAccountSubGroup extends string
+        ? ReadonlyAccount<TAccountSubGroup>
+        : TAccountSubGroup,
+      TAccountApproveAccount extends string
+        ? WritableAccount<TAccountApproveAccount>
+        : TAccountApproveAccount,
+      TAccountExtraMetasAccount extends string
+        ? WritableAccount<TAccountExtraMetasAccount>
+        : TAccountExtraMetasAccount,
+      TAccountRoyaltyConfig extends string
+        ? WritableAccount<TAccountRoyaltyConfig>
+        : TAccountRoyaltyConfig,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -116,6 +141,12 @@ export type BurnMintAccountAsyncInput<
   TAccountMint extends string = string,
   TAccountMintTokenAccount extends string = string,
   TAccountManager extends string = string,
+  TAccountGroup extends string = string,
+  TAccountMember extends string = string,
+  TAccountSubGroup extends string = string,
+  TAccountApproveAccount extends string = string,
+  TAccountExtraMetasAccount extends string = string,
+  TAccountRoyaltyConfig extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
@@ -123,6 +154,12 @@ export type BurnMintAccountAsyncInput<
   mint: Address<TAccountMint>;
   mintTokenAccount?: Address<TAccountMintTokenAccount>;
   manager?: Address<TAccountManager>;
+  group?: Address<TAccountGroup>;
+  member?: Address<TAccountMember>;
+  subGroup?: Address<TAccountSubGroup>;
+  approveAccount?: Address<TAccountApproveAccount>;
+  extraMetasAccount?: Address<TAccountExtraMetasAccount>;
+  royaltyConfig?: Address<TAccountRoyaltyConfig>;
   tokenProgram?: Address<TAccountTokenProgram>;
 };
 
@@ -132,6 +169,12 @@ export async function getBurnMintAccountInstructionAsync<
   TAccountMint extends string,
   TAccountMintTokenAccount extends string,
   TAccountManager extends string,
+  TAccountGroup extends string,
+  TAccountMember extends string,
+  TAccountSubGroup extends string,
+  TAccountApproveAccount extends string,
+  TAccountExtraMetasAccount extends string,
+  TAccountRoyaltyConfig extends string,
   TAccountTokenProgram extends string,
 >(
   input: BurnMintAccountAsyncInput<
@@ -140,6 +183,12 @@ export async function getBurnMintAccountInstructionAsync<
     TAccountMint,
     TAccountMintTokenAccount,
     TAccountManager,
+    TAccountGroup,
+    TAccountMember,
+    TAccountSubGroup,
+    TAccountApproveAccount,
+    TAccountExtraMetasAccount,
+    TAccountRoyaltyConfig,
     TAccountTokenProgram
   >
 ): Promise<
@@ -150,6 +199,12 @@ export async function getBurnMintAccountInstructionAsync<
     TAccountMint,
     TAccountMintTokenAccount,
     TAccountManager,
+    TAccountGroup,
+    TAccountMember,
+    TAccountSubGroup,
+    TAccountApproveAccount,
+    TAccountExtraMetasAccount,
+    TAccountRoyaltyConfig,
     TAccountTokenProgram
   >
 > {
@@ -166,6 +221,15 @@ export async function getBurnMintAccountInstructionAsync<
       isWritable: true,
     },
     manager: { value: input.manager ?? null, isWritable: false },
+    group: { value: input.group ?? null, isWritable: true },
+    member: { value: input.member ?? null, isWritable: true },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
+    approveAccount: { value: input.approveAccount ?? null, isWritable: true },
+    extraMetasAccount: {
+      value: input.extraMetasAccount ?? null,
+      isWritable: true,
+    },
+    royaltyConfig: { value: input.royaltyConfig ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -174,10 +238,6 @@ export async function getBurnMintAccountInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.tokenProgram.value) {
-    accounts.tokenProgram.value =
-      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
-  }
   if (!accounts.mintTokenAccount.value) {
     accounts.mintTokenAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -198,6 +258,43 @@ export async function getBurnMintAccountInstructionAsync<
       ],
     });
   }
+  if (!accounts.approveAccount.value) {
+    accounts.approveAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([97, 112, 112, 114, 111, 118, 101, 45, 97, 99, 99, 111, 117, 110, 116])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.extraMetasAccount.value) {
+    accounts.extraMetasAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([101, 120, 116, 114, 97, 45, 97, 99, 99, 111, 117, 110, 116, 45, 109, 101, 116, 97, 115])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.royaltyConfig.value) {
+    accounts.royaltyConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 111, 121, 97, 108, 116, 121, 45, 99, 111, 110, 102, 105, 103])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
@@ -207,6 +304,12 @@ export async function getBurnMintAccountInstructionAsync<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.mintTokenAccount),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.group),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.subGroup),
+      getAccountMeta(accounts.approveAccount),
+      getAccountMeta(accounts.extraMetasAccount),
+      getAccountMeta(accounts.royaltyConfig),
       getAccountMeta(accounts.tokenProgram),
     ],
     programAddress,
@@ -218,6 +321,12 @@ export async function getBurnMintAccountInstructionAsync<
     TAccountMint,
     TAccountMintTokenAccount,
     TAccountManager,
+    TAccountGroup,
+    TAccountMember,
+    TAccountSubGroup,
+    TAccountApproveAccount,
+    TAccountExtraMetasAccount,
+    TAccountRoyaltyConfig,
     TAccountTokenProgram
   >;
 
@@ -230,6 +339,12 @@ export type BurnMintAccountInput<
   TAccountMint extends string = string,
   TAccountMintTokenAccount extends string = string,
   TAccountManager extends string = string,
+  TAccountGroup extends string = string,
+  TAccountMember extends string = string,
+  TAccountSubGroup extends string = string,
+  TAccountApproveAccount extends string = string,
+  TAccountExtraMetasAccount extends string = string,
+  TAccountRoyaltyConfig extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
@@ -237,6 +352,12 @@ export type BurnMintAccountInput<
   mint: Address<TAccountMint>;
   mintTokenAccount: Address<TAccountMintTokenAccount>;
   manager: Address<TAccountManager>;
+  group?: Address<TAccountGroup>;
+  member?: Address<TAccountMember>;
+  subGroup?: Address<TAccountSubGroup>;
+  approveAccount: Address<TAccountApproveAccount>;
+  extraMetasAccount: Address<TAccountExtraMetasAccount>;
+  royaltyConfig: Address<TAccountRoyaltyConfig>;
   tokenProgram?: Address<TAccountTokenProgram>;
 };
 
@@ -246,6 +367,12 @@ export function getBurnMintAccountInstruction<
   TAccountMint extends string,
   TAccountMintTokenAccount extends string,
   TAccountManager extends string,
+  TAccountGroup extends string,
+  TAccountMember extends string,
+  TAccountSubGroup extends string,
+  TAccountApproveAccount extends string,
+  TAccountExtraMetasAccount extends string,
+  TAccountRoyaltyConfig extends string,
   TAccountTokenProgram extends string,
 >(
   input: BurnMintAccountInput<
@@ -254,6 +381,12 @@ export function getBurnMintAccountInstruction<
     TAccountMint,
     TAccountMintTokenAccount,
     TAccountManager,
+    TAccountGroup,
+    TAccountMember,
+    TAccountSubGroup,
+    TAccountApproveAccount,
+    TAccountExtraMetasAccount,
+    TAccountRoyaltyConfig,
     TAccountTokenProgram
   >
 ): BurnMintAccountInstruction<
@@ -263,6 +396,12 @@ export function getBurnMintAccountInstruction<
   TAccountMint,
   TAccountMintTokenAccount,
   TAccountManager,
+  TAccountGroup,
+  TAccountMember,
+  TAccountSubGroup,
+  TAccountApproveAccount,
+  TAccountExtraMetasAccount,
+  TAccountRoyaltyConfig,
   TAccountTokenProgram
 > {
   // Program address.
@@ -278,6 +417,15 @@ export function getBurnMintAccountInstruction<
       isWritable: true,
     },
     manager: { value: input.manager ?? null, isWritable: false },
+    group: { value: input.group ?? null, isWritable: true },
+    member: { value: input.member ?? null, isWritable: true },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
+    approveAccount: { value: input.approveAccount ?? null, isWritable: true },
+    extraMetasAccount: {
+      value: input.extraMetasAccount ?? null,
+      isWritable: true,
+    },
+    royaltyConfig: { value: input.royaltyConfig ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -299,6 +447,12 @@ export function getBurnMintAccountInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.mintTokenAccount),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.group),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.subGroup),
+      getAccountMeta(accounts.approveAccount),
+      getAccountMeta(accounts.extraMetasAccount),
+      getAccountMeta(accounts.royaltyConfig),
       getAccountMeta(accounts.tokenProgram),
     ],
     programAddress,
@@ -310,6 +464,12 @@ export function getBurnMintAccountInstruction<
     TAccountMint,
     TAccountMintTokenAccount,
     TAccountManager,
+    TAccountGroup,
+    TAccountMember,
+    TAccountSubGroup,
+    TAccountApproveAccount,
+    TAccountExtraMetasAccount,
+    TAccountRoyaltyConfig,
     TAccountTokenProgram
   >;
 
@@ -327,7 +487,13 @@ export type ParsedBurnMintAccountInstruction<
     mint: TAccountMetas[2];
     mintTokenAccount: TAccountMetas[3];
     manager: TAccountMetas[4];
-    tokenProgram: TAccountMetas[5];
+    group?: TAccountMetas[5] | undefined;
+    member?: TAccountMetas[6] | undefined;
+    subGroup?: TAccountMetas[7] | undefined;
+    approveAccount: TAccountMetas[8];
+    extraMetasAccount: TAccountMetas[9];
+    royaltyConfig: TAccountMetas[10];
+    tokenProgram: TAccountMetas[11];
   };
   data: BurnMintAccountInstructionData;
 };
@@ -340,7 +506,7 @@ export function parseBurnMintAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedBurnMintAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -350,6 +516,12 @@ export function parseBurnMintAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === WEN_NEW_STANDARD_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -358,6 +530,12 @@ export function parseBurnMintAccountInstruction<
       mint: getNextAccount(),
       mintTokenAccount: getNextAccount(),
       manager: getNextAccount(),
+      group: getNextOptionalAccount(),
+      member: getNextOptionalAccount(),
+      subGroup: getNextOptionalAccount(),
+      approveAccount: getNextAccount(),
+      extraMetasAccount: getNextAccount(),
+      royaltyConfig: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
     data: getBurnMintAccountInstructionDataDecoder().decode(instruction.data),
@@ -0,0 +1,543 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getProgramDerivedAddress,
+  getStructDecoder,
+  getStructEncoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { WEN_NEW_STANDARD_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export type ClawbackInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountTransferHookAuthority extends string | IAccountMeta<string> = string,
+  TAccountUser extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountMintTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountRecipientTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountAssociatedTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountTransferHookAuthority extends string
+        ? ReadonlySignerAccount<TAccountTransferHookAuthority> &
+            IAccountSignerMeta<TAccountTransferHookAuthority>
+        : TAccountTransferHookAuthority,
+      TAccountUser extends string
+        ? ReadonlyAccount<TAccountUser>
+        : TAccountUser,
+      TAccountRecipient extends string
+        ? ReadonlyAccount<TAccountRecipient>
+        : TAccountRecipient,
+      TAccountMint extends string
+        ? WritableAccount<TAccountMint>
+        : TAccountMint,
+      TAccountMintTokenAccount extends string
+        ? WritableAccount<TAccountMintTokenAccount>
+        : TAccountMintTokenAccount,
+      TAccountRecipientTokenAccount extends string
+        ? WritableAccount<TAccountRecipientTokenAccount>
+        : TAccountRecipientTokenAccount,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type ClawbackInstructionData = {
+  discriminator: ReadonlyUint8Array;
+};
+
+export type ClawbackInstructionDataArgs = {};
+
+export function getClawbackInstructionDataEncoder(): Encoder<ClawbackInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([111, 92, 142, 79, 33, 234, 82, 27]),
+    })
+  );
+}
+
+export function getClawbackInstructionDataDecoder(): Decoder<ClawbackInstructionData> {
+  return getStructDecoder([
+    ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+  ]);
+}
+
+export function getClawbackInstructionDataCodec(): Codec<
+  ClawbackInstructionDataArgs,
+  ClawbackInstructionData
+> {
+  return combineCodec(
+    getClawbackInstructionDataEncoder(),
+    getClawbackInstructionDataDecoder()
+  );
+}
+
+export type ClawbackAsyncInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountTransferHookAuthority extends string = string,
+  TAccountUser extends string = string,
+  TAccountRecipient extends string = string,
+  TAccountMint extends string = string,
+  TAccountMintTokenAccount extends string = string,
+  TAccountRecipientTokenAccount extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  transferHookAuthority?: TransactionSigner<TAccountTransferHookAuthority>;
+  user: Address<TAccountUser>;
+  recipient: Address<TAccountRecipient>;
+  mint: Address<TAccountMint>;
+  mintTokenAccount: Address<TAccountMintTokenAccount>;
+  recipientTokenAccount?: Address<TAccountRecipientTokenAccount>;
+  manager?: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export async function getClawbackInstructionAsync<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountTransferHookAuthority extends string,
+  TAccountUser extends string,
+  TAccountRecipient extends string,
+  TAccountMint extends string,
+  TAccountMintTokenAccount extends string,
+  TAccountRecipientTokenAccount extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ClawbackAsyncInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountTransferHookAuthority,
+    TAccountUser,
+    TAccountRecipient,
+    TAccountMint,
+    TAccountMintTokenAccount,
+    TAccountRecipientTokenAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram
+  >
+): Promise<
+  ClawbackInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountTransferHookAuthority,
+    TAccountUser,
+    TAccountRecipient,
+    TAccountMint,
+    TAccountMintTokenAccount,
+    TAccountRecipientTokenAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    transferHookAuthority: {
+      value: input.transferHookAuthority ?? null,
+      isWritable: false,
+    },
+    user: { value: input.user ?? null, isWritable: false },
+    recipient: { value: input.recipient ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    mintTokenAccount: {
+      value: input.mintTokenAccount ?? null,
+      isWritable: true,
+    },
+    recipientTokenAccount: {
+      value: input.recipientTokenAccount ?? null,
+      isWritable: true,
+    },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.recipientTokenAccount.value) {
+    accounts.recipientTokenAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.recipient.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.transferHookAuthority),
+      getAccountMeta(accounts.user),
+      getAccountMeta(accounts.recipient),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.mintTokenAccount),
+      getAccountMeta(accounts.recipientTokenAccount),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getClawbackInstructionDataEncoder().encode({}),
+  } as ClawbackInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountTransferHookAuthority,
+    TAccountUser,
+    TAccountRecipient,
+    TAccountMint,
+    TAccountMintTokenAccount,
+    TAccountRecipientTokenAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ClawbackInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountTransferHookAuthority extends string = string,
+  TAccountUser extends string = string,
+  TAccountRecipient extends string = string,
+  TAccountMint extends string = string,
+  TAccountMintTokenAccount extends string = string,
+  TAccountRecipientTokenAccount extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  transferHookAuthority?: TransactionSigner<TAccountTransferHookAuthority>;
+  user: Address<TAccountUser>;
+  recipient: Address<TAccountRecipient>;
+  mint: Address<TAccountMint>;
+  mintTokenAccount: Address<TAccountMintTokenAccount>;
+  recipientTokenAccount: Address<TAccountRecipientTokenAccount>;
+  manager: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export function getClawbackInstruction<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountTransferHookAuthority extends string,
+  TAccountUser extends string,
+  TAccountRecipient extends string,
+  TAccountMint extends string,
+  TAccountMintTokenAccount extends string,
+  TAccountRecipientTokenAccount extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ClawbackInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountTransferHookAuthority,
+    TAccountUser,
+    TAccountRecipient,
+    TAccountMint,
+    TAccountMintTokenAccount,
+    TAccountRecipientTokenAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram
+  >
+): ClawbackInstruction<
+  typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountTransferHookAuthority,
+  TAccountUser,
+  TAccountRecipient,
+  TAccountMint,
+  TAccountMintTokenAccount,
+  TAccountRecipientTokenAccount,
+  TAccountManager,
+  TAccountSystemProgram,
+  TAccountAssociatedTokenProgram,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    transferHookAuthority: {
+      value: input.transferHookAuthority ?? null,
+      isWritable: false,
+    },
+    user: { value: input.user ?? null, isWritable: false },
+    recipient: { value: input.recipient ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    mintTokenAccount: {
+      value: input.mintTokenAccount ?? null,
+      isWritable: true,
+    },
+    recipientTokenAccount: {
+      value: input.recipientTokenAccount ?? null,
+      isWritable: true,
+    },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.associatedTokenProgram.value) {
+    accounts.associatedTokenProgram.value =
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.transferHookAuthority),
+      getAccountMeta(accounts.user),
+      getAccountMeta(accounts.recipient),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.mintTokenAccount),
+      getAccountMeta(accounts.recipientTokenAccount),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getClawbackInstructionDataEncoder().encode({}),
+  } as ClawbackInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountTransferHookAuthority,
+    TAccountUser,
+    TAccountRecipient,
+    TAccountMint,
+    TAccountMintTokenAccount,
+    TAccountRecipientTokenAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedClawbackInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    payer: TAccountMetas[0];
+    authority: TAccountMetas[1];
+    transferHookAuthority?: TAccountMetas[2] | undefined;
+    user: TAccountMetas[3];
+    recipient: TAccountMetas[4];
+    mint: TAccountMetas[5];
+    mintTokenAccount: TAccountMetas[6];
+    recipientTokenAccount: TAccountMetas[7];
+    manager: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+    associatedTokenProgram: TAccountMetas[10];
+    tokenProgram: TAccountMetas[11];
+  };
+  data: ClawbackInstructionData;
+};
+
+export function parseClawbackInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedClawbackInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 12) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === WEN_NEW_STANDARD_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      payer: getNextAccount(),
+      authority: getNextAccount(),
+      transferHookAuthority: getNextOptionalAccount(),
+      user: getNextAccount(),
+      recipient: getNextAccount(),
+      mint: getNextAccount(),
+      mintTokenAccount: getNextAccount(),
+      recipientTokenAccount: getNextAccount(),
+      manager: getNextAccount(),
+      systemProgram: getNextAccount(),
+      associatedTokenProgram: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getClawbackInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -13,8 +13,12 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -32,6 +36,8 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -110,14 +116,18 @@ export type CreateGroupAccountInstructionData = {
   name: string;
   symbol: string;
   uri: string;
-  maxSize: number;
+  maxSize: Option<number>;
+  lockMaxSize: boolean;
+  advisoryMemberUpdateGate: boolean;
 };
 
 export type CreateGroupAccountInstructionDataArgs = {
   name: string;
   symbol: string;
   uri: string;
-  maxSize: number;
+  maxSize: OptionOrNullable<number>;
+  lockMaxSize: boolean;
+  advisoryMemberUpdateGate: boolean;
 };
 
 export function getCreateGroupAccountInstructionDataEncoder(): Encoder<CreateGroupAccountInstructionDataArgs> {
@@ -127,7 +137,9 @@ export function getCreateGroupAccountInstructionDataEncoder(): Encoder<CreateGro
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
-      ['maxSize', getU32Encoder()],
+      ['maxSize', getOptionEncoder(getU32Encoder())],
+      ['lockMaxSize', getBooleanEncoder()],
+      ['advisoryMemberUpdateGate', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -142,7 +154,9 @@ export function getCreateGroupAccountInstructionDataDecoder(): Decoder<CreateGro
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['maxSize', getU32Decoder()],
+    ['maxSize', getOptionDecoder(getU32Decoder())],
+    ['lockMaxSize', getBooleanDecoder()],
+    ['advisoryMemberUpdateGate', getBooleanDecoder()],
   ]);
 }
 
@@ -182,6 +196,8 @@ export type CreateGroupAccountAsyncInput<
   symbol: CreateGroupAccountInstructionDataArgs['symbol'];
   uri: CreateGroupAccountInstructionDataArgs['uri'];
   maxSize: CreateGroupAccountInstructionDataArgs['maxSize'];
+  lockMaxSize: CreateGroupAccountInstructionDataArgs['lockMaxSize'];
+  advisoryMemberUpdateGate: CreateGroupAccountInstructionDataArgs['advisoryMemberUpdateGate'];
 };
 
 export async function getCreateGroupAccountInstructionAsync<
@@ -357,6 +373,8 @@ export type CreateGroupAccountInput<
   symbol: CreateGroupAccountInstructionDataArgs['symbol'];
   uri: CreateGroupAccountInstructionDataArgs['uri'];
   maxSize: CreateGroupAccountInstructionDataArgs['maxSize'];
+  lockMaxSize: CreateGroupAccountInstructionDataArgs['lockMaxSize'];
+  advisoryMemberUpdateGate: CreateGroupAccountInstructionDataArgs['advisoryMemberUpdateGate'];
 };
 
 export function getCreateGroupAccountInstruction<
@@ -14,6 +14,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
@@ -112,6 +114,7 @@ export type CreateMintAccountInstructionData = {
   symbol: string;
   uri: string;
   permanentDelegate: Option<Address>;
+  soulbound: boolean;
 };
 
 export type CreateMintAccountInstructionDataArgs = {
@@ -119,6 +122,7 @@ export type CreateMintAccountInstructionDataArgs = {
   symbol: string;
   uri: string;
   permanentDelegate: OptionOrNullable<Address>;
+  soulbound: boolean;
 };
 
 export function getCreateMintAccountInstructionDataEncoder(): Encoder<CreateMintAccountInstructionDataArgs> {
@@ -129,6 +133,7 @@ export function getCreateMintAccountInstructionDataEncoder(): Encoder<CreateMint
       ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['permanentDelegate', getOptionEncoder(getAddressEncoder())],
+      ['soulbound', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -144,6 +149,7 @@ export function getCreateMintAccountInstructionDataDecoder(): Decoder<CreateMint
     ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['permanentDelegate', getOptionDecoder(getAddressDecoder())],
+    ['soulbound', getBooleanDecoder()],
   ]);
 }
 
@@ -181,6 +187,7 @@ export type CreateMintAccountAsyncInput<
   symbol: CreateMintAccountInstructionDataArgs['symbol'];
   uri: CreateMintAccountInstructionDataArgs['uri'];
   permanentDelegate: CreateMintAccountInstructionDataArgs['permanentDelegate'];
+  soulbound: CreateMintAccountInstructionDataArgs['soulbound'];
 };
 
 export async function getCreateMintAccountInstructionAsync<
@@ -339,6 +346,7 @@ export type CreateMintAccountInput<
   symbol: CreateMintAccountInstructionDataArgs['symbol'];
   uri: CreateMintAccountInstructionDataArgs['uri'];
   permanentDelegate: CreateMintAccountInstructionDataArgs['permanentDelegate'];
+  soulbound: CreateMintAccountInstructionDataArgs['soulbound'];
 };
 
 export function getCreateMintAccountInstruction<
@@ -11,13 +11,17 @@ export * from './addMintToGroup';
 export * from './addRoyalties';
 export * from './approveTransfer';
 export * from './burnMintAccount';
+export * from './clawback';
 export * from './createGroupAccount';
 export * from './createMintAccount';
 export * from './execute';
 export * from './freezeMintAccount';
+export * from './inheritRoyalties';
 export * from './initManagerAccount';
 export * from './modifyRoyalties';
 export * from './removeMetadata';
 export * from './removeMintFromGroup';
 export * from './thawMintAccount';
+export * from './transferMember';
 export * from './updateGroupAccount';
+export * from './updateMintAccount';
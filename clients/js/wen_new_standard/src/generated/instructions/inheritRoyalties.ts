@@ -0,0 +1,458 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getProgramDerivedAddress,
+  getStructDecoder,
+  getStructEncoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { WEN_NEW_STANDARD_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export type InheritRoyaltiesInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountMember extends string | IAccountMeta<string> = string,
+  TAccountGroupRoyaltyConfig extends string | IAccountMeta<string> = string,
+  TAccountExtraMetasAccount extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMint extends string
+        ? WritableAccount<TAccountMint>
+        : TAccountMint,
+      TAccountMember extends string
+        ? ReadonlyAccount<TAccountMember>
+        : TAccountMember,
+      TAccountGroupRoyaltyConfig extends string
+        ? ReadonlyAccount<TAccountGroupRoyaltyConfig>
+        : TAccountGroupRoyaltyConfig,
+      TAccountExtraMetasAccount extends string
+        ? WritableAccount<TAccountExtraMetasAccount>
+        : TAccountExtraMetasAccount,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InheritRoyaltiesInstructionData = {
+  discriminator: ReadonlyUint8Array;
+};
+
+export type InheritRoyaltiesInstructionDataArgs = {};
+
+export function getInheritRoyaltiesInstructionDataEncoder(): Encoder<InheritRoyaltiesInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([42, 168, 196, 44, 255, 181, 126, 15]),
+    })
+  );
+}
+
+export function getInheritRoyaltiesInstructionDataDecoder(): Decoder<InheritRoyaltiesInstructionData> {
+  return getStructDecoder([
+    ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+  ]);
+}
+
+export function getInheritRoyaltiesInstructionDataCodec(): Codec<
+  InheritRoyaltiesInstructionDataArgs,
+  InheritRoyaltiesInstructionData
+> {
+  return combineCodec(
+    getInheritRoyaltiesInstructionDataEncoder(),
+    getInheritRoyaltiesInstructionDataDecoder()
+  );
+}
+
+export type InheritRoyaltiesAsyncInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountMember extends string = string,
+  TAccountGroupRoyaltyConfig extends string = string,
+  TAccountExtraMetasAccount extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  mint: Address<TAccountMint>;
+  member?: Address<TAccountMember>;
+  groupRoyaltyConfig: Address<TAccountGroupRoyaltyConfig>;
+  extraMetasAccount?: Address<TAccountExtraMetasAccount>;
+  manager?: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export async function getInheritRoyaltiesInstructionAsync<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountMember extends string,
+  TAccountGroupRoyaltyConfig extends string,
+  TAccountExtraMetasAccount extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: InheritRoyaltiesAsyncInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountMember,
+    TAccountGroupRoyaltyConfig,
+    TAccountExtraMetasAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): Promise<
+  InheritRoyaltiesInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountMember,
+    TAccountGroupRoyaltyConfig,
+    TAccountExtraMetasAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    member: { value: input.member ?? null, isWritable: false },
+    groupRoyaltyConfig: {
+      value: input.groupRoyaltyConfig ?? null,
+      isWritable: false,
+    },
+    extraMetasAccount: {
+      value: input.extraMetasAccount ?? null,
+      isWritable: true,
+    },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.member.value) {
+    accounts.member.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([109, 101, 109, 98, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.extraMetasAccount.value) {
+    accounts.extraMetasAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([101, 120, 116, 114, 97, 45, 97, 99, 99, 111, 117, 110, 116, 45, 109, 101, 116, 97, 115])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.groupRoyaltyConfig),
+      getAccountMeta(accounts.extraMetasAccount),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getInheritRoyaltiesInstructionDataEncoder().encode({}),
+  } as InheritRoyaltiesInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountMember,
+    TAccountGroupRoyaltyConfig,
+    TAccountExtraMetasAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type InheritRoyaltiesInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountMember extends string = string,
+  TAccountGroupRoyaltyConfig extends string = string,
+  TAccountExtraMetasAccount extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  mint: Address<TAccountMint>;
+  member: Address<TAccountMember>;
+  groupRoyaltyConfig: Address<TAccountGroupRoyaltyConfig>;
+  extraMetasAccount: Address<TAccountExtraMetasAccount>;
+  manager: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export function getInheritRoyaltiesInstruction<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountMember extends string,
+  TAccountGroupRoyaltyConfig extends string,
+  TAccountExtraMetasAccount extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: InheritRoyaltiesInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountMember,
+    TAccountGroupRoyaltyConfig,
+    TAccountExtraMetasAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): InheritRoyaltiesInstruction<
+  typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMint,
+  TAccountMember,
+  TAccountGroupRoyaltyConfig,
+  TAccountExtraMetasAccount,
+  TAccountManager,
+  TAccountSystemProgram,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    member: { value: input.member ?? null, isWritable: false },
+    groupRoyaltyConfig: {
+      value: input.groupRoyaltyConfig ?? null,
+      isWritable: false,
+    },
+    extraMetasAccount: {
+      value: input.extraMetasAccount ?? null,
+      isWritable: true,
+    },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.groupRoyaltyConfig),
+      getAccountMeta(accounts.extraMetasAccount),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getInheritRoyaltiesInstructionDataEncoder().encode({}),
+  } as InheritRoyaltiesInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountMember,
+    TAccountGroupRoyaltyConfig,
+    TAccountExtraMetasAccount,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInheritRoyaltiesInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    payer: TAccountMetas[0];
+    authority: TAccountMetas[1];
+    mint: TAccountMetas[2];
+    member: TAccountMetas[3];
+    groupRoyaltyConfig: TAccountMetas[4];
+    extraMetasAccount: TAccountMetas[5];
+    manager: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
+  };
+  data: InheritRoyaltiesInstructionData;
+};
+
+export function parseInheritRoyaltiesInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInheritRoyaltiesInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      payer: getNextAccount(),
+      authority: getNextAccount(),
+      mint: getNextAccount(),
+      member: getNextAccount(),
+      groupRoyaltyConfig: getNextAccount(),
+      extraMetasAccount: getNextAccount(),
+      manager: getNextAccount(),
+      systemProgram: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getInheritRoyaltiesInstructionDataDecoder().decode(instruction.data),
+  };
+}
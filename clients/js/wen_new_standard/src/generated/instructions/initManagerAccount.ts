@@ -26,6 +26,7 @@ import {
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -37,7 +38,12 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 export type InitManagerAccountInstruction<
   TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
   TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountUpgradeAuthority extends string | IAccountMeta<string> = string,
   TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountProgram extends
+    | string
+    | IAccountMeta<string> = 'wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM',
+  TAccountProgramData extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -50,9 +56,19 @@ export type InitManagerAccountInstruction<
         ? WritableSignerAccount<TAccountPayer> &
             IAccountSignerMeta<TAccountPayer>
         : TAccountPayer,
+      TAccountUpgradeAuthority extends string
+        ? ReadonlySignerAccount<TAccountUpgradeAuthority> &
+            IAccountSignerMeta<TAccountUpgradeAuthority>
+        : TAccountUpgradeAuthority,
       TAccountManager extends string
         ? WritableAccount<TAccountManager>
         : TAccountManager,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountProgramData extends string
+        ? ReadonlyAccount<TAccountProgramData>
+        : TAccountProgramData,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -94,29 +110,44 @@ export function getInitManagerAccountInstructionDataCodec(): Codec<
 
 export type InitManagerAccountAsyncInput<
   TAccountPayer extends string = string,
+  TAccountUpgradeAuthority extends string = string,
   TAccountManager extends string = string,
+  TAccountProgram extends string = string,
+  TAccountProgramData extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
+  upgradeAuthority: TransactionSigner<TAccountUpgradeAuthority>;
   manager?: Address<TAccountManager>;
+  program?: Address<TAccountProgram>;
+  programData: Address<TAccountProgramData>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export async function getInitManagerAccountInstructionAsync<
   TAccountPayer extends string,
+  TAccountUpgradeAuthority extends string,
   TAccountManager extends string,
+  TAccountProgram extends string,
+  TAccountProgramData extends string,
   TAccountSystemProgram extends string,
 >(
   input: InitManagerAccountAsyncInput<
     TAccountPayer,
+    TAccountUpgradeAuthority,
     TAccountManager,
+    TAccountProgram,
+    TAccountProgramData,
     TAccountSystemProgram
   >
 ): Promise<
   InitManagerAccountInstruction<
     typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
     TAccountPayer,
+    TAccountUpgradeAuthority,
     TAccountManager,
+    TAccountProgram,
+    TAccountProgramData,
     TAccountSystemProgram
   >
 > {
@@ -126,7 +157,13 @@ export async function getInitManagerAccountInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
+    upgradeAuthority: {
+      value: input.upgradeAuthority ?? null,
+      isWritable: false,
+    },
     manager: { value: input.manager ?? null, isWritable: true },
+    program: { value: input.program ?? null, isWritable: false },
+    programData: { value: input.programData ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -145,6 +182,10 @@ export async function getInitManagerAccountInstructionAsync<
       ],
     });
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM' as Address<'wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -154,7 +195,10 @@ export async function getInitManagerAccountInstructionAsync<
   const instruction = {
     accounts: [
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.upgradeAuthority),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.programData),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
@@ -162,7 +206,10 @@ export async function getInitManagerAccountInstructionAsync<
   } as InitManagerAccountInstruction<
     typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
     TAccountPayer,
+    TAccountUpgradeAuthority,
     TAccountManager,
+    TAccountProgram,
+    TAccountProgramData,
     TAccountSystemProgram
   >;
 
@@ -171,28 +218,43 @@ export async function getInitManagerAccountInstructionAsync<
 
 export type InitManagerAccountInput<
   TAccountPayer extends string = string,
+  TAccountUpgradeAuthority extends string = string,
   TAccountManager extends string = string,
+  TAccountProgram extends string = string,
+  TAccountProgramData extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
+  upgradeAuthority: TransactionSigner<TAccountUpgradeAuthority>;
   manager: Address<TAccountManager>;
+  program?: Address<TAccountProgram>;
+  programData: Address<TAccountProgramData>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export function getInitManagerAccountInstruction<
   TAccountPayer extends string,
+  TAccountUpgradeAuthority extends string,
   TAccountManager extends string,
+  TAccountProgram extends string,
+  TAccountProgramData extends string,
   TAccountSystemProgram extends string,
 >(
   input: InitManagerAccountInput<
     TAccountPayer,
+    TAccountUpgradeAuthority,
     TAccountManager,
+    TAccountProgram,
+    TAccountProgramData,
     TAccountSystemProgram
   >
 ): InitManagerAccountInstruction<
   typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
   TAccountPayer,
+  TAccountUpgradeAuthority,
   TAccountManager,
+  TAccountProgram,
+  TAccountProgramData,
   TAccountSystemProgram
 > {
   // Program address.
@@ -201,7 +263,13 @@ export function getInitManagerAccountInstruction<
   // Original accounts.
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
+    upgradeAuthority: {
+      value: input.upgradeAuthority ?? null,
+      isWritable: false,
+    },
     manager: { value: input.manager ?? null, isWritable: true },
+    program: { value: input.program ?? null, isWritable: false },
+    programData: { value: input.programData ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -210,6 +278,10 @@ export function getInitManagerAccountInstruction<
   >;
 
   // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM' as Address<'wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -219,7 +291,10 @@ export function getInitManagerAccountInstruction<
   const instruction = {
     accounts: [
       getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.upgradeAuthority),
       getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.programData),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
@@ -227,7 +302,10 @@ export function getInitManagerAccountInstruction<
   } as InitManagerAccountInstruction<
     typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
     TAccountPayer,
+    TAccountUpgradeAuthority,
     TAccountManager,
+    TAccountProgram,
+    TAccountProgramData,
     TAccountSystemProgram
   >;
 
@@ -241,8 +319,11 @@ export type ParsedInitManagerAccountInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     payer: TAccountMetas[0];
-    manager: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    upgradeAuthority: TAccountMetas[1];
+    manager: TAccountMetas[2];
+    program: TAccountMetas[3];
+    programData: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: InitManagerAccountInstructionData;
 };
@@ -255,7 +336,7 @@ export function parseInitManagerAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitManagerAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -269,7 +350,10 @@ export function parseInitManagerAccountInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
+      upgradeAuthority: getNextAccount(),
       manager: getNextAccount(),
+      program: getNextAccount(),
+      programData: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getInitManagerAccountInstructionDataDecoder().decode(
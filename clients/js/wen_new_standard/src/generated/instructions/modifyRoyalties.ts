@@ -12,6 +12,7 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -45,6 +46,7 @@ export type ModifyRoyaltiesInstruction<
   TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -67,6 +69,9 @@ export type ModifyRoyaltiesInstruction<
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -116,16 +121,129 @@ export function getModifyRoyaltiesInstructionDataCodec(): Codec<
   );
 }
 
+export type ModifyRoyaltiesAsyncInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  mint: Address<TAccountMint>;
+  manager?: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  args: ModifyRoyaltiesInstructionDataArgs['args'];
+};
+
+export async function getModifyRoyaltiesInstructionAsync<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: ModifyRoyaltiesAsyncInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): Promise<
+  ModifyRoyaltiesInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getModifyRoyaltiesInstructionDataEncoder().encode(
+      args as ModifyRoyaltiesInstructionDataArgs
+    ),
+  } as ModifyRoyaltiesInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
 export type ModifyRoyaltiesInput<
   TAccountPayer extends string = string,
   TAccountAuthority extends string = string,
   TAccountMint extends string = string,
+  TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
   authority: TransactionSigner<TAccountAuthority>;
   mint: Address<TAccountMint>;
+  manager: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   args: ModifyRoyaltiesInstructionDataArgs['args'];
@@ -135,6 +253,7 @@ export function getModifyRoyaltiesInstruction<
   TAccountPayer extends string,
   TAccountAuthority extends string,
   TAccountMint extends string,
+  TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
 >(
@@ -142,6 +261,7 @@ export function getModifyRoyaltiesInstruction<
     TAccountPayer,
     TAccountAuthority,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -150,6 +270,7 @@ export function getModifyRoyaltiesInstruction<
   TAccountPayer,
   TAccountAuthority,
   TAccountMint,
+  TAccountManager,
   TAccountSystemProgram,
   TAccountTokenProgram
 > {
@@ -161,6 +282,7 @@ export function getModifyRoyaltiesInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -188,6 +310,7 @@ export function getModifyRoyaltiesInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
     ],
@@ -200,6 +323,7 @@ export function getModifyRoyaltiesInstruction<
     TAccountPayer,
     TAccountAuthority,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >;
@@ -216,8 +340,9 @@ export type ParsedModifyRoyaltiesInstruction<
     payer: TAccountMetas[0];
     authority: TAccountMetas[1];
     mint: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
-    tokenProgram: TAccountMetas[4];
+    manager: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+    tokenProgram: TAccountMetas[5];
   };
   data: ModifyRoyaltiesInstructionData;
 };
@@ -230,7 +355,7 @@ export function parseModifyRoyaltiesInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedModifyRoyaltiesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -246,6 +371,7 @@ export function parseModifyRoyaltiesInstruction<
       payer: getNextAccount(),
       authority: getNextAccount(),
       mint: getNextAccount(),
+      manager: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
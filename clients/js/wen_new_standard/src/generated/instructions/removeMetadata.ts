@@ -14,6 +14,7 @@ import {
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -47,6 +48,7 @@ export type RemoveMetadataInstruction<
   TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -69,6 +71,9 @@ export type RemoveMetadataInstruction<
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -118,16 +123,129 @@ export function getRemoveMetadataInstructionDataCodec(): Codec<
   );
 }
 
+export type RemoveMetadataAsyncInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  mint: Address<TAccountMint>;
+  manager?: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  args: RemoveMetadataInstructionDataArgs['args'];
+};
+
+export async function getRemoveMetadataInstructionAsync<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: RemoveMetadataAsyncInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): Promise<
+  RemoveMetadataInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getRemoveMetadataInstructionDataEncoder().encode(
+      args as RemoveMetadataInstructionDataArgs
+    ),
+  } as RemoveMetadataInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
 export type RemoveMetadataInput<
   TAccountPayer extends string = string,
   TAccountAuthority extends string = string,
   TAccountMint extends string = string,
+  TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
   authority: TransactionSigner<TAccountAuthority>;
   mint: Address<TAccountMint>;
+  manager: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   args: RemoveMetadataInstructionDataArgs['args'];
@@ -137,6 +255,7 @@ export function getRemoveMetadataInstruction<
   TAccountPayer extends string,
   TAccountAuthority extends string,
   TAccountMint extends string,
+  TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
 >(
@@ -144,6 +263,7 @@ export function getRemoveMetadataInstruction<
     TAccountPayer,
     TAccountAuthority,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -152,6 +272,7 @@ export function getRemoveMetadataInstruction<
   TAccountPayer,
   TAccountAuthority,
   TAccountMint,
+  TAccountManager,
   TAccountSystemProgram,
   TAccountTokenProgram
 > {
@@ -163,6 +284,7 @@ export function getRemoveMetadataInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -190,6 +312,7 @@ export function getRemoveMetadataInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
     ],
@@ -202,6 +325,7 @@ export function getRemoveMetadataInstruction<
     TAccountPayer,
     TAccountAuthority,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >;
@@ -218,8 +342,9 @@ export type ParsedRemoveMetadataInstruction<
     payer: TAccountMetas[0];
     authority: TAccountMetas[1];
     mint: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
-    tokenProgram: TAccountMetas[4];
+    manager: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+    tokenProgram: TAccountMetas[5];
   };
   data: RemoveMetadataInstructionData;
 };
@@ -232,7 +357,7 @@ export function parseRemoveMetadataInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRemoveMetadataInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -248,6 +373,7 @@ export function parseRemoveMetadataInstruction<
       payer: getNextAccount(),
       authority: getNextAccount(),
       mint: getNextAccount(),
+      manager: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
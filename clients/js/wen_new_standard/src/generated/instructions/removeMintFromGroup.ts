@@ -47,6 +47,7 @@ export type RemoveMintFromGroupInstruction<
   TAccountGroup extends string | IAccountMeta<string> = string,
   TAccountMember extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountSubGroup extends string | IAccountMeta<string> = string,
   TAccountManager extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -76,6 +77,9 @@ export type RemoveMintFromGroupInstruction<
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountSubGroup extends string
+        ? ReadonlyAccount<TAccountSubGroup>
+        : TAccountSubGroup,
       TAccountManager extends string
         ? ReadonlyAccount<TAccountManager>
         : TAccountManager,
@@ -127,6 +131,7 @@ export type RemoveMintFromGroupAsyncInput<
   TAccountGroup extends string = string,
   TAccountMember extends string = string,
   TAccountMint extends string = string,
+  TAccountSubGroup extends string = string,
   TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
@@ -136,6 +141,7 @@ export type RemoveMintFromGroupAsyncInput<
   group: Address<TAccountGroup>;
   member?: Address<TAccountMember>;
   mint: Address<TAccountMint>;
+  subGroup?: Address<TAccountSubGroup>;
   manager?: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -147,6 +153,7 @@ export async function getRemoveMintFromGroupInstructionAsync<
   TAccountGroup extends string,
   TAccountMember extends string,
   TAccountMint extends string,
+  TAccountSubGroup extends string,
   TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
@@ -157,6 +164,7 @@ export async function getRemoveMintFromGroupInstructionAsync<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -169,6 +177,7 @@ export async function getRemoveMintFromGroupInstructionAsync<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -184,6 +193,7 @@ export async function getRemoveMintFromGroupInstructionAsync<
     group: { value: input.group ?? null, isWritable: true },
     member: { value: input.member ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
     manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -230,6 +240,7 @@ export async function getRemoveMintFromGroupInstructionAsync<
       getAccountMeta(accounts.group),
       getAccountMeta(accounts.member),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.subGroup),
       getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -243,6 +254,7 @@ export async function getRemoveMintFromGroupInstructionAsync<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -257,6 +269,7 @@ export type RemoveMintFromGroupInput<
   TAccountGroup extends string = string,
   TAccountMember extends string = string,
   TAccountMint extends string = string,
+  TAccountSubGroup extends string = string,
   TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
@@ -266,6 +279,7 @@ export type RemoveMintFromGroupInput<
   group: Address<TAccountGroup>;
   member: Address<TAccountMember>;
   mint: Address<TAccountMint>;
+  subGroup?: Address<TAccountSubGroup>;
   manager: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -277,6 +291,7 @@ export function getRemoveMintFromGroupInstruction<
   TAccountGroup extends string,
   TAccountMember extends string,
   TAccountMint extends string,
+  TAccountSubGroup extends string,
   TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
@@ -287,6 +302,7 @@ export function getRemoveMintFromGroupInstruction<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -298,6 +314,7 @@ export function getRemoveMintFromGroupInstruction<
   TAccountGroup,
   TAccountMember,
   TAccountMint,
+  TAccountSubGroup,
   TAccountManager,
   TAccountSystemProgram,
   TAccountTokenProgram
@@ -312,6 +329,7 @@ export function getRemoveMintFromGroupInstruction<
     group: { value: input.group ?? null, isWritable: true },
     member: { value: input.member ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
     manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -339,6 +357,7 @@ export function getRemoveMintFromGroupInstruction<
       getAccountMeta(accounts.group),
       getAccountMeta(accounts.member),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.subGroup),
       getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
@@ -352,6 +371,7 @@ export function getRemoveMintFromGroupInstruction<
     TAccountGroup,
     TAccountMember,
     TAccountMint,
+    TAccountSubGroup,
     TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
@@ -371,9 +391,10 @@ export type ParsedRemoveMintFromGroupInstruction<
     group: TAccountMetas[2];
     member: TAccountMetas[3];
     mint: TAccountMetas[4];
-    manager: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
+    subGroup?: TAccountMetas[5] | undefined;
+    manager: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
   };
   data: RemoveMintFromGroupInstructionData;
 };
@@ -386,7 +407,7 @@ export function parseRemoveMintFromGroupInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRemoveMintFromGroupInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -396,6 +417,12 @@ export function parseRemoveMintFromGroupInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === WEN_NEW_STANDARD_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -404,6 +431,7 @@ export function parseRemoveMintFromGroupInstruction<
       group: getNextAccount(),
       member: getNextAccount(),
       mint: getNextAccount(),
+      subGroup: getNextOptionalAccount(),
       manager: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
@@ -0,0 +1,481 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getProgramDerivedAddress,
+  getStructDecoder,
+  getStructEncoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { WEN_NEW_STANDARD_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export type TransferMemberInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountNewAuthority extends string | IAccountMeta<string> = string,
+  TAccountGroup extends string | IAccountMeta<string> = string,
+  TAccountNewGroup extends string | IAccountMeta<string> = string,
+  TAccountMember extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountSubGroup extends string | IAccountMeta<string> = string,
+  TAccountAssetAuthority extends string | IAccountMeta<string> = string,
+  TAccountAssetTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountNewAuthority extends string
+        ? ReadonlySignerAccount<TAccountNewAuthority> &
+            IAccountSignerMeta<TAccountNewAuthority>
+        : TAccountNewAuthority,
+      TAccountGroup extends string
+        ? WritableAccount<TAccountGroup>
+        : TAccountGroup,
+      TAccountNewGroup extends string
+        ? WritableAccount<TAccountNewGroup>
+        : TAccountNewGroup,
+      TAccountMember extends string
+        ? WritableAccount<TAccountMember>
+        : TAccountMember,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountSubGroup extends string
+        ? ReadonlyAccount<TAccountSubGroup>
+        : TAccountSubGroup,
+      TAccountAssetAuthority extends string
+        ? ReadonlySignerAccount<TAccountAssetAuthority> &
+            IAccountSignerMeta<TAccountAssetAuthority>
+        : TAccountAssetAuthority,
+      TAccountAssetTokenAccount extends string
+        ? ReadonlyAccount<TAccountAssetTokenAccount>
+        : TAccountAssetTokenAccount,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type TransferMemberInstructionData = {
+  discriminator: ReadonlyUint8Array;
+};
+
+export type TransferMemberInstructionDataArgs = {};
+
+export function getTransferMemberInstructionDataEncoder(): Encoder<TransferMemberInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([111, 133, 45, 239, 224, 235, 109, 82]),
+    })
+  );
+}
+
+export function getTransferMemberInstructionDataDecoder(): Decoder<TransferMemberInstructionData> {
+  return getStructDecoder([
+    ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+  ]);
+}
+
+export function getTransferMemberInstructionDataCodec(): Codec<
+  TransferMemberInstructionDataArgs,
+  TransferMemberInstructionData
+> {
+  return combineCodec(
+    getTransferMemberInstructionDataEncoder(),
+    getTransferMemberInstructionDataDecoder()
+  );
+}
+
+export type TransferMemberAsyncInput<
+  TAccountAuthority extends string = string,
+  TAccountNewAuthority extends string = string,
+  TAccountGroup extends string = string,
+  TAccountNewGroup extends string = string,
+  TAccountMember extends string = string,
+  TAccountMint extends string = string,
+  TAccountSubGroup extends string = string,
+  TAccountAssetAuthority extends string = string,
+  TAccountAssetTokenAccount extends string = string,
+  TAccountManager extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  authority: TransactionSigner<TAccountAuthority>;
+  newAuthority: TransactionSigner<TAccountNewAuthority>;
+  group: Address<TAccountGroup>;
+  newGroup: Address<TAccountNewGroup>;
+  member?: Address<TAccountMember>;
+  mint: Address<TAccountMint>;
+  subGroup?: Address<TAccountSubGroup>;
+  assetAuthority: TransactionSigner<TAccountAssetAuthority>;
+  assetTokenAccount?: Address<TAccountAssetTokenAccount>;
+  manager?: Address<TAccountManager>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export async function getTransferMemberInstructionAsync<
+  TAccountAuthority extends string,
+  TAccountNewAuthority extends string,
+  TAccountGroup extends string,
+  TAccountNewGroup extends string,
+  TAccountMember extends string,
+  TAccountMint extends string,
+  TAccountSubGroup extends string,
+  TAccountAssetAuthority extends string,
+  TAccountAssetTokenAccount extends string,
+  TAccountManager extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: TransferMemberAsyncInput<
+    TAccountAuthority,
+    TAccountNewAuthority,
+    TAccountGroup,
+    TAccountNewGroup,
+    TAccountMember,
+    TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
+    TAccountManager,
+    TAccountTokenProgram
+  >
+): Promise<
+  TransferMemberInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountAuthority,
+    TAccountNewAuthority,
+    TAccountGroup,
+    TAccountNewGroup,
+    TAccountMember,
+    TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
+    TAccountManager,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    authority: { value: input.authority ?? null, isWritable: false },
+    newAuthority: { value: input.newAuthority ?? null, isWritable: false },
+    group: { value: input.group ?? null, isWritable: true },
+    newGroup: { value: input.newGroup ?? null, isWritable: true },
+    member: { value: input.member ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
+    assetAuthority: { value: input.assetAuthority ?? null, isWritable: false },
+    assetTokenAccount: {
+      value: input.assetTokenAccount ?? null,
+      isWritable: false,
+    },
+    manager: { value: input.manager ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.member.value) {
+    accounts.member.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([109, 101, 109, 98, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.newAuthority),
+      getAccountMeta(accounts.group),
+      getAccountMeta(accounts.newGroup),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.subGroup),
+      getAccountMeta(accounts.assetAuthority),
+      getAccountMeta(accounts.assetTokenAccount),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getTransferMemberInstructionDataEncoder().encode({}),
+  } as TransferMemberInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountAuthority,
+    TAccountNewAuthority,
+    TAccountGroup,
+    TAccountNewGroup,
+    TAccountMember,
+    TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
+    TAccountManager,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type TransferMemberInput<
+  TAccountAuthority extends string = string,
+  TAccountNewAuthority extends string = string,
+  TAccountGroup extends string = string,
+  TAccountNewGroup extends string = string,
+  TAccountMember extends string = string,
+  TAccountMint extends string = string,
+  TAccountSubGroup extends string = string,
+  TAccountAssetAuthority extends string = string,
+  TAccountAssetTokenAccount extends string = string,
+  TAccountManager extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  authority: TransactionSigner<TAccountAuthority>;
+  newAuthority: TransactionSigner<TAccountNewAuthority>;
+  group: Address<TAccountGroup>;
+  newGroup: Address<TAccountNewGroup>;
+  member: Address<TAccountMember>;
+  mint: Address<TAccountMint>;
+  subGroup?: Address<TAccountSubGroup>;
+  assetAuthority: TransactionSigner<TAccountAssetAuthority>;
+  assetTokenAccount?: Address<TAccountAssetTokenAccount>;
+  manager: Address<TAccountManager>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+};
+
+export function getTransferMemberInstruction<
+  TAccountAuthority extends string,
+  TAccountNewAuthority extends string,
+  TAccountGroup extends string,
+  TAccountNewGroup extends string,
+  TAccountMember extends string,
+  TAccountMint extends string,
+  TAccountSubGroup extends string,
+  TAccountAssetAuthority extends string,
+  TAccountAssetTokenAccount extends string,
+  TAccountManager extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: TransferMemberInput<
+    TAccountAuthority,
+    TAccountNewAuthority,
+    TAccountGroup,
+    TAccountNewGroup,
+    TAccountMember,
+    TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
+    TAccountManager,
+    TAccountTokenProgram
+  >
+): TransferMemberInstruction<
+  typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountAuthority,
+  TAccountNewAuthority,
+  TAccountGroup,
+  TAccountNewGroup,
+  TAccountMember,
+  TAccountMint,
+  TAccountSubGroup,
+  TAccountAssetAuthority,
+  TAccountAssetTokenAccount,
+  TAccountManager,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    authority: { value: input.authority ?? null, isWritable: false },
+    newAuthority: { value: input.newAuthority ?? null, isWritable: false },
+    group: { value: input.group ?? null, isWritable: true },
+    newGroup: { value: input.newGroup ?? null, isWritable: true },
+    member: { value: input.member ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    subGroup: { value: input.subGroup ?? null, isWritable: false },
+    assetAuthority: { value: input.assetAuthority ?? null, isWritable: false },
+    assetTokenAccount: {
+      value: input.assetTokenAccount ?? null,
+      isWritable: false,
+    },
+    manager: { value: input.manager ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.newAuthority),
+      getAccountMeta(accounts.group),
+      getAccountMeta(accounts.newGroup),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.subGroup),
+      getAccountMeta(accounts.assetAuthority),
+      getAccountMeta(accounts.assetTokenAccount),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getTransferMemberInstructionDataEncoder().encode({}),
+  } as TransferMemberInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountAuthority,
+    TAccountNewAuthority,
+    TAccountGroup,
+    TAccountNewGroup,
+    TAccountMember,
+    TAccountMint,
+    TAccountSubGroup,
+    TAccountAssetAuthority,
+    TAccountAssetTokenAccount,
+    TAccountManager,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedTransferMemberInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    authority: TAccountMetas[0];
+    newAuthority: TAccountMetas[1];
+    group: TAccountMetas[2];
+    newGroup: TAccountMetas[3];
+    member: TAccountMetas[4];
+    mint: TAccountMetas[5];
+    subGroup?: TAccountMetas[6] | undefined;
+    assetAuthority: TAccountMetas[7];
+    assetTokenAccount?: TAccountMetas[8] | undefined;
+    manager: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+  };
+  data: TransferMemberInstructionData;
+};
+
+export function parseTransferMemberInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedTransferMemberInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 11) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === WEN_NEW_STANDARD_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      authority: getNextAccount(),
+      newAuthority: getNextAccount(),
+      group: getNextAccount(),
+      newGroup: getNextAccount(),
+      member: getNextAccount(),
+      mint: getNextAccount(),
+      subGroup: getNextOptionalAccount(),
+      assetAuthority: getNextAccount(),
+      assetTokenAccount: getNextOptionalAccount(),
+      manager: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getTransferMemberInstructionDataDecoder().decode(instruction.data),
+  };
+}
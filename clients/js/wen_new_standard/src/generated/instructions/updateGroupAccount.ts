@@ -13,8 +13,12 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -32,7 +36,10 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -51,6 +58,7 @@ export type UpdateGroupAccountInstruction<
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountGroup extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -67,7 +75,8 @@ export type UpdateGroupAccountInstruction<
             IAccountSignerMeta<TAccountPayer>
         : TAccountPayer,
       TAccountAuthority extends string
-        ? ReadonlyAccount<TAccountAuthority>
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
       TAccountGroup extends string
         ? WritableAccount<TAccountGroup>
@@ -75,6 +84,9 @@ export type UpdateGroupAccountInstruction<
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -90,14 +102,18 @@ export type UpdateGroupAccountInstructionData = {
   name: string;
   symbol: string;
   uri: string;
-  maxSize: number;
+  maxSize: Option<number>;
+  lockMaxSize: boolean;
+  advisoryMemberUpdateGate: boolean;
 };
 
 export type UpdateGroupAccountInstructionDataArgs = {
   name: string;
   symbol: string;
   uri: string;
-  maxSize: number;
+  maxSize: OptionOrNullable<number>;
+  lockMaxSize: boolean;
+  advisoryMemberUpdateGate: boolean;
 };
 
 export function getUpdateGroupAccountInstructionDataEncoder(): Encoder<UpdateGroupAccountInstructionDataArgs> {
@@ -107,7 +123,9 @@ export function getUpdateGroupAccountInstructionDataEncoder(): Encoder<UpdateGro
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
-      ['maxSize', getU32Encoder()],
+      ['maxSize', getOptionEncoder(getU32Encoder())],
+      ['lockMaxSize', getBooleanEncoder()],
+      ['advisoryMemberUpdateGate', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -122,7 +140,9 @@ export function getUpdateGroupAccountInstructionDataDecoder(): Decoder<UpdateGro
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['maxSize', getU32Decoder()],
+    ['maxSize', getOptionDecoder(getU32Decoder())],
+    ['lockMaxSize', getBooleanDecoder()],
+    ['advisoryMemberUpdateGate', getBooleanDecoder()],
   ]);
 }
 
@@ -141,19 +161,23 @@ export type UpdateGroupAccountAsyncInput<
   TAccountAuthority extends string = string,
   TAccountGroup extends string = string,
   TAccountMint extends string = string,
+  TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  authority: Address<TAccountAuthority>;
+  authority: TransactionSigner<TAccountAuthority>;
   group?: Address<TAccountGroup>;
   mint: Address<TAccountMint>;
+  manager?: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   name: UpdateGroupAccountInstructionDataArgs['name'];
   symbol: UpdateGroupAccountInstructionDataArgs['symbol'];
   uri: UpdateGroupAccountInstructionDataArgs['uri'];
   maxSize: UpdateGroupAccountInstructionDataArgs['maxSize'];
+  lockMaxSize: UpdateGroupAccountInstructionDataArgs['lockMaxSize'];
+  advisoryMemberUpdateGate: UpdateGroupAccountInstructionDataArgs['advisoryMemberUpdateGate'];
 };
 
 export async function getUpdateGroupAccountInstructionAsync<
@@ -161,6 +185,7 @@ export async function getUpdateGroupAccountInstructionAsync<
   TAccountAuthority extends string,
   TAccountGroup extends string,
   TAccountMint extends string,
+  TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
 >(
@@ -169,6 +194,7 @@ export async function getUpdateGroupAccountInstructionAsync<
     TAccountAuthority,
     TAccountGroup,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -179,6 +205,7 @@ export async function getUpdateGroupAccountInstructionAsync<
     TAccountAuthority,
     TAccountGroup,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -192,6 +219,7 @@ export async function getUpdateGroupAccountInstructionAsync<
     authority: { value: input.authority ?? null, isWritable: false },
     group: { value: input.group ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -213,6 +241,16 @@ export async function getUpdateGroupAccountInstructionAsync<
       ],
     });
   }
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -229,6 +267,7 @@ export async function getUpdateGroupAccountInstructionAsync<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.group),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
     ],
@@ -242,6 +281,7 @@ export async function getUpdateGroupAccountInstructionAsync<
     TAccountAuthority,
     TAccountGroup,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >;
@@ -254,19 +294,23 @@ export type UpdateGroupAccountInput<
   TAccountAuthority extends string = string,
   TAccountGroup extends string = string,
   TAccountMint extends string = string,
+  TAccountManager extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  authority: Address<TAccountAuthority>;
+  authority: TransactionSigner<TAccountAuthority>;
   group: Address<TAccountGroup>;
   mint: Address<TAccountMint>;
+  manager: Address<TAccountManager>;
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   name: UpdateGroupAccountInstructionDataArgs['name'];
   symbol: UpdateGroupAccountInstructionDataArgs['symbol'];
   uri: UpdateGroupAccountInstructionDataArgs['uri'];
   maxSize: UpdateGroupAccountInstructionDataArgs['maxSize'];
+  lockMaxSize: UpdateGroupAccountInstructionDataArgs['lockMaxSize'];
+  advisoryMemberUpdateGate: UpdateGroupAccountInstructionDataArgs['advisoryMemberUpdateGate'];
 };
 
 export function getUpdateGroupAccountInstruction<
@@ -274,6 +318,7 @@ export function getUpdateGroupAccountInstruction<
   TAccountAuthority extends string,
   TAccountGroup extends string,
   TAccountMint extends string,
+  TAccountManager extends string,
   TAccountSystemProgram extends string,
   TAccountTokenProgram extends string,
 >(
@@ -282,6 +327,7 @@ export function getUpdateGroupAccountInstruction<
     TAccountAuthority,
     TAccountGroup,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >
@@ -291,6 +337,7 @@ export function getUpdateGroupAccountInstruction<
   TAccountAuthority,
   TAccountGroup,
   TAccountMint,
+  TAccountManager,
   TAccountSystemProgram,
   TAccountTokenProgram
 > {
@@ -303,6 +350,7 @@ export function getUpdateGroupAccountInstruction<
     authority: { value: input.authority ?? null, isWritable: false },
     group: { value: input.group ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    manager: { value: input.manager ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -331,6 +379,7 @@ export function getUpdateGroupAccountInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.group),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.manager),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.tokenProgram),
     ],
@@ -344,6 +393,7 @@ export function getUpdateGroupAccountInstruction<
     TAccountAuthority,
     TAccountGroup,
     TAccountMint,
+    TAccountManager,
     TAccountSystemProgram,
     TAccountTokenProgram
   >;
@@ -361,8 +411,9 @@ export type ParsedUpdateGroupAccountInstruction<
     authority: TAccountMetas[1];
     group: TAccountMetas[2];
     mint: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
-    tokenProgram: TAccountMetas[5];
+    manager: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+    tokenProgram: TAccountMetas[6];
   };
   data: UpdateGroupAccountInstructionData;
 };
@@ -375,7 +426,7 @@ export function parseUpdateGroupAccountInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedUpdateGroupAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -392,6 +443,7 @@ export function parseUpdateGroupAccountInstruction<
       authority: getNextAccount(),
       group: getNextAccount(),
       mint: getNextAccount(),
+      manager: getNextAccount(),
       systemProgram: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
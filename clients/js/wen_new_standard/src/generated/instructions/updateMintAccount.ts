@@ -0,0 +1,501 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getProgramDerivedAddress,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { WEN_NEW_STANDARD_PROGRAM_ADDRESS } from '../programs';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
+
+export type UpdateMintAccountInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountGroupMint extends string | IAccountMeta<string> = string,
+  TAccountGroup extends string | IAccountMeta<string> = string,
+  TAccountMember extends string | IAccountMeta<string> = string,
+  TAccountGroupAuthority extends string | IAccountMeta<string> = string,
+  TAccountManager extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountMint extends string
+        ? WritableAccount<TAccountMint>
+        : TAccountMint,
+      TAccountGroupMint extends string
+        ? ReadonlyAccount<TAccountGroupMint>
+        : TAccountGroupMint,
+      TAccountGroup extends string
+        ? ReadonlyAccount<TAccountGroup>
+        : TAccountGroup,
+      TAccountMember extends string
+        ? ReadonlyAccount<TAccountMember>
+        : TAccountMember,
+      TAccountGroupAuthority extends string
+        ? ReadonlySignerAccount<TAccountGroupAuthority> &
+            IAccountSignerMeta<TAccountGroupAuthority>
+        : TAccountGroupAuthority,
+      TAccountManager extends string
+        ? ReadonlyAccount<TAccountManager>
+        : TAccountManager,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type UpdateMintAccountInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export type UpdateMintAccountInstructionDataArgs = {
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export function getUpdateMintAccountInstructionDataEncoder(): Encoder<UpdateMintAccountInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: new Uint8Array([181, 238, 113, 21, 222, 37, 92, 9]),
+    })
+  );
+}
+
+export function getUpdateMintAccountInstructionDataDecoder(): Decoder<UpdateMintAccountInstructionData> {
+  return getStructDecoder([
+    ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+  ]);
+}
+
+export function getUpdateMintAccountInstructionDataCodec(): Codec<
+  UpdateMintAccountInstructionDataArgs,
+  UpdateMintAccountInstructionData
+> {
+  return combineCodec(
+    getUpdateMintAccountInstructionDataEncoder(),
+    getUpdateMintAccountInstructionDataDecoder()
+  );
+}
+
+export type UpdateMintAccountAsyncInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountGroupMint extends string = string,
+  TAccountGroup extends string = string,
+  TAccountMember extends string = string,
+  TAccountGroupAuthority extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  mint: Address<TAccountMint>;
+  groupMint?: Address<TAccountGroupMint>;
+  group?: Address<TAccountGroup>;
+  member?: Address<TAccountMember>;
+  groupAuthority?: TransactionSigner<TAccountGroupAuthority>;
+  manager?: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  name: UpdateMintAccountInstructionDataArgs['name'];
+  symbol: UpdateMintAccountInstructionDataArgs['symbol'];
+  uri: UpdateMintAccountInstructionDataArgs['uri'];
+};
+
+export async function getUpdateMintAccountInstructionAsync<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountGroupMint extends string,
+  TAccountGroup extends string,
+  TAccountMember extends string,
+  TAccountGroupAuthority extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: UpdateMintAccountAsyncInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountGroupMint,
+    TAccountGroup,
+    TAccountMember,
+    TAccountGroupAuthority,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): Promise<
+  UpdateMintAccountInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountGroupMint,
+    TAccountGroup,
+    TAccountMember,
+    TAccountGroupAuthority,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    groupMint: { value: input.groupMint ?? null, isWritable: false },
+    group: { value: input.group ?? null, isWritable: false },
+    member: { value: input.member ?? null, isWritable: false },
+    groupAuthority: { value: input.groupAuthority ?? null, isWritable: false },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.member.value) {
+    accounts.member.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([109, 101, 109, 98, 101, 114])),
+        getAddressEncoder().encode(expectAddress(accounts.mint.value)),
+      ],
+    });
+  }
+  if (!accounts.manager.value) {
+    accounts.manager.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([109, 97, 110, 97, 103, 101, 114])
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.groupMint),
+      getAccountMeta(accounts.group),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.groupAuthority),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getUpdateMintAccountInstructionDataEncoder().encode(
+      args as UpdateMintAccountInstructionDataArgs
+    ),
+  } as UpdateMintAccountInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountGroupMint,
+    TAccountGroup,
+    TAccountMember,
+    TAccountGroupAuthority,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type UpdateMintAccountInput<
+  TAccountPayer extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountGroupMint extends string = string,
+  TAccountGroup extends string = string,
+  TAccountMember extends string = string,
+  TAccountGroupAuthority extends string = string,
+  TAccountManager extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  payer: TransactionSigner<TAccountPayer>;
+  authority: TransactionSigner<TAccountAuthority>;
+  mint: Address<TAccountMint>;
+  groupMint?: Address<TAccountGroupMint>;
+  group?: Address<TAccountGroup>;
+  member?: Address<TAccountMember>;
+  groupAuthority?: TransactionSigner<TAccountGroupAuthority>;
+  manager: Address<TAccountManager>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  name: UpdateMintAccountInstructionDataArgs['name'];
+  symbol: UpdateMintAccountInstructionDataArgs['symbol'];
+  uri: UpdateMintAccountInstructionDataArgs['uri'];
+};
+
+export function getUpdateMintAccountInstruction<
+  TAccountPayer extends string,
+  TAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountGroupMint extends string,
+  TAccountGroup extends string,
+  TAccountMember extends string,
+  TAccountGroupAuthority extends string,
+  TAccountManager extends string,
+  TAccountSystemProgram extends string,
+  TAccountTokenProgram extends string,
+>(
+  input: UpdateMintAccountInput<
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountGroupMint,
+    TAccountGroup,
+    TAccountMember,
+    TAccountGroupAuthority,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >
+): UpdateMintAccountInstruction<
+  typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountPayer,
+  TAccountAuthority,
+  TAccountMint,
+  TAccountGroupMint,
+  TAccountGroup,
+  TAccountMember,
+  TAccountGroupAuthority,
+  TAccountManager,
+  TAccountSystemProgram,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress = WEN_NEW_STANDARD_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    authority: { value: input.authority ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: true },
+    groupMint: { value: input.groupMint ?? null, isWritable: false },
+    group: { value: input.group ?? null, isWritable: false },
+    member: { value: input.member ?? null, isWritable: false },
+    groupAuthority: { value: input.groupAuthority ?? null, isWritable: false },
+    manager: { value: input.manager ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.groupMint),
+      getAccountMeta(accounts.group),
+      getAccountMeta(accounts.member),
+      getAccountMeta(accounts.groupAuthority),
+      getAccountMeta(accounts.manager),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getUpdateMintAccountInstructionDataEncoder().encode(
+      args as UpdateMintAccountInstructionDataArgs
+    ),
+  } as UpdateMintAccountInstruction<
+    typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAuthority,
+    TAccountMint,
+    TAccountGroupMint,
+    TAccountGroup,
+    TAccountMember,
+    TAccountGroupAuthority,
+    TAccountManager,
+    TAccountSystemProgram,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedUpdateMintAccountInstruction<
+  TProgram extends string = typeof WEN_NEW_STANDARD_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    payer: TAccountMetas[0];
+    authority: TAccountMetas[1];
+    mint: TAccountMetas[2];
+    groupMint?: TAccountMetas[3] | undefined;
+    group?: TAccountMetas[4] | undefined;
+    member?: TAccountMetas[5] | undefined;
+    groupAuthority?: TAccountMetas[6] | undefined;
+    manager: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+  };
+  data: UpdateMintAccountInstructionData;
+};
+
+export function parseUpdateMintAccountInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedUpdateMintAccountInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 10) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === WEN_NEW_STANDARD_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      payer: getNextAccount(),
+      authority: getNextAccount(),
+      mint: getNextAccount(),
+      groupMint: getNextOptionalAccount(),
+      group: getNextOptionalAccount(),
+      member: getNextOptionalAccount(),
+      groupAuthority: getNextOptionalAccount(),
+      manager: getNextAccount(),
+      systemProgram: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getUpdateMintAccountInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -18,16 +18,20 @@ import {
   type ParsedAddRoyaltiesInstruction,
   type ParsedApproveTransferInstruction,
   type ParsedBurnMintAccountInstruction,
+  type ParsedClawbackInstruction,
   type ParsedCreateGroupAccountInstruction,
   type ParsedCreateMintAccountInstruction,
   type ParsedExecuteInstruction,
   type ParsedFreezeMintAccountInstruction,
+  type ParsedInheritRoyaltiesInstruction,
   type ParsedInitManagerAccountInstruction,
   type ParsedModifyRoyaltiesInstruction,
   type ParsedRemoveMetadataInstruction,
   type ParsedRemoveMintFromGroupInstruction,
   type ParsedThawMintAccountInstruction,
+  type ParsedTransferMemberInstruction,
   type ParsedUpdateGroupAccountInstruction,
+  type ParsedUpdateMintAccountInstruction,
 } from '../instructions';
 
 export const WEN_NEW_STANDARD_PROGRAM_ADDRESS =
@@ -99,16 +103,20 @@ export enum WenNewStandardInstruction {
   AddRoyalties,
   ApproveTransfer,
   BurnMintAccount,
+  Clawback,
   CreateGroupAccount,
   CreateMintAccount,
   Execute,
   FreezeMintAccount,
+  InheritRoyalties,
   InitManagerAccount,
   ModifyRoyalties,
   RemoveMetadata,
   RemoveMintFromGroup,
   ThawMintAccount,
+  TransferMember,
   UpdateGroupAccount,
+  UpdateMintAccount,
 }
 
 export function identifyWenNewStandardInstruction(
@@ -171,6 +179,17 @@ export function identifyWenNewStandardInstruction(
   ) {
     return WenNewStandardInstruction.BurnMintAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([111, 92, 142, 79, 33, 234, 82, 27])
+      ),
+      0
+    )
+  ) {
+    return WenNewStandardInstruction.Clawback;
+  }
   if (
     containsBytes(
       data,
@@ -215,6 +234,17 @@ export function identifyWenNewStandardInstruction(
   ) {
     return WenNewStandardInstruction.FreezeMintAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 168, 196, 44, 255, 181, 126, 15])
+      ),
+      0
+    )
+  ) {
+    return WenNewStandardInstruction.InheritRoyalties;
+  }
   if (
     containsBytes(
       data,
@@ -270,6 +300,17 @@ export function identifyWenNewStandardInstruction(
   ) {
     return WenNewStandardInstruction.ThawMintAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([111, 133, 45, 239, 224, 235, 109, 82])
+      ),
+      0
+    )
+  ) {
+    return WenNewStandardInstruction.TransferMember;
+  }
   if (
     containsBytes(
       data,
@@ -281,6 +322,17 @@ export function identifyWenNewStandardInstruction(
   ) {
     return WenNewStandardInstruction.UpdateGroupAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 238, 113, 21, 222, 37, 92, 9])
+      ),
+      0
+    )
+  ) {
+    return WenNewStandardInstruction.UpdateMintAccount;
+  }
   throw new Error(
     'The provided instruction could not be identified as a wenNewStandard instruction.'
   );
@@ -304,6 +356,9 @@ export type ParsedWenNewStandardInstruction<
   | ({
       instructionType: WenNewStandardInstruction.BurnMintAccount;
     } & ParsedBurnMintAccountInstruction<TProgram>)
+  | ({
+      instructionType: WenNewStandardInstruction.Clawback;
+    } & ParsedClawbackInstruction<TProgram>)
   | ({
       instructionType: WenNewStandardInstruction.CreateGroupAccount;
     } & ParsedCreateGroupAccountInstruction<TProgram>)
@@ -316,6 +371,9 @@ export type ParsedWenNewStandardInstruction<
   | ({
       instructionType: WenNewStandardInstruction.FreezeMintAccount;
     } & ParsedFreezeMintAccountInstruction<TProgram>)
+  | ({
+      instructionType: WenNewStandardInstruction.InheritRoyalties;
+    } & ParsedInheritRoyaltiesInstruction<TProgram>)
   | ({
       instructionType: WenNewStandardInstruction.InitManagerAccount;
     } & ParsedInitManagerAccountInstruction<TProgram>)
@@ -331,6 +389,12 @@ export type ParsedWenNewStandardInstruction<
   | ({
       instructionType: WenNewStandardInstruction.ThawMintAccount;
     } & ParsedThawMintAccountInstruction<TProgram>)
+  | ({
+      instructionType: WenNewStandardInstruction.TransferMember;
+    } & ParsedTransferMemberInstruction<TProgram>)
   | ({
       instructionType: WenNewStandardInstruction.UpdateGroupAccount;
-    } & ParsedUpdateGroupAccountInstruction<TProgram>);
+    } & ParsedUpdateGroupAccountInstruction<TProgram>)
+  | ({
+      instructionType: WenNewStandardInstruction.UpdateMintAccount;
+    } & ParsedUpdateMintAccountInstruction<TProgram>);
@@ -12,8 +12,8 @@ import {
   getAddressEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU16Decoder,
+  getU16Encoder,
   type Address,
   type Codec,
   type Decoder,
@@ -27,14 +27,14 @@ export type CreatorWithShareArgs = CreatorWithShare;
 export function getCreatorWithShareEncoder(): Encoder<CreatorWithShareArgs> {
   return getStructEncoder([
     ['address', getAddressEncoder()],
-    ['share', getU8Encoder()],
+    ['share', getU16Encoder()],
   ]);
 }
 
 export function getCreatorWithShareDecoder(): Decoder<CreatorWithShare> {
   return getStructDecoder([
     ['address', getAddressDecoder()],
-    ['share', getU8Decoder()],
+    ['share', getU16Decoder()],
   ]);
 }
 
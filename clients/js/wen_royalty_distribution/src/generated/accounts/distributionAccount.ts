@@ -21,8 +21,16 @@ import {
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -53,6 +61,22 @@ export type DistributionAccount = {
   groupMint: Address;
   /** payment mint for the distribution account */
   paymentMint: Address;
+  /** royalties received over the lifetime of the distribution account, creator shares and dust */
+  totalEarned: bigint;
+  /** rounding remainder of royalty splits that has not been swept yet */
+  dustAmount: bigint;
+  /** receiver of swept dust, Pubkey::default() for the first creator in claim data */
+  dustRecipient: Address;
+  /** share of every distribute_to_creator payout kept by the cranker, in basis points */
+  crankTipBasisPoints: number;
+  /** number of creators in claim data */
+  creatorCount: number;
+  /** unix timestamp vesting starts at */
+  vestingStart: bigint;
+  /** seconds after the vesting start before anything vests */
+  vestingCliff: bigint;
+  /** seconds after the vesting start until everything is vested, 0 if royalties do not vest */
+  vestingDuration: bigint;
   claimData: Array<Creator>;
 };
 
@@ -63,6 +87,22 @@ export type DistributionAccountArgs = {
   groupMint: Address;
   /** payment mint for the distribution account */
   paymentMint: Address;
+  /** royalties received over the lifetime of the distribution account, creator shares and dust */
+  totalEarned: number | bigint;
+  /** rounding remainder of royalty splits that has not been swept yet */
+  dustAmount: number | bigint;
+  /** receiver of swept dust, Pubkey::default() for the first creator in claim data */
+  dustRecipient: Address;
+  /** share of every distribute_to_creator payout kept by the cranker, in basis points */
+  crankTipBasisPoints: number;
+  /** number of creators in claim data */
+  creatorCount: number;
+  /** unix timestamp vesting starts at */
+  vestingStart: number | bigint;
+  /** seconds after the vesting start before anything vests */
+  vestingCliff: number | bigint;
+  /** seconds after the vesting start until everything is vested, 0 if royalties do not vest */
+  vestingDuration: number | bigint;
   claimData: Array<CreatorArgs>;
 };
 
@@ -73,6 +113,14 @@ export function getDistributionAccountEncoder(): Encoder<DistributionAccountArgs
       ['version', getU8Encoder()],
       ['groupMint', getAddressEncoder()],
       ['paymentMint', getAddressEncoder()],
+      ['totalEarned', getU64Encoder()],
+      ['dustAmount', getU64Encoder()],
+      ['dustRecipient', getAddressEncoder()],
+      ['crankTipBasisPoints', getU16Encoder()],
+      ['creatorCount', getU32Encoder()],
+      ['vestingStart', getI64Encoder()],
+      ['vestingCliff', getI64Encoder()],
+      ['vestingDuration', getI64Encoder()],
       ['claimData', getArrayEncoder(getCreatorEncoder())],
     ]),
     (value) => ({
@@ -88,6 +136,14 @@ export function getDistributionAccountDecoder(): Decoder<DistributionAccount> {
     ['version', getU8Decoder()],
     ['groupMint', getAddressDecoder()],
     ['paymentMint', getAddressDecoder()],
+    ['totalEarned', getU64Decoder()],
+    ['dustAmount', getU64Decoder()],
+    ['dustRecipient', getAddressDecoder()],
+    ['crankTipBasisPoints', getU16Decoder()],
+    ['creatorCount', getU32Decoder()],
+    ['vestingStart', getI64Decoder()],
+    ['vestingCliff', getI64Decoder()],
+    ['vestingDuration', getI64Decoder()],
     ['claimData', getArrayDecoder(getCreatorDecoder())],
   ]);
 }
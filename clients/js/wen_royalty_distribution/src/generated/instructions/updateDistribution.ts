@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -92,15 +94,20 @@ export type UpdateDistributionInstruction<
 export type UpdateDistributionInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
+  groupPathLen: number;
 };
 
-export type UpdateDistributionInstructionDataArgs = { amount: number | bigint };
+export type UpdateDistributionInstructionDataArgs = {
+  amount: number | bigint;
+  groupPathLen: number;
+};
 
 export function getUpdateDistributionInstructionDataEncoder(): Encoder<UpdateDistributionInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amount', getU64Encoder()],
+      ['groupPathLen', getU8Encoder()],
     ]),
     (value) => ({
       ...value,
@@ -113,6 +120,7 @@ export function getUpdateDistributionInstructionDataDecoder(): Decoder<UpdateDis
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amount', getU64Decoder()],
+    ['groupPathLen', getU8Decoder()],
   ]);
 }
 
@@ -147,6 +155,7 @@ export type UpdateDistributionInput<
   paymentTokenProgram?: Address<TAccountPaymentTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: UpdateDistributionInstructionDataArgs['amount'];
+  groupPathLen: UpdateDistributionInstructionDataArgs['groupPathLen'];
 };
 
 export function getUpdateDistributionInstruction<
@@ -25,6 +25,16 @@ export type Creator = {
   address: Address;
   /** token amount that creator can claim */
   claimAmount: bigint;
+  /** token amount credited to the creator over the lifetime of the distribution account */
+  totalEarned: bigint;
+  /** token amount paid out to the creator over the lifetime of the distribution account */
+  totalClaimed: bigint;
+  /** slot of the last claim, 0 if the creator never claimed */
+  lastClaimSlot: bigint;
+  /** token amount credited to the creator that has not vested yet */
+  unvestedAmount: bigint;
+  /** token amount released from vesting under the current vesting schedule */
+  vestedAmount: bigint;
 };
 
 export type CreatorArgs = {
@@ -32,12 +42,27 @@ export type CreatorArgs = {
   address: Address;
   /** token amount that creator can claim */
   claimAmount: number | bigint;
+  /** token amount credited to the creator over the lifetime of the distribution account */
+  totalEarned: number | bigint;
+  /** token amount paid out to the creator over the lifetime of the distribution account */
+  totalClaimed: number | bigint;
+  /** slot of the last claim, 0 if the creator never claimed */
+  lastClaimSlot: number | bigint;
+  /** token amount credited to the creator that has not vested yet */
+  unvestedAmount: number | bigint;
+  /** token amount released from vesting under the current vesting schedule */
+  vestedAmount: number | bigint;
 };
 
 export function getCreatorEncoder(): Encoder<CreatorArgs> {
   return getStructEncoder([
     ['address', getAddressEncoder()],
     ['claimAmount', getU64Encoder()],
+    ['totalEarned', getU64Encoder()],
+    ['totalClaimed', getU64Encoder()],
+    ['lastClaimSlot', getU64Encoder()],
+    ['unvestedAmount', getU64Encoder()],
+    ['vestedAmount', getU64Encoder()],
   ]);
 }
 
@@ -45,6 +70,11 @@ export function getCreatorDecoder(): Decoder<Creator> {
   return getStructDecoder([
     ['address', getAddressDecoder()],
     ['claimAmount', getU64Decoder()],
+    ['totalEarned', getU64Decoder()],
+    ['totalClaimed', getU64Decoder()],
+    ['lastClaimSlot', getU64Decoder()],
+    ['unvestedAmount', getU64Decoder()],
+    ['vestedAmount', getU64Decoder()],
   ]);
 }
 
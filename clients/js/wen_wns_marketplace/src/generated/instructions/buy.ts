@@ -18,6 +18,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -157,15 +159,20 @@ export type BuyInstruction<
 export type BuyInstructionData = {
   discriminator: ReadonlyUint8Array;
   buyAmount: bigint;
+  groupPathLen: number;
 };
 
-export type BuyInstructionDataArgs = { buyAmount: number | bigint };
+export type BuyInstructionDataArgs = {
+  buyAmount: number | bigint;
+  groupPathLen: number;
+};
 
 export function getBuyInstructionDataEncoder(): Encoder<BuyInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['buyAmount', getU64Encoder()],
+      ['groupPathLen', getU8Encoder()],
     ]),
     (value) => ({
       ...value,
@@ -178,6 +185,7 @@ export function getBuyInstructionDataDecoder(): Decoder<BuyInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['buyAmount', getU64Decoder()],
+    ['groupPathLen', getU8Decoder()],
   ]);
 }
 
@@ -236,6 +244,7 @@ export type BuyAsyncInput<
   buyerPaymentTokenAccount?: Address<TAccountBuyerPaymentTokenAccount>;
   distributionPaymentTokenAccount?: Address<TAccountDistributionPaymentTokenAccount>;
   buyAmount: BuyInstructionDataArgs['buyAmount'];
+  groupPathLen: BuyInstructionDataArgs['groupPathLen'];
 };
 
 export async function getBuyInstructionAsync<
@@ -510,6 +519,7 @@ export type BuyInput<
   buyerPaymentTokenAccount?: Address<TAccountBuyerPaymentTokenAccount>;
   distributionPaymentTokenAccount?: Address<TAccountDistributionPaymentTokenAccount>;
   buyAmount: BuyInstructionDataArgs['buyAmount'];
+  groupPathLen: BuyInstructionDataArgs['groupPathLen'];
 };
 
 export function getBuyInstruction<
@@ -7,15 +7,32 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Manager {
     pub discriminator: [u8; 8],
+    /// authority that can update the protocol config
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+    /// cut of every royalty payment routed to the treasury, in basis points of the royalty amount
+    pub protocol_fee_basis_points: u16,
+    /// receiver of the protocol fees
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub treasury: Pubkey,
+    /// state-changing instructions are rejected while paused, users can still exit
+    pub paused: bool,
 }
 
 impl Manager {
-    pub const LEN: usize = 8;
+    pub const LEN: usize = 75;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
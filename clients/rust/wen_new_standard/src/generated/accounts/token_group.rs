@@ -28,12 +28,18 @@ pub struct TokenGroup {
     pub mint: Pubkey,
     /// The current number of group members
     pub size: u32,
-    /// The maximum number of group members
+    /// The maximum number of group members, `u32::MAX` for unbounded groups
     pub max_size: u32,
+    /// Whether the max size is locked for good
+    pub max_size_locked: bool,
+    /// Whether `update_mint_account` needs the update authority of the group to sign along for
+    /// member mints. Advisory only, the metadata update authority of a member can still update it
+    /// directly through the token extensions program
+    pub advisory_member_update_gate: bool,
 }
 
 impl TokenGroup {
-    pub const LEN: usize = 80;
+    pub const LEN: usize = 82;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
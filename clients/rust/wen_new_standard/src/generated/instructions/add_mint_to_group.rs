@@ -20,6 +20,12 @@ pub struct AddMintToGroup {
 
     pub mint: solana_program::pubkey::Pubkey,
 
+    pub sub_group: Option<solana_program::pubkey::Pubkey>,
+
+    pub asset_authority: solana_program::pubkey::Pubkey,
+
+    pub asset_token_account: Option<solana_program::pubkey::Pubkey>,
+
     pub manager: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
@@ -36,7 +42,7 @@ impl AddMintToGroup {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -54,6 +60,31 @@ impl AddMintToGroup {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                sub_group, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset_authority,
+            true,
+        ));
+        if let Some(asset_token_account) = self.asset_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                asset_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.manager,
             false,
@@ -105,9 +136,12 @@ impl Default for AddMintToGroupInstructionData {
 ///   2. `[writable]` group
 ///   3. `[writable]` member
 ///   4. `[writable]` mint
-///   5. `[]` manager
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   7. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   5. `[optional]` sub_group
+///   6. `[signer]` asset_authority
+///   7. `[optional]` asset_token_account
+///   8. `[]` manager
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct AddMintToGroupBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
@@ -115,6 +149,9 @@ pub struct AddMintToGroupBuilder {
     group: Option<solana_program::pubkey::Pubkey>,
     member: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    sub_group: Option<solana_program::pubkey::Pubkey>,
+    asset_authority: Option<solana_program::pubkey::Pubkey>,
+    asset_token_account: Option<solana_program::pubkey::Pubkey>,
     manager: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
@@ -150,6 +187,29 @@ impl AddMintToGroupBuilder {
         self.mint = Some(mint);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(&mut self, sub_group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.sub_group = sub_group;
+        self
+    }
+    #[inline(always)]
+    pub fn asset_authority(
+        &mut self,
+        asset_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.asset_authority = Some(asset_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn asset_token_account(
+        &mut self,
+        asset_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.asset_token_account = asset_token_account;
+        self
+    }
     #[inline(always)]
     pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
         self.manager = Some(manager);
@@ -193,6 +253,9 @@ impl AddMintToGroupBuilder {
             group: self.group.expect("group is not set"),
             member: self.member.expect("member is not set"),
             mint: self.mint.expect("mint is not set"),
+            sub_group: self.sub_group,
+            asset_authority: self.asset_authority.expect("asset_authority is not set"),
+            asset_token_account: self.asset_token_account,
             manager: self.manager.expect("manager is not set"),
             system_program: self
                 .system_program
@@ -218,6 +281,12 @@ pub struct AddMintToGroupCpiAccounts<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub asset_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -240,6 +309,12 @@ pub struct AddMintToGroupCpi<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub asset_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -259,6 +334,9 @@ impl<'a, 'b> AddMintToGroupCpi<'a, 'b> {
             group: accounts.group,
             member: accounts.member,
             mint: accounts.mint,
+            sub_group: accounts.sub_group,
+            asset_authority: accounts.asset_authority,
+            asset_token_account: accounts.asset_token_account,
             manager: accounts.manager,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
@@ -297,7 +375,7 @@ impl<'a, 'b> AddMintToGroupCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -318,6 +396,32 @@ impl<'a, 'b> AddMintToGroupCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *sub_group.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset_authority.key,
+            true,
+        ));
+        if let Some(asset_token_account) = self.asset_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *asset_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.manager.key,
             false,
@@ -344,13 +448,20 @@ impl<'a, 'b> AddMintToGroupCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.group.clone());
         account_infos.push(self.member.clone());
         account_infos.push(self.mint.clone());
+        if let Some(sub_group) = self.sub_group {
+            account_infos.push(sub_group.clone());
+        }
+        account_infos.push(self.asset_authority.clone());
+        if let Some(asset_token_account) = self.asset_token_account {
+            account_infos.push(asset_token_account.clone());
+        }
         account_infos.push(self.manager.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
@@ -375,9 +486,12 @@ impl<'a, 'b> AddMintToGroupCpi<'a, 'b> {
 ///   2. `[writable]` group
 ///   3. `[writable]` member
 ///   4. `[writable]` mint
-///   5. `[]` manager
-///   6. `[]` system_program
-///   7. `[]` token_program
+///   5. `[optional]` sub_group
+///   6. `[signer]` asset_authority
+///   7. `[optional]` asset_token_account
+///   8. `[]` manager
+///   9. `[]` system_program
+///   10. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct AddMintToGroupCpiBuilder<'a, 'b> {
     instruction: Box<AddMintToGroupCpiBuilderInstruction<'a, 'b>>,
@@ -392,6 +506,9 @@ impl<'a, 'b> AddMintToGroupCpiBuilder<'a, 'b> {
             group: None,
             member: None,
             mint: None,
+            sub_group: None,
+            asset_authority: None,
+            asset_token_account: None,
             manager: None,
             system_program: None,
             token_program: None,
@@ -430,6 +547,32 @@ impl<'a, 'b> AddMintToGroupCpiBuilder<'a, 'b> {
         self.instruction.mint = Some(mint);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(
+        &mut self,
+        sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.sub_group = sub_group;
+        self
+    }
+    #[inline(always)]
+    pub fn asset_authority(
+        &mut self,
+        asset_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.asset_authority = Some(asset_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn asset_token_account(
+        &mut self,
+        asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.asset_token_account = asset_token_account;
+        self
+    }
     #[inline(always)]
     pub fn manager(
         &mut self,
@@ -508,6 +651,15 @@ impl<'a, 'b> AddMintToGroupCpiBuilder<'a, 'b> {
 
             mint: self.instruction.mint.expect("mint is not set"),
 
+            sub_group: self.instruction.sub_group,
+
+            asset_authority: self
+                .instruction
+                .asset_authority
+                .expect("asset_authority is not set"),
+
+            asset_token_account: self.instruction.asset_token_account,
+
             manager: self.instruction.manager.expect("manager is not set"),
 
             system_program: self
@@ -535,6 +687,9 @@ struct AddMintToGroupCpiBuilderInstruction<'a, 'b> {
     group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
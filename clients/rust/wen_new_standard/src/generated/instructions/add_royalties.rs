@@ -19,6 +19,10 @@ pub struct AddRoyalties {
 
     pub extra_metas_account: solana_program::pubkey::Pubkey,
 
+    pub royalty_config: solana_program::pubkey::Pubkey,
+
+    pub manager: solana_program::pubkey::Pubkey,
+
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
@@ -37,7 +41,7 @@ impl AddRoyalties {
         args: AddRoyaltiesInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -52,6 +56,14 @@ impl AddRoyalties {
             self.extra_metas_account,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.royalty_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.manager,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -106,14 +118,18 @@ pub struct AddRoyaltiesInstructionArgs {
 ///   1. `[signer]` authority
 ///   2. `[writable]` mint
 ///   3. `[writable]` extra_metas_account
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   4. `[writable]` royalty_config
+///   5. `[]` manager
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct AddRoyaltiesBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     extra_metas_account: Option<solana_program::pubkey::Pubkey>,
+    royalty_config: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     args: Option<UpdateRoyaltiesArgs>,
@@ -147,6 +163,16 @@ impl AddRoyaltiesBuilder {
         self.extra_metas_account = Some(extra_metas_account);
         self
     }
+    #[inline(always)]
+    pub fn royalty_config(&mut self, royalty_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.royalty_config = Some(royalty_config);
+        self
+    }
+    #[inline(always)]
+    pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -191,6 +217,8 @@ impl AddRoyaltiesBuilder {
             extra_metas_account: self
                 .extra_metas_account
                 .expect("extra_metas_account is not set"),
+            royalty_config: self.royalty_config.expect("royalty_config is not set"),
+            manager: self.manager.expect("manager is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -216,6 +244,10 @@ pub struct AddRoyaltiesCpiAccounts<'a, 'b> {
 
     pub extra_metas_account: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -234,6 +266,10 @@ pub struct AddRoyaltiesCpi<'a, 'b> {
 
     pub extra_metas_account: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -253,6 +289,8 @@ impl<'a, 'b> AddRoyaltiesCpi<'a, 'b> {
             authority: accounts.authority,
             mint: accounts.mint,
             extra_metas_account: accounts.extra_metas_account,
+            royalty_config: accounts.royalty_config,
+            manager: accounts.manager,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             __args: args,
@@ -291,7 +329,7 @@ impl<'a, 'b> AddRoyaltiesCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -308,6 +346,14 @@ impl<'a, 'b> AddRoyaltiesCpi<'a, 'b> {
             *self.extra_metas_account.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.royalty_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.manager.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -332,12 +378,14 @@ impl<'a, 'b> AddRoyaltiesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.extra_metas_account.clone());
+        account_infos.push(self.royalty_config.clone());
+        account_infos.push(self.manager.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         remaining_accounts
@@ -360,8 +408,10 @@ impl<'a, 'b> AddRoyaltiesCpi<'a, 'b> {
 ///   1. `[signer]` authority
 ///   2. `[writable]` mint
 ///   3. `[writable]` extra_metas_account
-///   4. `[]` system_program
-///   5. `[]` token_program
+///   4. `[writable]` royalty_config
+///   5. `[]` manager
+///   6. `[]` system_program
+///   7. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct AddRoyaltiesCpiBuilder<'a, 'b> {
     instruction: Box<AddRoyaltiesCpiBuilderInstruction<'a, 'b>>,
@@ -375,6 +425,8 @@ impl<'a, 'b> AddRoyaltiesCpiBuilder<'a, 'b> {
             authority: None,
             mint: None,
             extra_metas_account: None,
+            royalty_config: None,
+            manager: None,
             system_program: None,
             token_program: None,
             args: None,
@@ -409,6 +461,22 @@ impl<'a, 'b> AddRoyaltiesCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn royalty_config(
+        &mut self,
+        royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.royalty_config = Some(royalty_config);
+        self
+    }
+    #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -487,6 +555,13 @@ impl<'a, 'b> AddRoyaltiesCpiBuilder<'a, 'b> {
                 .extra_metas_account
                 .expect("extra_metas_account is not set"),
 
+            royalty_config: self
+                .instruction
+                .royalty_config
+                .expect("royalty_config is not set"),
+
+            manager: self.instruction.manager.expect("manager is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -512,6 +587,8 @@ struct AddRoyaltiesCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     extra_metas_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     args: Option<UpdateRoyaltiesArgs>,
@@ -152,6 +152,7 @@ impl Default for ApproveTransferInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApproveTransferInstructionArgs {
     pub buy_amount: u64,
+    pub group_path_len: u8,
 }
 
 /// Instruction builder for `ApproveTransfer`.
@@ -185,6 +186,7 @@ pub struct ApproveTransferBuilder {
     token_program: Option<solana_program::pubkey::Pubkey>,
     payment_token_program: Option<solana_program::pubkey::Pubkey>,
     buy_amount: Option<u64>,
+    group_path_len: Option<u8>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -281,6 +283,11 @@ impl ApproveTransferBuilder {
         self.buy_amount = Some(buy_amount);
         self
     }
+    #[inline(always)]
+    pub fn group_path_len(&mut self, group_path_len: u8) -> &mut Self {
+        self.group_path_len = Some(group_path_len);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -325,6 +332,10 @@ impl ApproveTransferBuilder {
         };
         let args = ApproveTransferInstructionArgs {
             buy_amount: self.buy_amount.clone().expect("buy_amount is not set"),
+            group_path_len: self
+                .group_path_len
+                .clone()
+                .expect("group_path_len is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -602,6 +613,7 @@ impl<'a, 'b> ApproveTransferCpiBuilder<'a, 'b> {
             token_program: None,
             payment_token_program: None,
             buy_amount: None,
+            group_path_len: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -704,6 +716,11 @@ impl<'a, 'b> ApproveTransferCpiBuilder<'a, 'b> {
         self.instruction.buy_amount = Some(buy_amount);
         self
     }
+    #[inline(always)]
+    pub fn group_path_len(&mut self, group_path_len: u8) -> &mut Self {
+        self.instruction.group_path_len = Some(group_path_len);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -751,6 +768,11 @@ impl<'a, 'b> ApproveTransferCpiBuilder<'a, 'b> {
                 .buy_amount
                 .clone()
                 .expect("buy_amount is not set"),
+            group_path_len: self
+                .instruction
+                .group_path_len
+                .clone()
+                .expect("group_path_len is not set"),
         };
         let instruction = ApproveTransferCpi {
             __program: self.instruction.__program,
@@ -821,6 +843,7 @@ struct ApproveTransferCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payment_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     buy_amount: Option<u64>,
+    group_path_len: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -20,6 +20,18 @@ pub struct BurnMintAccount {
 
     pub manager: solana_program::pubkey::Pubkey,
 
+    pub group: Option<solana_program::pubkey::Pubkey>,
+
+    pub member: Option<solana_program::pubkey::Pubkey>,
+
+    pub sub_group: Option<solana_program::pubkey::Pubkey>,
+
+    pub approve_account: solana_program::pubkey::Pubkey,
+
+    pub extra_metas_account: solana_program::pubkey::Pubkey,
+
+    pub royalty_config: solana_program::pubkey::Pubkey,
+
     pub token_program: solana_program::pubkey::Pubkey,
 }
 
@@ -32,7 +44,7 @@ impl BurnMintAccount {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -50,6 +62,44 @@ impl BurnMintAccount {
             self.manager,
             false,
         ));
+        if let Some(group) = self.group {
+            accounts.push(solana_program::instruction::AccountMeta::new(group, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        if let Some(member) = self.member {
+            accounts.push(solana_program::instruction::AccountMeta::new(member, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                sub_group, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.approve_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.extra_metas_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.royalty_config,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_program,
             false,
@@ -93,7 +143,13 @@ impl Default for BurnMintAccountInstructionData {
 ///   2. `[writable]` mint
 ///   3. `[writable]` mint_token_account
 ///   4. `[]` manager
-///   5. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   5. `[writable, optional]` group
+///   6. `[writable, optional]` member
+///   7. `[optional]` sub_group
+///   8. `[writable]` approve_account
+///   9. `[writable]` extra_metas_account
+///   10. `[writable]` royalty_config
+///   11. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct BurnMintAccountBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
@@ -101,6 +157,12 @@ pub struct BurnMintAccountBuilder {
     mint: Option<solana_program::pubkey::Pubkey>,
     mint_token_account: Option<solana_program::pubkey::Pubkey>,
     manager: Option<solana_program::pubkey::Pubkey>,
+    group: Option<solana_program::pubkey::Pubkey>,
+    member: Option<solana_program::pubkey::Pubkey>,
+    sub_group: Option<solana_program::pubkey::Pubkey>,
+    approve_account: Option<solana_program::pubkey::Pubkey>,
+    extra_metas_account: Option<solana_program::pubkey::Pubkey>,
+    royalty_config: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -137,6 +199,45 @@ impl BurnMintAccountBuilder {
         self.manager = Some(manager);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn group(&mut self, group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.group = group;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn member(&mut self, member: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.member = member;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(&mut self, sub_group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.sub_group = sub_group;
+        self
+    }
+    #[inline(always)]
+    pub fn approve_account(
+        &mut self,
+        approve_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.approve_account = Some(approve_account);
+        self
+    }
+    #[inline(always)]
+    pub fn extra_metas_account(
+        &mut self,
+        extra_metas_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.extra_metas_account = Some(extra_metas_account);
+        self
+    }
+    #[inline(always)]
+    pub fn royalty_config(&mut self, royalty_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.royalty_config = Some(royalty_config);
+        self
+    }
     /// `[optional account, default to 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb']`
     #[inline(always)]
     pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -171,6 +272,14 @@ impl BurnMintAccountBuilder {
                 .mint_token_account
                 .expect("mint_token_account is not set"),
             manager: self.manager.expect("manager is not set"),
+            group: self.group,
+            member: self.member,
+            sub_group: self.sub_group,
+            approve_account: self.approve_account.expect("approve_account is not set"),
+            extra_metas_account: self
+                .extra_metas_account
+                .expect("extra_metas_account is not set"),
+            royalty_config: self.royalty_config.expect("royalty_config is not set"),
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
@@ -192,6 +301,18 @@ pub struct BurnMintAccountCpiAccounts<'a, 'b> {
 
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub approve_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub extra_metas_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -210,6 +331,18 @@ pub struct BurnMintAccountCpi<'a, 'b> {
 
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub approve_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub extra_metas_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -225,6 +358,12 @@ impl<'a, 'b> BurnMintAccountCpi<'a, 'b> {
             mint: accounts.mint,
             mint_token_account: accounts.mint_token_account,
             manager: accounts.manager,
+            group: accounts.group,
+            member: accounts.member,
+            sub_group: accounts.sub_group,
+            approve_account: accounts.approve_account,
+            extra_metas_account: accounts.extra_metas_account,
+            royalty_config: accounts.royalty_config,
             token_program: accounts.token_program,
         }
     }
@@ -261,7 +400,7 @@ impl<'a, 'b> BurnMintAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -282,6 +421,50 @@ impl<'a, 'b> BurnMintAccountCpi<'a, 'b> {
             *self.manager.key,
             false,
         ));
+        if let Some(group) = self.group {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *group.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        if let Some(member) = self.member {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *member.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *sub_group.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.approve_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.extra_metas_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.royalty_config.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false,
@@ -300,13 +483,25 @@ impl<'a, 'b> BurnMintAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.mint_token_account.clone());
         account_infos.push(self.manager.clone());
+        if let Some(group) = self.group {
+            account_infos.push(group.clone());
+        }
+        if let Some(member) = self.member {
+            account_infos.push(member.clone());
+        }
+        if let Some(sub_group) = self.sub_group {
+            account_infos.push(sub_group.clone());
+        }
+        account_infos.push(self.approve_account.clone());
+        account_infos.push(self.extra_metas_account.clone());
+        account_infos.push(self.royalty_config.clone());
         account_infos.push(self.token_program.clone());
         remaining_accounts
             .iter()
@@ -329,7 +524,13 @@ impl<'a, 'b> BurnMintAccountCpi<'a, 'b> {
 ///   2. `[writable]` mint
 ///   3. `[writable]` mint_token_account
 ///   4. `[]` manager
-///   5. `[]` token_program
+///   5. `[writable, optional]` group
+///   6. `[writable, optional]` member
+///   7. `[optional]` sub_group
+///   8. `[writable]` approve_account
+///   9. `[writable]` extra_metas_account
+///   10. `[writable]` royalty_config
+///   11. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct BurnMintAccountCpiBuilder<'a, 'b> {
     instruction: Box<BurnMintAccountCpiBuilderInstruction<'a, 'b>>,
@@ -344,6 +545,12 @@ impl<'a, 'b> BurnMintAccountCpiBuilder<'a, 'b> {
             mint: None,
             mint_token_account: None,
             manager: None,
+            group: None,
+            member: None,
+            sub_group: None,
+            approve_account: None,
+            extra_metas_account: None,
+            royalty_config: None,
             token_program: None,
             __remaining_accounts: Vec::new(),
         });
@@ -380,6 +587,57 @@ impl<'a, 'b> BurnMintAccountCpiBuilder<'a, 'b> {
         self.instruction.manager = Some(manager);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn group(
+        &mut self,
+        group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.group = group;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn member(
+        &mut self,
+        member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.member = member;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(
+        &mut self,
+        sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.sub_group = sub_group;
+        self
+    }
+    #[inline(always)]
+    pub fn approve_account(
+        &mut self,
+        approve_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.approve_account = Some(approve_account);
+        self
+    }
+    #[inline(always)]
+    pub fn extra_metas_account(
+        &mut self,
+        extra_metas_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.extra_metas_account = Some(extra_metas_account);
+        self
+    }
+    #[inline(always)]
+    pub fn royalty_config(
+        &mut self,
+        royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.royalty_config = Some(royalty_config);
+        self
+    }
     #[inline(always)]
     pub fn token_program(
         &mut self,
@@ -445,6 +703,27 @@ impl<'a, 'b> BurnMintAccountCpiBuilder<'a, 'b> {
 
             manager: self.instruction.manager.expect("manager is not set"),
 
+            group: self.instruction.group,
+
+            member: self.instruction.member,
+
+            sub_group: self.instruction.sub_group,
+
+            approve_account: self
+                .instruction
+                .approve_account
+                .expect("approve_account is not set"),
+
+            extra_metas_account: self
+                .instruction
+                .extra_metas_account
+                .expect("extra_metas_account is not set"),
+
+            royalty_config: self
+                .instruction
+                .royalty_config
+                .expect("royalty_config is not set"),
+
             token_program: self
                 .instruction
                 .token_program
@@ -465,6 +744,12 @@ struct BurnMintAccountCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    approve_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    extra_metas_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,741 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct Clawback {
+    pub payer: solana_program::pubkey::Pubkey,
+
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub transfer_hook_authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub user: solana_program::pubkey::Pubkey,
+
+    pub recipient: solana_program::pubkey::Pubkey,
+
+    pub mint: solana_program::pubkey::Pubkey,
+
+    pub mint_token_account: solana_program::pubkey::Pubkey,
+
+    pub recipient_token_account: solana_program::pubkey::Pubkey,
+
+    pub manager: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+}
+
+impl Clawback {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        if let Some(transfer_hook_authority) = self.transfer_hook_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                transfer_hook_authority,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.user, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.recipient,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.mint_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.recipient_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = ClawbackInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::WEN_NEW_STANDARD_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ClawbackInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl ClawbackInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [111, 92, 142, 79, 33, 234, 82, 27],
+        }
+    }
+}
+
+impl Default for ClawbackInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `Clawback`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[signer]` authority
+///   2. `[signer, optional]` transfer_hook_authority
+///   3. `[]` user
+///   4. `[]` recipient
+///   5. `[writable]` mint
+///   6. `[writable]` mint_token_account
+///   7. `[writable]` recipient_token_account
+///   8. `[]` manager
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   11. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+#[derive(Clone, Debug, Default)]
+pub struct ClawbackBuilder {
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    transfer_hook_authority: Option<solana_program::pubkey::Pubkey>,
+    user: Option<solana_program::pubkey::Pubkey>,
+    recipient: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    mint_token_account: Option<solana_program::pubkey::Pubkey>,
+    recipient_token_account: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ClawbackBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn transfer_hook_authority(
+        &mut self,
+        transfer_hook_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.transfer_hook_authority = transfer_hook_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn user(&mut self, user: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.user = Some(user);
+        self
+    }
+    #[inline(always)]
+    pub fn recipient(&mut self, recipient: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.recipient = Some(recipient);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn mint_token_account(
+        &mut self,
+        mint_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mint_token_account = Some(mint_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn recipient_token_account(
+        &mut self,
+        recipient_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.recipient_token_account = Some(recipient_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    /// `[optional account, default to 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = Clawback {
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority.expect("authority is not set"),
+            transfer_hook_authority: self.transfer_hook_authority,
+            user: self.user.expect("user is not set"),
+            recipient: self.recipient.expect("recipient is not set"),
+            mint: self.mint.expect("mint is not set"),
+            mint_token_account: self
+                .mint_token_account
+                .expect("mint_token_account is not set"),
+            recipient_token_account: self
+                .recipient_token_account
+                .expect("recipient_token_account is not set"),
+            manager: self.manager.expect("manager is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            associated_token_program: self.associated_token_program.unwrap_or(
+                solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+            ),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `clawback` CPI accounts.
+pub struct ClawbackCpiAccounts<'a, 'b> {
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub transfer_hook_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub user: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipient: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipient_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `clawback` CPI instruction.
+pub struct ClawbackCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub transfer_hook_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub user: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipient: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipient_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> ClawbackCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ClawbackCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            transfer_hook_authority: accounts.transfer_hook_authority,
+            user: accounts.user,
+            recipient: accounts.recipient,
+            mint: accounts.mint,
+            mint_token_account: accounts.mint_token_account,
+            recipient_token_account: accounts.recipient_token_account,
+            manager: accounts.manager,
+            system_program: accounts.system_program,
+            associated_token_program: accounts.associated_token_program,
+            token_program: accounts.token_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        if let Some(transfer_hook_authority) = self.transfer_hook_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *transfer_hook_authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.user.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.recipient.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.mint_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.recipient_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = ClawbackInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::WEN_NEW_STANDARD_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.authority.clone());
+        if let Some(transfer_hook_authority) = self.transfer_hook_authority {
+            account_infos.push(transfer_hook_authority.clone());
+        }
+        account_infos.push(self.user.clone());
+        account_infos.push(self.recipient.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.mint_token_account.clone());
+        account_infos.push(self.recipient_token_account.clone());
+        account_infos.push(self.manager.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `Clawback` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[signer]` authority
+///   2. `[signer, optional]` transfer_hook_authority
+///   3. `[]` user
+///   4. `[]` recipient
+///   5. `[writable]` mint
+///   6. `[writable]` mint_token_account
+///   7. `[writable]` recipient_token_account
+///   8. `[]` manager
+///   9. `[]` system_program
+///   10. `[]` associated_token_program
+///   11. `[]` token_program
+#[derive(Clone, Debug)]
+pub struct ClawbackCpiBuilder<'a, 'b> {
+    instruction: Box<ClawbackCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ClawbackCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ClawbackCpiBuilderInstruction {
+            __program: program,
+            payer: None,
+            authority: None,
+            transfer_hook_authority: None,
+            user: None,
+            recipient: None,
+            mint: None,
+            mint_token_account: None,
+            recipient_token_account: None,
+            manager: None,
+            system_program: None,
+            associated_token_program: None,
+            token_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn transfer_hook_authority(
+        &mut self,
+        transfer_hook_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.transfer_hook_authority = transfer_hook_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn user(&mut self, user: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.user = Some(user);
+        self
+    }
+    #[inline(always)]
+    pub fn recipient(
+        &mut self,
+        recipient: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.recipient = Some(recipient);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn mint_token_account(
+        &mut self,
+        mint_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mint_token_account = Some(mint_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn recipient_token_account(
+        &mut self,
+        recipient_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.recipient_token_account = Some(recipient_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = ClawbackCpi {
+            __program: self.instruction.__program,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            transfer_hook_authority: self.instruction.transfer_hook_authority,
+
+            user: self.instruction.user.expect("user is not set"),
+
+            recipient: self.instruction.recipient.expect("recipient is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            mint_token_account: self
+                .instruction
+                .mint_token_account
+                .expect("mint_token_account is not set"),
+
+            recipient_token_account: self
+                .instruction
+                .recipient_token_account
+                .expect("recipient_token_account is not set"),
+
+            manager: self.instruction.manager.expect("manager is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ClawbackCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    transfer_hook_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    user: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipient_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -122,7 +122,9 @@ pub struct CreateGroupAccountInstructionArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub max_size: u32,
+    pub max_size: Option<u32>,
+    pub lock_max_size: bool,
+    pub advisory_member_update_gate: bool,
 }
 
 /// Instruction builder for `CreateGroupAccount`.
@@ -155,6 +157,8 @@ pub struct CreateGroupAccountBuilder {
     symbol: Option<String>,
     uri: Option<String>,
     max_size: Option<u32>,
+    lock_max_size: Option<bool>,
+    advisory_member_update_gate: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -236,11 +240,22 @@ impl CreateGroupAccountBuilder {
         self.uri = Some(uri);
         self
     }
+    /// `[optional argument]`
     #[inline(always)]
     pub fn max_size(&mut self, max_size: u32) -> &mut Self {
         self.max_size = Some(max_size);
         self
     }
+    #[inline(always)]
+    pub fn lock_max_size(&mut self, lock_max_size: bool) -> &mut Self {
+        self.lock_max_size = Some(lock_max_size);
+        self
+    }
+    #[inline(always)]
+    pub fn advisory_member_update_gate(&mut self, advisory_member_update_gate: bool) -> &mut Self {
+        self.advisory_member_update_gate = Some(advisory_member_update_gate);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -285,7 +300,15 @@ impl CreateGroupAccountBuilder {
             name: self.name.clone().expect("name is not set"),
             symbol: self.symbol.clone().expect("symbol is not set"),
             uri: self.uri.clone().expect("uri is not set"),
-            max_size: self.max_size.clone().expect("max_size is not set"),
+            max_size: self.max_size.clone(),
+            lock_max_size: self
+                .lock_max_size
+                .clone()
+                .expect("lock_max_size is not set"),
+            advisory_member_update_gate: self
+                .advisory_member_update_gate
+                .clone()
+                .expect("advisory_member_update_gate is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -517,6 +540,8 @@ impl<'a, 'b> CreateGroupAccountCpiBuilder<'a, 'b> {
             symbol: None,
             uri: None,
             max_size: None,
+            lock_max_size: None,
+            advisory_member_update_gate: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -607,11 +632,22 @@ impl<'a, 'b> CreateGroupAccountCpiBuilder<'a, 'b> {
         self.instruction.uri = Some(uri);
         self
     }
+    /// `[optional argument]`
     #[inline(always)]
     pub fn max_size(&mut self, max_size: u32) -> &mut Self {
         self.instruction.max_size = Some(max_size);
         self
     }
+    #[inline(always)]
+    pub fn lock_max_size(&mut self, lock_max_size: bool) -> &mut Self {
+        self.instruction.lock_max_size = Some(lock_max_size);
+        self
+    }
+    #[inline(always)]
+    pub fn advisory_member_update_gate(&mut self, advisory_member_update_gate: bool) -> &mut Self {
+        self.instruction.advisory_member_update_gate = Some(advisory_member_update_gate);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -657,11 +693,17 @@ impl<'a, 'b> CreateGroupAccountCpiBuilder<'a, 'b> {
             name: self.instruction.name.clone().expect("name is not set"),
             symbol: self.instruction.symbol.clone().expect("symbol is not set"),
             uri: self.instruction.uri.clone().expect("uri is not set"),
-            max_size: self
+            max_size: self.instruction.max_size.clone(),
+            lock_max_size: self
+                .instruction
+                .lock_max_size
+                .clone()
+                .expect("lock_max_size is not set"),
+            advisory_member_update_gate: self
                 .instruction
-                .max_size
+                .advisory_member_update_gate
                 .clone()
-                .expect("max_size is not set"),
+                .expect("advisory_member_update_gate is not set"),
         };
         let instruction = CreateGroupAccountCpi {
             __program: self.instruction.__program,
@@ -723,6 +765,8 @@ struct CreateGroupAccountCpiBuilderInstruction<'a, 'b> {
     symbol: Option<String>,
     uri: Option<String>,
     max_size: Option<u32>,
+    lock_max_size: Option<bool>,
+    advisory_member_update_gate: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -119,6 +119,7 @@ pub struct CreateMintAccountInstructionArgs {
     pub symbol: String,
     pub uri: String,
     pub permanent_delegate: Option<Pubkey>,
+    pub soulbound: bool,
 }
 
 /// Instruction builder for `CreateMintAccount`.
@@ -149,6 +150,7 @@ pub struct CreateMintAccountBuilder {
     symbol: Option<String>,
     uri: Option<String>,
     permanent_delegate: Option<Pubkey>,
+    soulbound: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -231,6 +233,11 @@ impl CreateMintAccountBuilder {
         self.permanent_delegate = Some(permanent_delegate);
         self
     }
+    #[inline(always)]
+    pub fn soulbound(&mut self, soulbound: bool) -> &mut Self {
+        self.soulbound = Some(soulbound);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -275,6 +282,7 @@ impl CreateMintAccountBuilder {
             symbol: self.symbol.clone().expect("symbol is not set"),
             uri: self.uri.clone().expect("uri is not set"),
             permanent_delegate: self.permanent_delegate.clone(),
+            soulbound: self.soulbound.clone().expect("soulbound is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -494,6 +502,7 @@ impl<'a, 'b> CreateMintAccountCpiBuilder<'a, 'b> {
             symbol: None,
             uri: None,
             permanent_delegate: None,
+            soulbound: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -585,6 +594,11 @@ impl<'a, 'b> CreateMintAccountCpiBuilder<'a, 'b> {
         self.instruction.permanent_delegate = Some(permanent_delegate);
         self
     }
+    #[inline(always)]
+    pub fn soulbound(&mut self, soulbound: bool) -> &mut Self {
+        self.instruction.soulbound = Some(soulbound);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -631,6 +645,11 @@ impl<'a, 'b> CreateMintAccountCpiBuilder<'a, 'b> {
             symbol: self.instruction.symbol.clone().expect("symbol is not set"),
             uri: self.instruction.uri.clone().expect("uri is not set"),
             permanent_delegate: self.instruction.permanent_delegate.clone(),
+            soulbound: self
+                .instruction
+                .soulbound
+                .clone()
+                .expect("soulbound is not set"),
         };
         let instruction = CreateMintAccountCpi {
             __program: self.instruction.__program,
@@ -689,6 +708,7 @@ struct CreateMintAccountCpiBuilderInstruction<'a, 'b> {
     symbol: Option<String>,
     uri: Option<String>,
     permanent_delegate: Option<Pubkey>,
+    soulbound: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -12,8 +12,14 @@ use borsh::BorshSerialize;
 pub struct InitManagerAccount {
     pub payer: solana_program::pubkey::Pubkey,
 
+    pub upgrade_authority: solana_program::pubkey::Pubkey,
+
     pub manager: solana_program::pubkey::Pubkey,
 
+    pub program: solana_program::pubkey::Pubkey,
+
+    pub program_data: solana_program::pubkey::Pubkey,
+
     pub system_program: solana_program::pubkey::Pubkey,
 }
 
@@ -26,14 +32,26 @@ impl InitManagerAccount {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.upgrade_authority,
+            true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.manager,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program_data,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -75,12 +93,18 @@ impl Default for InitManagerAccountInstructionData {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` payer
-///   1. `[writable]` manager
-///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   1. `[signer]` upgrade_authority
+///   2. `[writable]` manager
+///   3. `[optional]` program (default to `wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM`)
+///   4. `[]` program_data
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct InitManagerAccountBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
+    upgrade_authority: Option<solana_program::pubkey::Pubkey>,
     manager: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    program_data: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -95,10 +119,29 @@ impl InitManagerAccountBuilder {
         self
     }
     #[inline(always)]
+    pub fn upgrade_authority(
+        &mut self,
+        upgrade_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.upgrade_authority = Some(upgrade_authority);
+        self
+    }
+    #[inline(always)]
     pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
         self.manager = Some(manager);
         self
     }
+    /// `[optional account, default to 'wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn program_data(&mut self, program_data: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -127,7 +170,14 @@ impl InitManagerAccountBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = InitManagerAccount {
             payer: self.payer.expect("payer is not set"),
+            upgrade_authority: self
+                .upgrade_authority
+                .expect("upgrade_authority is not set"),
             manager: self.manager.expect("manager is not set"),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM"
+            )),
+            program_data: self.program_data.expect("program_data is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -141,8 +191,14 @@ impl InitManagerAccountBuilder {
 pub struct InitManagerAccountCpiAccounts<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub upgrade_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program_data: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -153,8 +209,14 @@ pub struct InitManagerAccountCpi<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub upgrade_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program_data: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -166,7 +228,10 @@ impl<'a, 'b> InitManagerAccountCpi<'a, 'b> {
         Self {
             __program: program,
             payer: accounts.payer,
+            upgrade_authority: accounts.upgrade_authority,
             manager: accounts.manager,
+            program: accounts.program,
+            program_data: accounts.program_data,
             system_program: accounts.system_program,
         }
     }
@@ -203,15 +268,27 @@ impl<'a, 'b> InitManagerAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.upgrade_authority.key,
+            true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.manager.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program_data.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -232,10 +309,13 @@ impl<'a, 'b> InitManagerAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
+        account_infos.push(self.upgrade_authority.clone());
         account_infos.push(self.manager.clone());
+        account_infos.push(self.program.clone());
+        account_infos.push(self.program_data.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
@@ -254,8 +334,11 @@ impl<'a, 'b> InitManagerAccountCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` payer
-///   1. `[writable]` manager
-///   2. `[]` system_program
+///   1. `[signer]` upgrade_authority
+///   2. `[writable]` manager
+///   3. `[]` program
+///   4. `[]` program_data
+///   5. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct InitManagerAccountCpiBuilder<'a, 'b> {
     instruction: Box<InitManagerAccountCpiBuilderInstruction<'a, 'b>>,
@@ -266,7 +349,10 @@ impl<'a, 'b> InitManagerAccountCpiBuilder<'a, 'b> {
         let instruction = Box::new(InitManagerAccountCpiBuilderInstruction {
             __program: program,
             payer: None,
+            upgrade_authority: None,
             manager: None,
+            program: None,
+            program_data: None,
             system_program: None,
             __remaining_accounts: Vec::new(),
         });
@@ -278,6 +364,14 @@ impl<'a, 'b> InitManagerAccountCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn upgrade_authority(
+        &mut self,
+        upgrade_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.upgrade_authority = Some(upgrade_authority);
+        self
+    }
+    #[inline(always)]
     pub fn manager(
         &mut self,
         manager: &'b solana_program::account_info::AccountInfo<'a>,
@@ -286,6 +380,22 @@ impl<'a, 'b> InitManagerAccountCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn program_data(
+        &mut self,
+        program_data: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program_data = Some(program_data);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -339,8 +449,20 @@ impl<'a, 'b> InitManagerAccountCpiBuilder<'a, 'b> {
 
             payer: self.instruction.payer.expect("payer is not set"),
 
+            upgrade_authority: self
+                .instruction
+                .upgrade_authority
+                .expect("upgrade_authority is not set"),
+
             manager: self.instruction.manager.expect("manager is not set"),
 
+            program: self.instruction.program.expect("program is not set"),
+
+            program_data: self
+                .instruction
+                .program_data
+                .expect("program_data is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -357,7 +479,10 @@ impl<'a, 'b> InitManagerAccountCpiBuilder<'a, 'b> {
 struct InitManagerAccountCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    upgrade_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program_data: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
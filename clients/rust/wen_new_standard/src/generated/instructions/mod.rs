@@ -10,29 +10,37 @@ pub(crate) mod r#add_mint_to_group;
 pub(crate) mod r#add_royalties;
 pub(crate) mod r#approve_transfer;
 pub(crate) mod r#burn_mint_account;
+pub(crate) mod r#clawback;
 pub(crate) mod r#create_group_account;
 pub(crate) mod r#create_mint_account;
 pub(crate) mod r#execute;
 pub(crate) mod r#freeze_mint_account;
+pub(crate) mod r#inherit_royalties;
 pub(crate) mod r#init_manager_account;
 pub(crate) mod r#modify_royalties;
 pub(crate) mod r#remove_metadata;
 pub(crate) mod r#remove_mint_from_group;
 pub(crate) mod r#thaw_mint_account;
+pub(crate) mod r#transfer_member;
 pub(crate) mod r#update_group_account;
+pub(crate) mod r#update_mint_account;
 
 pub use self::r#add_metadata::*;
 pub use self::r#add_mint_to_group::*;
 pub use self::r#add_royalties::*;
 pub use self::r#approve_transfer::*;
 pub use self::r#burn_mint_account::*;
+pub use self::r#clawback::*;
 pub use self::r#create_group_account::*;
 pub use self::r#create_mint_account::*;
 pub use self::r#execute::*;
 pub use self::r#freeze_mint_account::*;
+pub use self::r#inherit_royalties::*;
 pub use self::r#init_manager_account::*;
 pub use self::r#modify_royalties::*;
 pub use self::r#remove_metadata::*;
 pub use self::r#remove_mint_from_group::*;
 pub use self::r#thaw_mint_account::*;
+pub use self::r#transfer_member::*;
 pub use self::r#update_group_account::*;
+pub use self::r#update_mint_account::*;
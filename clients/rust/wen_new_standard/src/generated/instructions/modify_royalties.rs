@@ -17,6 +17,10 @@ pub struct ModifyRoyalties {
 
     pub mint: solana_program::pubkey::Pubkey,
 
+    pub royalty_config: solana_program::pubkey::Pubkey,
+
+    pub manager: solana_program::pubkey::Pubkey,
+
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
@@ -35,7 +39,7 @@ impl ModifyRoyalties {
         args: ModifyRoyaltiesInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -46,6 +50,14 @@ impl ModifyRoyalties {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.royalty_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.manager,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -99,13 +111,17 @@ pub struct ModifyRoyaltiesInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[writable]` mint
-///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   4. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   3. `[writable]` royalty_config
+///   4. `[]` manager
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct ModifyRoyaltiesBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    royalty_config: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     args: Option<UpdateRoyaltiesArgs>,
@@ -131,6 +147,16 @@ impl ModifyRoyaltiesBuilder {
         self.mint = Some(mint);
         self
     }
+    #[inline(always)]
+    pub fn royalty_config(&mut self, royalty_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.royalty_config = Some(royalty_config);
+        self
+    }
+    #[inline(always)]
+    pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -172,6 +198,8 @@ impl ModifyRoyaltiesBuilder {
             payer: self.payer.expect("payer is not set"),
             authority: self.authority.expect("authority is not set"),
             mint: self.mint.expect("mint is not set"),
+            royalty_config: self.royalty_config.expect("royalty_config is not set"),
+            manager: self.manager.expect("manager is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -195,6 +223,10 @@ pub struct ModifyRoyaltiesCpiAccounts<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -211,6 +243,10 @@ pub struct ModifyRoyaltiesCpi<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -229,6 +265,8 @@ impl<'a, 'b> ModifyRoyaltiesCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             mint: accounts.mint,
+            royalty_config: accounts.royalty_config,
+            manager: accounts.manager,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             __args: args,
@@ -267,7 +305,7 @@ impl<'a, 'b> ModifyRoyaltiesCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -280,6 +318,14 @@ impl<'a, 'b> ModifyRoyaltiesCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.royalty_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.manager.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -304,11 +350,13 @@ impl<'a, 'b> ModifyRoyaltiesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint.clone());
+        account_infos.push(self.royalty_config.clone());
+        account_infos.push(self.manager.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         remaining_accounts
@@ -330,8 +378,10 @@ impl<'a, 'b> ModifyRoyaltiesCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[writable]` mint
-///   3. `[]` system_program
-///   4. `[]` token_program
+///   3. `[writable]` royalty_config
+///   4. `[]` manager
+///   5. `[]` system_program
+///   6. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct ModifyRoyaltiesCpiBuilder<'a, 'b> {
     instruction: Box<ModifyRoyaltiesCpiBuilderInstruction<'a, 'b>>,
@@ -344,6 +394,8 @@ impl<'a, 'b> ModifyRoyaltiesCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             mint: None,
+            royalty_config: None,
+            manager: None,
             system_program: None,
             token_program: None,
             args: None,
@@ -370,6 +422,22 @@ impl<'a, 'b> ModifyRoyaltiesCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn royalty_config(
+        &mut self,
+        royalty_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.royalty_config = Some(royalty_config);
+        self
+    }
+    #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -443,6 +511,13 @@ impl<'a, 'b> ModifyRoyaltiesCpiBuilder<'a, 'b> {
 
             mint: self.instruction.mint.expect("mint is not set"),
 
+            royalty_config: self
+                .instruction
+                .royalty_config
+                .expect("royalty_config is not set"),
+
+            manager: self.instruction.manager.expect("manager is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -467,6 +542,8 @@ struct ModifyRoyaltiesCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     args: Option<UpdateRoyaltiesArgs>,
@@ -17,6 +17,8 @@ pub struct RemoveMetadata {
 
     pub mint: solana_program::pubkey::Pubkey,
 
+    pub manager: solana_program::pubkey::Pubkey,
+
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
@@ -35,7 +37,7 @@ impl RemoveMetadata {
         args: RemoveMetadataInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -46,6 +48,10 @@ impl RemoveMetadata {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.manager,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -99,13 +105,15 @@ pub struct RemoveMetadataInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[writable]` mint
-///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   4. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   3. `[]` manager
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct RemoveMetadataBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     args: Option<Vec<RemoveMetadataArgs>>,
@@ -131,6 +139,11 @@ impl RemoveMetadataBuilder {
         self.mint = Some(mint);
         self
     }
+    #[inline(always)]
+    pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -172,6 +185,7 @@ impl RemoveMetadataBuilder {
             payer: self.payer.expect("payer is not set"),
             authority: self.authority.expect("authority is not set"),
             mint: self.mint.expect("mint is not set"),
+            manager: self.manager.expect("manager is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -195,6 +209,8 @@ pub struct RemoveMetadataCpiAccounts<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -211,6 +227,8 @@ pub struct RemoveMetadataCpi<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -229,6 +247,7 @@ impl<'a, 'b> RemoveMetadataCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             mint: accounts.mint,
+            manager: accounts.manager,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             __args: args,
@@ -267,7 +286,7 @@ impl<'a, 'b> RemoveMetadataCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -280,6 +299,10 @@ impl<'a, 'b> RemoveMetadataCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.manager.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -304,11 +327,12 @@ impl<'a, 'b> RemoveMetadataCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint.clone());
+        account_infos.push(self.manager.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         remaining_accounts
@@ -330,8 +354,9 @@ impl<'a, 'b> RemoveMetadataCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[writable]` mint
-///   3. `[]` system_program
-///   4. `[]` token_program
+///   3. `[]` manager
+///   4. `[]` system_program
+///   5. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct RemoveMetadataCpiBuilder<'a, 'b> {
     instruction: Box<RemoveMetadataCpiBuilderInstruction<'a, 'b>>,
@@ -344,6 +369,7 @@ impl<'a, 'b> RemoveMetadataCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             mint: None,
+            manager: None,
             system_program: None,
             token_program: None,
             args: None,
@@ -370,6 +396,14 @@ impl<'a, 'b> RemoveMetadataCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -443,6 +477,8 @@ impl<'a, 'b> RemoveMetadataCpiBuilder<'a, 'b> {
 
             mint: self.instruction.mint.expect("mint is not set"),
 
+            manager: self.instruction.manager.expect("manager is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -467,6 +503,7 @@ struct RemoveMetadataCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     args: Option<Vec<RemoveMetadataArgs>>,
@@ -20,6 +20,8 @@ pub struct RemoveMintFromGroup {
 
     pub mint: solana_program::pubkey::Pubkey,
 
+    pub sub_group: Option<solana_program::pubkey::Pubkey>,
+
     pub manager: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
@@ -36,7 +38,7 @@ impl RemoveMintFromGroup {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -54,6 +56,16 @@ impl RemoveMintFromGroup {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                sub_group, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.manager,
             false,
@@ -107,9 +119,10 @@ impl Default for RemoveMintFromGroupInstructionData {
 ///   2. `[writable]` group
 ///   3. `[writable]` member
 ///   4. `[writable]` mint
-///   5. `[]` manager
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   7. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   5. `[optional]` sub_group
+///   6. `[]` manager
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct RemoveMintFromGroupBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
@@ -117,6 +130,7 @@ pub struct RemoveMintFromGroupBuilder {
     group: Option<solana_program::pubkey::Pubkey>,
     member: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    sub_group: Option<solana_program::pubkey::Pubkey>,
     manager: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
@@ -152,6 +166,12 @@ impl RemoveMintFromGroupBuilder {
         self.mint = Some(mint);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(&mut self, sub_group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.sub_group = sub_group;
+        self
+    }
     #[inline(always)]
     pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
         self.manager = Some(manager);
@@ -195,6 +215,7 @@ impl RemoveMintFromGroupBuilder {
             group: self.group.expect("group is not set"),
             member: self.member.expect("member is not set"),
             mint: self.mint.expect("mint is not set"),
+            sub_group: self.sub_group,
             manager: self.manager.expect("manager is not set"),
             system_program: self
                 .system_program
@@ -220,6 +241,8 @@ pub struct RemoveMintFromGroupCpiAccounts<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -242,6 +265,8 @@ pub struct RemoveMintFromGroupCpi<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -261,6 +286,7 @@ impl<'a, 'b> RemoveMintFromGroupCpi<'a, 'b> {
             group: accounts.group,
             member: accounts.member,
             mint: accounts.mint,
+            sub_group: accounts.sub_group,
             manager: accounts.manager,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
@@ -299,7 +325,7 @@ impl<'a, 'b> RemoveMintFromGroupCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -320,6 +346,17 @@ impl<'a, 'b> RemoveMintFromGroupCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *sub_group.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.manager.key,
             false,
@@ -348,13 +385,16 @@ impl<'a, 'b> RemoveMintFromGroupCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.group.clone());
         account_infos.push(self.member.clone());
         account_infos.push(self.mint.clone());
+        if let Some(sub_group) = self.sub_group {
+            account_infos.push(sub_group.clone());
+        }
         account_infos.push(self.manager.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
@@ -379,9 +419,10 @@ impl<'a, 'b> RemoveMintFromGroupCpi<'a, 'b> {
 ///   2. `[writable]` group
 ///   3. `[writable]` member
 ///   4. `[writable]` mint
-///   5. `[]` manager
-///   6. `[]` system_program
-///   7. `[]` token_program
+///   5. `[optional]` sub_group
+///   6. `[]` manager
+///   7. `[]` system_program
+///   8. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct RemoveMintFromGroupCpiBuilder<'a, 'b> {
     instruction: Box<RemoveMintFromGroupCpiBuilderInstruction<'a, 'b>>,
@@ -396,6 +437,7 @@ impl<'a, 'b> RemoveMintFromGroupCpiBuilder<'a, 'b> {
             group: None,
             member: None,
             mint: None,
+            sub_group: None,
             manager: None,
             system_program: None,
             token_program: None,
@@ -434,6 +476,15 @@ impl<'a, 'b> RemoveMintFromGroupCpiBuilder<'a, 'b> {
         self.instruction.mint = Some(mint);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(
+        &mut self,
+        sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.sub_group = sub_group;
+        self
+    }
     #[inline(always)]
     pub fn manager(
         &mut self,
@@ -512,6 +563,8 @@ impl<'a, 'b> RemoveMintFromGroupCpiBuilder<'a, 'b> {
 
             mint: self.instruction.mint.expect("mint is not set"),
 
+            sub_group: self.instruction.sub_group,
+
             manager: self.instruction.manager.expect("manager is not set"),
 
             system_program: self
@@ -539,6 +592,7 @@ struct RemoveMintFromGroupCpiBuilderInstruction<'a, 'b> {
     group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
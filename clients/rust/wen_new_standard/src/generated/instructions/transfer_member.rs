@@ -0,0 +1,703 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct TransferMember {
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub new_authority: solana_program::pubkey::Pubkey,
+
+    pub group: solana_program::pubkey::Pubkey,
+
+    pub new_group: solana_program::pubkey::Pubkey,
+
+    pub member: solana_program::pubkey::Pubkey,
+
+    pub mint: solana_program::pubkey::Pubkey,
+
+    pub sub_group: Option<solana_program::pubkey::Pubkey>,
+
+    pub asset_authority: solana_program::pubkey::Pubkey,
+
+    pub asset_token_account: Option<solana_program::pubkey::Pubkey>,
+
+    pub manager: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+}
+
+impl TransferMember {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.new_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.group, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.new_group,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.member,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                sub_group, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset_authority,
+            true,
+        ));
+        if let Some(asset_token_account) = self.asset_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                asset_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = TransferMemberInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::WEN_NEW_STANDARD_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TransferMemberInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl TransferMemberInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [111, 133, 45, 239, 224, 235, 109, 82],
+        }
+    }
+}
+
+impl Default for TransferMemberInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `TransferMember`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[signer]` new_authority
+///   2. `[writable]` group
+///   3. `[writable]` new_group
+///   4. `[writable]` member
+///   5. `[]` mint
+///   6. `[optional]` sub_group
+///   7. `[signer]` asset_authority
+///   8. `[optional]` asset_token_account
+///   9. `[]` manager
+///   10. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+#[derive(Clone, Debug, Default)]
+pub struct TransferMemberBuilder {
+    authority: Option<solana_program::pubkey::Pubkey>,
+    new_authority: Option<solana_program::pubkey::Pubkey>,
+    group: Option<solana_program::pubkey::Pubkey>,
+    new_group: Option<solana_program::pubkey::Pubkey>,
+    member: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    sub_group: Option<solana_program::pubkey::Pubkey>,
+    asset_authority: Option<solana_program::pubkey::Pubkey>,
+    asset_token_account: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl TransferMemberBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn new_authority(&mut self, new_authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn group(&mut self, group: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.group = Some(group);
+        self
+    }
+    #[inline(always)]
+    pub fn new_group(&mut self, new_group: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.new_group = Some(new_group);
+        self
+    }
+    #[inline(always)]
+    pub fn member(&mut self, member: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.member = Some(member);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(&mut self, sub_group: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.sub_group = sub_group;
+        self
+    }
+    #[inline(always)]
+    pub fn asset_authority(
+        &mut self,
+        asset_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.asset_authority = Some(asset_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn asset_token_account(
+        &mut self,
+        asset_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.asset_token_account = asset_token_account;
+        self
+    }
+    #[inline(always)]
+    pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
+    /// `[optional account, default to 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = TransferMember {
+            authority: self.authority.expect("authority is not set"),
+            new_authority: self.new_authority.expect("new_authority is not set"),
+            group: self.group.expect("group is not set"),
+            new_group: self.new_group.expect("new_group is not set"),
+            member: self.member.expect("member is not set"),
+            mint: self.mint.expect("mint is not set"),
+            sub_group: self.sub_group,
+            asset_authority: self.asset_authority.expect("asset_authority is not set"),
+            asset_token_account: self.asset_token_account,
+            manager: self.manager.expect("manager is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `transfer_member` CPI accounts.
+pub struct TransferMemberCpiAccounts<'a, 'b> {
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub new_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub new_group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub member: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub asset_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `transfer_member` CPI instruction.
+pub struct TransferMemberCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub new_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub new_group: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub member: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub asset_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> TransferMemberCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: TransferMemberCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            new_authority: accounts.new_authority,
+            group: accounts.group,
+            new_group: accounts.new_group,
+            member: accounts.member,
+            mint: accounts.mint,
+            sub_group: accounts.sub_group,
+            asset_authority: accounts.asset_authority,
+            asset_token_account: accounts.asset_token_account,
+            manager: accounts.manager,
+            token_program: accounts.token_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.new_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.group.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.new_group.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.member.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        if let Some(sub_group) = self.sub_group {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *sub_group.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset_authority.key,
+            true,
+        ));
+        if let Some(asset_token_account) = self.asset_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *asset_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::WEN_NEW_STANDARD_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = TransferMemberInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::WEN_NEW_STANDARD_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.new_authority.clone());
+        account_infos.push(self.group.clone());
+        account_infos.push(self.new_group.clone());
+        account_infos.push(self.member.clone());
+        account_infos.push(self.mint.clone());
+        if let Some(sub_group) = self.sub_group {
+            account_infos.push(sub_group.clone());
+        }
+        account_infos.push(self.asset_authority.clone());
+        if let Some(asset_token_account) = self.asset_token_account {
+            account_infos.push(asset_token_account.clone());
+        }
+        account_infos.push(self.manager.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `TransferMember` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[signer]` new_authority
+///   2. `[writable]` group
+///   3. `[writable]` new_group
+///   4. `[writable]` member
+///   5. `[]` mint
+///   6. `[optional]` sub_group
+///   7. `[signer]` asset_authority
+///   8. `[optional]` asset_token_account
+///   9. `[]` manager
+///   10. `[]` token_program
+#[derive(Clone, Debug)]
+pub struct TransferMemberCpiBuilder<'a, 'b> {
+    instruction: Box<TransferMemberCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> TransferMemberCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(TransferMemberCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            new_authority: None,
+            group: None,
+            new_group: None,
+            member: None,
+            mint: None,
+            sub_group: None,
+            asset_authority: None,
+            asset_token_account: None,
+            manager: None,
+            token_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_authority = Some(new_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn group(&mut self, group: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.group = Some(group);
+        self
+    }
+    #[inline(always)]
+    pub fn new_group(
+        &mut self,
+        new_group: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_group = Some(new_group);
+        self
+    }
+    #[inline(always)]
+    pub fn member(
+        &mut self,
+        member: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.member = Some(member);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn sub_group(
+        &mut self,
+        sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.sub_group = sub_group;
+        self
+    }
+    #[inline(always)]
+    pub fn asset_authority(
+        &mut self,
+        asset_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.asset_authority = Some(asset_authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn asset_token_account(
+        &mut self,
+        asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.asset_token_account = asset_token_account;
+        self
+    }
+    #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = TransferMemberCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            new_authority: self
+                .instruction
+                .new_authority
+                .expect("new_authority is not set"),
+
+            group: self.instruction.group.expect("group is not set"),
+
+            new_group: self.instruction.new_group.expect("new_group is not set"),
+
+            member: self.instruction.member.expect("member is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            sub_group: self.instruction.sub_group,
+
+            asset_authority: self
+                .instruction
+                .asset_authority
+                .expect("asset_authority is not set"),
+
+            asset_token_account: self.instruction.asset_token_account,
+
+            manager: self.instruction.manager.expect("manager is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TransferMemberCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    member: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    sub_group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -18,6 +18,8 @@ pub struct UpdateGroupAccount {
 
     pub mint: solana_program::pubkey::Pubkey,
 
+    pub manager: solana_program::pubkey::Pubkey,
+
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
@@ -36,13 +38,13 @@ impl UpdateGroupAccount {
         args: UpdateGroupAccountInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.authority,
-            false,
+            true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.group, false,
@@ -50,6 +52,10 @@ impl UpdateGroupAccount {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.manager,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -98,7 +104,9 @@ pub struct UpdateGroupAccountInstructionArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub max_size: u32,
+    pub max_size: Option<u32>,
+    pub lock_max_size: bool,
+    pub advisory_member_update_gate: bool,
 }
 
 /// Instruction builder for `UpdateGroupAccount`.
@@ -106,23 +114,27 @@ pub struct UpdateGroupAccountInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` payer
-///   1. `[]` authority
+///   1. `[signer]` authority
 ///   2. `[writable]` group
 ///   3. `[writable]` mint
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   4. `[]` manager
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 #[derive(Clone, Debug, Default)]
 pub struct UpdateGroupAccountBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     group: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    manager: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     symbol: Option<String>,
     uri: Option<String>,
     max_size: Option<u32>,
+    lock_max_size: Option<bool>,
+    advisory_member_update_gate: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -150,6 +162,11 @@ impl UpdateGroupAccountBuilder {
         self.mint = Some(mint);
         self
     }
+    #[inline(always)]
+    pub fn manager(&mut self, manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.manager = Some(manager);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -177,11 +194,22 @@ impl UpdateGroupAccountBuilder {
         self.uri = Some(uri);
         self
     }
+    /// `[optional argument]`
     #[inline(always)]
     pub fn max_size(&mut self, max_size: u32) -> &mut Self {
         self.max_size = Some(max_size);
         self
     }
+    #[inline(always)]
+    pub fn lock_max_size(&mut self, lock_max_size: bool) -> &mut Self {
+        self.lock_max_size = Some(lock_max_size);
+        self
+    }
+    #[inline(always)]
+    pub fn advisory_member_update_gate(&mut self, advisory_member_update_gate: bool) -> &mut Self {
+        self.advisory_member_update_gate = Some(advisory_member_update_gate);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -207,6 +235,7 @@ impl UpdateGroupAccountBuilder {
             authority: self.authority.expect("authority is not set"),
             group: self.group.expect("group is not set"),
             mint: self.mint.expect("mint is not set"),
+            manager: self.manager.expect("manager is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -218,7 +247,15 @@ impl UpdateGroupAccountBuilder {
             name: self.name.clone().expect("name is not set"),
             symbol: self.symbol.clone().expect("symbol is not set"),
             uri: self.uri.clone().expect("uri is not set"),
-            max_size: self.max_size.clone().expect("max_size is not set"),
+            max_size: self.max_size.clone(),
+            lock_max_size: self
+                .lock_max_size
+                .clone()
+                .expect("lock_max_size is not set"),
+            advisory_member_update_gate: self
+                .advisory_member_update_gate
+                .clone()
+                .expect("advisory_member_update_gate is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -235,6 +272,8 @@ pub struct UpdateGroupAccountCpiAccounts<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -253,6 +292,8 @@ pub struct UpdateGroupAccountCpi<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -272,6 +313,7 @@ impl<'a, 'b> UpdateGroupAccountCpi<'a, 'b> {
             authority: accounts.authority,
             group: accounts.group,
             mint: accounts.mint,
+            manager: accounts.manager,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             __args: args,
@@ -310,14 +352,14 @@ impl<'a, 'b> UpdateGroupAccountCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.authority.key,
-            false,
+            true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.group.key,
@@ -327,6 +369,10 @@ impl<'a, 'b> UpdateGroupAccountCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.manager.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -353,12 +399,13 @@ impl<'a, 'b> UpdateGroupAccountCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.group.clone());
         account_infos.push(self.mint.clone());
+        account_infos.push(self.manager.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         remaining_accounts
@@ -378,11 +425,12 @@ impl<'a, 'b> UpdateGroupAccountCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` payer
-///   1. `[]` authority
+///   1. `[signer]` authority
 ///   2. `[writable]` group
 ///   3. `[writable]` mint
-///   4. `[]` system_program
-///   5. `[]` token_program
+///   4. `[]` manager
+///   5. `[]` system_program
+///   6. `[]` token_program
 #[derive(Clone, Debug)]
 pub struct UpdateGroupAccountCpiBuilder<'a, 'b> {
     instruction: Box<UpdateGroupAccountCpiBuilderInstruction<'a, 'b>>,
@@ -396,12 +444,15 @@ impl<'a, 'b> UpdateGroupAccountCpiBuilder<'a, 'b> {
             authority: None,
             group: None,
             mint: None,
+            manager: None,
             system_program: None,
             token_program: None,
             name: None,
             symbol: None,
             uri: None,
             max_size: None,
+            lock_max_size: None,
+            advisory_member_update_gate: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -430,6 +481,14 @@ impl<'a, 'b> UpdateGroupAccountCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn manager(
+        &mut self,
+        manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.manager = Some(manager);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -460,11 +519,22 @@ impl<'a, 'b> UpdateGroupAccountCpiBuilder<'a, 'b> {
         self.instruction.uri = Some(uri);
         self
     }
+    /// `[optional argument]`
     #[inline(always)]
     pub fn max_size(&mut self, max_size: u32) -> &mut Self {
         self.instruction.max_size = Some(max_size);
         self
     }
+    #[inline(always)]
+    pub fn lock_max_size(&mut self, lock_max_size: bool) -> &mut Self {
+        self.instruction.lock_max_size = Some(lock_max_size);
+        self
+    }
+    #[inline(always)]
+    pub fn advisory_member_update_gate(&mut self, advisory_member_update_gate: bool) -> &mut Self {
+        self.instruction.advisory_member_update_gate = Some(advisory_member_update_gate);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -510,11 +580,17 @@ impl<'a, 'b> UpdateGroupAccountCpiBuilder<'a, 'b> {
             name: self.instruction.name.clone().expect("name is not set"),
             symbol: self.instruction.symbol.clone().expect("symbol is not set"),
             uri: self.instruction.uri.clone().expect("uri is not set"),
-            max_size: self
+            max_size: self.instruction.max_size.clone(),
+            lock_max_size: self
+                .instruction
+                .lock_max_size
+                .clone()
+                .expect("lock_max_size is not set"),
+            advisory_member_update_gate: self
                 .instruction
-                .max_size
+                .advisory_member_update_gate
                 .clone()
-                .expect("max_size is not set"),
+                .expect("advisory_member_update_gate is not set"),
         };
         let instruction = UpdateGroupAccountCpi {
             __program: self.instruction.__program,
@@ -527,6 +603,8 @@ impl<'a, 'b> UpdateGroupAccountCpiBuilder<'a, 'b> {
 
             mint: self.instruction.mint.expect("mint is not set"),
 
+            manager: self.instruction.manager.expect("manager is not set"),
+
             system_program: self
                 .instruction
                 .system_program
@@ -552,12 +630,15 @@ struct UpdateGroupAccountCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     symbol: Option<String>,
     uri: Option<String>,
     max_size: Option<u32>,
+    lock_max_size: Option<bool>,
+    advisory_member_update_gate: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -17,5 +17,5 @@ pub struct CreatorWithShare {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub address: Pubkey,
-    pub share: u8,
+    pub share: u16,
 }
@@ -137,6 +137,7 @@ impl Default for UpdateDistributionInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateDistributionInstructionArgs {
     pub amount: u64,
+    pub group_path_len: u8,
 }
 
 /// Instruction builder for `UpdateDistribution`.
@@ -164,6 +165,7 @@ pub struct UpdateDistributionBuilder {
     payment_token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
+    group_path_len: Option<u8>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -238,6 +240,11 @@ impl UpdateDistributionBuilder {
         self.amount = Some(amount);
         self
     }
+    #[inline(always)]
+    pub fn group_path_len(&mut self, group_path_len: u8) -> &mut Self {
+        self.group_path_len = Some(group_path_len);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -277,6 +284,10 @@ impl UpdateDistributionBuilder {
         };
         let args = UpdateDistributionInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
+            group_path_len: self
+                .group_path_len
+                .clone()
+                .expect("group_path_len is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -520,6 +531,7 @@ impl<'a, 'b> UpdateDistributionCpiBuilder<'a, 'b> {
             payment_token_program: None,
             system_program: None,
             amount: None,
+            group_path_len: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -601,6 +613,11 @@ impl<'a, 'b> UpdateDistributionCpiBuilder<'a, 'b> {
         self.instruction.amount = Some(amount);
         self
     }
+    #[inline(always)]
+    pub fn group_path_len(&mut self, group_path_len: u8) -> &mut Self {
+        self.instruction.group_path_len = Some(group_path_len);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -644,6 +661,11 @@ impl<'a, 'b> UpdateDistributionCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = UpdateDistributionInstructionArgs {
             amount: self.instruction.amount.clone().expect("amount is not set"),
+            group_path_len: self
+                .instruction
+                .group_path_len
+                .clone()
+                .expect("group_path_len is not set"),
         };
         let instruction = UpdateDistributionCpi {
             __program: self.instruction.__program,
@@ -699,6 +721,7 @@ struct UpdateDistributionCpiBuilderInstruction<'a, 'b> {
     payment_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
+    group_path_len: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
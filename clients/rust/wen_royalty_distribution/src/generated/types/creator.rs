@@ -20,4 +20,14 @@ pub struct Creator {
     pub address: Pubkey,
     /// token amount that creator can claim
     pub claim_amount: u64,
+    /// token amount credited to the creator over the lifetime of the distribution account
+    pub total_earned: u64,
+    /// token amount paid out to the creator over the lifetime of the distribution account
+    pub total_claimed: u64,
+    /// slot of the last claim, 0 if the creator never claimed
+    pub last_claim_slot: u64,
+    /// token amount credited to the creator that has not vested yet
+    pub unvested_amount: u64,
+    /// token amount released from vesting under the current vesting schedule
+    pub vested_amount: u64,
 }
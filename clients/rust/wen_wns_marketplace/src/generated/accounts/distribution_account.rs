@@ -28,6 +28,26 @@ pub struct DistributionAccount {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub payment_mint: Pubkey,
+    /// royalties received over the lifetime of the distribution account, creator shares and dust
+    pub total_earned: u64,
+    /// rounding remainder of royalty splits that has not been swept yet
+    pub dust_amount: u64,
+    /// receiver of swept dust, Pubkey::default() for the first creator in claim data
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub dust_recipient: Pubkey,
+    /// share of every distribute_to_creator payout kept by the cranker, in basis points
+    pub crank_tip_basis_points: u16,
+    /// number of creators in claim data
+    pub creator_count: u32,
+    /// unix timestamp vesting starts at
+    pub vesting_start: i64,
+    /// seconds after the vesting start before anything vests
+    pub vesting_cliff: i64,
+    /// seconds after the vesting start until everything is vested, 0 if royalties do not vest
+    pub vesting_duration: i64,
     pub claim_data: Vec<Creator>,
 }
 
@@ -212,6 +212,7 @@ impl Default for BuyInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuyInstructionArgs {
     pub buy_amount: u64,
+    pub group_path_len: u8,
 }
 
 /// Instruction builder for `Buy`.
@@ -263,6 +264,7 @@ pub struct BuyBuilder {
     buyer_payment_token_account: Option<solana_program::pubkey::Pubkey>,
     distribution_payment_token_account: Option<solana_program::pubkey::Pubkey>,
     buy_amount: Option<u64>,
+    group_path_len: Option<u8>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -419,6 +421,11 @@ impl BuyBuilder {
         self.buy_amount = Some(buy_amount);
         self
     }
+    #[inline(always)]
+    pub fn group_path_len(&mut self, group_path_len: u8) -> &mut Self {
+        self.group_path_len = Some(group_path_len);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -480,6 +487,10 @@ impl BuyBuilder {
         };
         let args = BuyInstructionArgs {
             buy_amount: self.buy_amount.clone().expect("buy_amount is not set"),
+            group_path_len: self
+                .group_path_len
+                .clone()
+                .expect("group_path_len is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -876,6 +887,7 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
             buyer_payment_token_account: None,
             distribution_payment_token_account: None,
             buy_amount: None,
+            group_path_len: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -1050,6 +1062,11 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
         self.instruction.buy_amount = Some(buy_amount);
         self
     }
+    #[inline(always)]
+    pub fn group_path_len(&mut self, group_path_len: u8) -> &mut Self {
+        self.instruction.group_path_len = Some(group_path_len);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -1097,6 +1114,11 @@ impl<'a, 'b> BuyCpiBuilder<'a, 'b> {
                 .buy_amount
                 .clone()
                 .expect("buy_amount is not set"),
+            group_path_len: self
+                .instruction
+                .group_path_len
+                .clone()
+                .expect("group_path_len is not set"),
         };
         let instruction = BuyCpi {
             __program: self.instruction.__program,
@@ -1209,6 +1231,7 @@ struct BuyCpiBuilderInstruction<'a, 'b> {
     buyer_payment_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     distribution_payment_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     buy_amount: Option<u64>,
+    group_path_len: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -6,7 +6,7 @@ pub enum MetadataErrors {
     SizeExceedsMaxSize,
     #[msg("Max size cannot be reduced below current size.")]
     MaxSizeBelowCurrentSize,
-    #[msg("Creators shares must add up to 100.")]
+    #[msg("Creators shares must add up to 10000 basis points.")]
     CreatorShareInvalid,
     #[msg("Missing approve account.")]
     MissingApproveAccount,
@@ -18,6 +18,52 @@ pub enum MetadataErrors {
     CreatorAddressInvalid,
     #[msg("Royalty basis points must be less than or equal to 10000.")]
     RoyaltyBasisPointsInvalid,
+    #[msg("Group has not been migrated to the token group extension.")]
+    GroupNotMigrated,
+    #[msg("Member does not belong to the group.")]
+    InvalidGroupMember,
+    #[msg("Group and member accounts are required for group members.")]
+    MissingGroupMember,
+    #[msg("Royalties have not been added to the mint.")]
+    RoyaltiesNotFound,
+    #[msg("Arithmetic overflow.")]
+    ArithmeticOverflow,
+    #[msg("Royalty config does not belong to the group.")]
+    InvalidGroupRoyaltyConfig,
+    #[msg("Invalid group update authority.")]
+    InvalidGroupAuthority,
+    #[msg("Invalid manager admin.")]
+    InvalidManagerAdmin,
+    #[msg("Protocol fee basis points must be less than or equal to 500.")]
+    ProtocolFeeBasisPointsInvalid,
+    #[msg("Manager account has already been migrated.")]
+    ManagerAlreadyMigrated,
+    #[msg("Treasury does not match the manager treasury.")]
+    InvalidTreasury,
+    #[msg("Program is paused.")]
+    ProgramPaused,
+    #[msg("Signer is not the pending group update authority.")]
+    InvalidPendingGroupAuthority,
+    #[msg("Asset authority must be the metadata update authority or the holder of the mint.")]
+    InvalidAssetAuthority,
+    #[msg("Mint is already a member of the group.")]
+    SameGroup,
+    #[msg("Group ancestry accounts do not match the parents of the group.")]
+    InvalidGroupAncestry,
+    #[msg("Sub group account does not belong to the mint.")]
+    InvalidSubGroup,
+    #[msg("A group cannot be nested inside itself or its own sub groups.")]
+    CircularGroupNesting,
+    #[msg("Group has to be removed from its parent group before migrating.")]
+    NestedGroupMigration,
+    #[msg("Max size of the group is locked.")]
+    MaxSizeLocked,
+    #[msg("Soulbound mints cannot have royalties.")]
+    SoulboundRoyalties,
+    #[msg("The group has an advisory gate on updates to its members.")]
+    AdvisoryMemberUpdateGate,
+    #[msg("Royalties of a group mint are set with the group royalty instructions.")]
+    GroupMintRoyalties,
 }
 
 #[error_code]
@@ -28,4 +74,8 @@ pub enum MintErrors {
     InvalidDelegateAuthority,
     #[msg("Invalid Token group member mint")]
     InvalidTokenGroupMemberMint,
+    #[msg("Mint has no permanent delegate.")]
+    MissingPermanentDelegate,
+    #[msg("Invalid clawback authority.")]
+    InvalidClawbackAuthority,
 }
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TransferMemberEvent {
+    /// mint that moved between groups
+    pub mint: Pubkey,
+    /// group the mint left
+    pub old_group: Pubkey,
+    /// group the mint joined
+    pub new_group: Pubkey,
+    /// member number of the mint in the old group
+    pub old_member_number: u32,
+    /// member number of the mint in the new group
+    pub new_member_number: u32,
+}
+
+#[event]
+pub struct ClawbackEvent {
+    /// mint that was clawed back
+    pub mint: Pubkey,
+    /// issuer that signed the clawback
+    pub authority: Pubkey,
+    /// holder the mint was taken from
+    pub from: Pubkey,
+    /// recovery address the mint was moved to
+    pub to: Pubkey,
+    /// amount of tokens moved
+    pub amount: u64,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_bump_in_seed_form, set_group_update_authority, GroupAuthorityProposal, Manager,
+    MetadataErrors, GROUP_ACCOUNT_SEED, GROUP_AUTHORITY_PROPOSAL_SEED, MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct AcceptGroupAuthority<'info> {
+    #[account()]
+    pub new_authority: Signer<'info>,
+    /// CHECK: proposer of the transfer, receives the proposal rent
+    #[account(
+        mut,
+        address = proposal.authority,
+    )]
+    pub authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        mut,
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = mint,
+        constraint = proposal.pending_authority == new_authority.key() @ MetadataErrors::InvalidPendingGroupAuthority,
+        seeds = [GROUP_AUTHORITY_PROPOSAL_SEED, mint.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GroupAuthorityProposal>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<AcceptGroupAuthority>) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    let signer_seeds = &[
+        GROUP_AUTHORITY_PROPOSAL_SEED,
+        mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.accounts.proposal.bump),
+    ];
+
+    // hand the group and the group mint metadata over from the proposal to the new authority
+    set_group_update_authority(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+        &ctx.accounts.proposal.to_account_info(),
+        &ctx.accounts.new_authority.to_account_info(),
+        &[&signer_seeds[..]],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_bump_in_seed_form, set_group_update_authority, GroupAuthorityProposal, MetadataErrors,
+    GROUP_ACCOUNT_SEED, GROUP_AUTHORITY_PROPOSAL_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelGroupAuthority<'info> {
+    /// proposer of the transfer, takes the group back and receives the proposal rent
+    #[account(
+        mut,
+        address = proposal.authority @ MetadataErrors::InvalidGroupAuthority,
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        mut,
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = mint,
+        seeds = [GROUP_AUTHORITY_PROPOSAL_SEED, mint.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GroupAuthorityProposal>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<CancelGroupAuthority>) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    let signer_seeds = &[
+        GROUP_AUTHORITY_PROPOSAL_SEED,
+        mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.accounts.proposal.bump),
+    ];
+
+    // hand the group and the group mint metadata back from the proposal to the proposer
+    set_group_update_authority(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+        &ctx.accounts.proposal.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &[&signer_seeds[..]],
+    )?;
+
+    Ok(())
+}
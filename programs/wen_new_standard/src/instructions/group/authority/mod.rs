@@ -0,0 +1,60 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token_interface::{
+    spl_pod::optional_keys::OptionalNonZeroPubkey, token_metadata_update_authority,
+    TokenMetadataUpdateAuthority,
+};
+use spl_token_group_interface::instruction::update_group_authority;
+
+use crate::TokenGroup;
+
+pub mod accept;
+pub mod cancel;
+pub mod propose;
+
+pub use accept::*;
+pub use cancel::*;
+pub use propose::*;
+
+/// Set the update authority of a group, on the wns group account if the group has not been
+/// migrated yet, else on the token extensions group extension of the mint. The metadata update
+/// authority of the group mint is rotated along with it
+pub fn set_group_update_authority<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    group: &AccountInfo<'info>,
+    current_authority: &AccountInfo<'info>,
+    new_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if group.owner == &crate::id() && !group.data_is_empty() {
+        let mut group_data = group.try_borrow_mut_data()?;
+        let mut token_group = TokenGroup::try_deserialize(&mut &group_data[..])?;
+        token_group.update_authority = new_authority.key();
+        token_group.try_serialize(&mut &mut group_data[..])?;
+    } else {
+        invoke_signed(
+            &update_group_authority(
+                token_program.key,
+                mint.key,
+                current_authority.key,
+                Some(new_authority.key()),
+            ),
+            &[mint.clone(), current_authority.clone()],
+            signer_seeds,
+        )?;
+    }
+
+    let cpi_accounts = TokenMetadataUpdateAuthority {
+        token_program_id: token_program.clone(),
+        metadata: mint.clone(), // metadata account is the mint, since data is stored in mint
+        current_authority: current_authority.clone(),
+        new_authority: new_authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token_metadata_update_authority(
+        cpi_ctx,
+        OptionalNonZeroPubkey::try_from(Some(new_authority.key()))?,
+    )?;
+
+    Ok(())
+}
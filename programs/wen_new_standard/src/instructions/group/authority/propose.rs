@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_group_update_authority, set_group_update_authority, GroupAuthorityProposal, Manager,
+    MetadataErrors, GROUP_ACCOUNT_SEED, GROUP_AUTHORITY_PROPOSAL_SEED, MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ProposeGroupAuthority<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        mut,
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + GroupAuthorityProposal::INIT_SPACE,
+        seeds = [GROUP_AUTHORITY_PROPOSAL_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub proposal: Account<'info, GroupAuthorityProposal>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<ProposeGroupAuthority>, new_authority: Pubkey) -> Result<()> {
+    let update_authority = get_group_update_authority(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+    )?;
+
+    if update_authority == Some(ctx.accounts.proposal.key()) {
+        // a transfer is already pending, only its proposer can replace the pending authority
+        require_keys_eq!(
+            ctx.accounts.proposal.authority,
+            ctx.accounts.authority.key(),
+            MetadataErrors::InvalidGroupAuthority
+        );
+    } else {
+        require!(
+            update_authority == Some(ctx.accounts.authority.key()),
+            MetadataErrors::InvalidGroupAuthority
+        );
+
+        // proposal holds the group until the pending authority accepts or the proposer cancels
+        set_group_update_authority(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.group.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.proposal.to_account_info(),
+            &[],
+        )?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.bump = ctx.bumps.proposal;
+        proposal.mint = ctx.accounts.mint.key();
+        proposal.authority = ctx.accounts.authority.key();
+    }
+
+    ctx.accounts.proposal.pending_authority = new_authority;
+
+    Ok(())
+}
@@ -9,8 +9,8 @@ use anchor_spl::{
 };
 
 use crate::{
-    update_account_lamports_to_minimum_balance, Manager, TokenGroup, GROUP_ACCOUNT_SEED,
-    MANAGER_SEED,
+    update_account_lamports_to_minimum_balance, Manager, MetadataErrors, TokenGroup,
+    GROUP_ACCOUNT_SEED, MANAGER_SEED,
 };
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -18,7 +18,13 @@ pub struct CreateGroupAccountArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub max_size: u32,
+    /// `None` for an unbounded group
+    pub max_size: Option<u32>,
+    /// lock the max size for good
+    pub lock_max_size: bool,
+    /// require the group update authority to sign along with `update_mint_account` for member
+    /// mints, advisory only as member mints stay updatable through the token extensions program
+    pub advisory_member_update_gate: bool,
 }
 
 #[derive(Accounts)]
@@ -52,6 +58,8 @@ pub struct CreateGroupAccount<'info> {
         // group pointer authority is left as the manager so that it can be updated once token group support inside mint is added
         extensions::group_pointer::authority = manager,
         extensions::group_pointer::group_address = group,
+        // member pointer lets the group mint join a parent group as a sub group
+        extensions::group_member_pointer::authority = manager,
         // temporary mint close authority until a better program accounts can be used
         extensions::close_authority::authority = manager,
     )]
@@ -66,7 +74,8 @@ pub struct CreateGroupAccount<'info> {
     pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         seeds = [MANAGER_SEED],
-        bump
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
     )]
     pub manager: Account<'info, Manager>,
     pub system_program: Program<'info, System>,
@@ -119,10 +128,15 @@ pub fn handler(ctx: Context<CreateGroupAccount>, args: CreateGroupAccountArgs) -
 
     // using a custom group account until token22 implements group account
     let group = &mut ctx.accounts.group;
-    group.max_size = args.max_size;
-    group.update_authority = ctx.accounts.authority.key();
-    group.mint = ctx.accounts.mint.key();
-    group.size = 0;
+    group.set_inner(TokenGroup::new(
+        &ctx.accounts.mint.key(),
+        ctx.accounts.authority.key(),
+        args.max_size,
+    ));
+    if args.lock_max_size {
+        group.lock_max_size();
+    }
+    group.advisory_member_update_gate = args.advisory_member_update_gate;
 
     // mint to receiver
     ctx.accounts.mint_to_receiver()?;
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    group_pointer_update, set_authority, spl_token_2022::instruction::AuthorityType,
+    token_group_initialize, GroupPointerUpdate, Mint, SetAuthority, Token2022,
+    TokenGroupInitialize,
+};
+
+use crate::{
+    get_bump_in_seed_form, is_any_group_member, update_account_lamports_to_minimum_balance,
+    Manager, MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED, MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MigrateGroup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.update_authority == authority.key(),
+        has_one = mint,
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl MigrateGroup<'_> {
+    fn update_group_pointer_group_address(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = GroupPointerUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        // group data now lives inside the mint itself
+        group_pointer_update(cpi_ctx, Some(self.mint.key()))?;
+        Ok(())
+    }
+
+    fn initialize_token_group(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = TokenGroupInitialize {
+            token_program_id: self.token_program.to_account_info(),
+            group: self.mint.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        // unbounded groups carry `u32::MAX` over as the max size of the extension
+        token_group_initialize(
+            cpi_ctx,
+            Some(self.group.update_authority),
+            self.group.max_size,
+        )?;
+        Ok(())
+    }
+
+    fn remove_mint_authority(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.manager.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<MigrateGroup>) -> Result<()> {
+    // sizes of nested groups roll up into the wns group accounts of their parents
+    require!(
+        !is_any_group_member(&ctx.accounts.mint.to_account_info()),
+        MetadataErrors::NestedGroupMigration
+    );
+    // the group extension has no way to lock its max size
+    require!(
+        !ctx.accounts.group.max_size_locked,
+        MetadataErrors::MaxSizeLocked
+    );
+    // nor a way to gate updates to its members
+    require!(
+        !ctx.accounts.group.advisory_member_update_gate,
+        MetadataErrors::AdvisoryMemberUpdateGate
+    );
+
+    let signer_seeds = &[MANAGER_SEED, &get_bump_in_seed_form(&ctx.bumps.manager)];
+
+    // point the group pointer at the mint
+    ctx.accounts
+        .update_group_pointer_group_address(&[&signer_seeds[..]])?;
+
+    // initialize the group extension, manager is still the mint authority at this point
+    // size starts at 0 and is incremented by token extensions as each member is migrated
+    ctx.accounts.initialize_token_group(&[&signer_seeds[..]])?;
+
+    // update mint lamports to minimum rent balance
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // manager no longer needs to hold on to the mint authority
+    ctx.accounts.remove_mint_authority(&[&signer_seeds[..]])?;
+
+    // the legacy group account keeps the size of the group until every member has been migrated with
+    // `migrate_member`, which closes it. a group without members has nothing left to migrate
+    if ctx.accounts.group.size == 0 {
+        let group = &mut ctx.accounts.group;
+        group.close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,11 @@
+pub mod authority;
 pub mod create;
+pub mod migrate;
+pub mod royalties;
 pub mod update;
 
+pub use authority::*;
 pub use create::*;
+pub use migrate::*;
+pub use royalties::*;
 pub use update::*;
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_group_royalty_config, get_group_update_authority, Manager, MetadataErrors, RoyaltyConfig,
+    UpdateRoyaltiesArgs, GROUP_ACCOUNT_SEED, MANAGER_SEED, ROYALTY_CONFIG_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(args: UpdateRoyaltiesArgs)]
+pub struct AddGroupRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        init,
+        space = RoyaltyConfig::size(args.creators.len()),
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<AddGroupRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+    // only the group update authority can set the royalties inherited by its members
+    let update_authority = get_group_update_authority(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+    )?;
+    require!(
+        update_authority == Some(ctx.accounts.authority.key()),
+        MetadataErrors::InvalidGroupAuthority
+    );
+
+    // write the typed group royalty config
+    let royalty_config = get_group_royalty_config(&ctx.accounts.mint.key(), &args)?;
+    ctx.accounts.royalty_config.set_inner(royalty_config);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{MetadataErrors, RoyaltyConfig, RoyaltyCreator, UpdateRoyaltiesArgs};
+
+pub mod add;
+pub mod modify;
+
+pub use add::*;
+pub use modify::*;
+
+/// Validates the royalty args and builds the group `RoyaltyConfig` from them
+pub fn get_group_royalty_config(
+    group_mint: &Pubkey,
+    args: &UpdateRoyaltiesArgs,
+) -> Result<RoyaltyConfig> {
+    // validate that the fee_basis_point is less than 10000 (100%)
+    require!(
+        args.royalty_basis_points <= 10000,
+        MetadataErrors::RoyaltyBasisPointsInvalid
+    );
+
+    let creators = args
+        .creators
+        .iter()
+        .map(|creator| RoyaltyCreator {
+            address: creator.address,
+            share: creator.share,
+        })
+        .collect();
+
+    let royalty_config = RoyaltyConfig::new(group_mint, args.royalty_basis_points, creators);
+    royalty_config.validate_creator_shares()?;
+
+    Ok(royalty_config)
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_group_royalty_config, get_group_update_authority, Manager, MetadataErrors, RoyaltyConfig,
+    UpdateRoyaltiesArgs, GROUP_ACCOUNT_SEED, MANAGER_SEED, ROYALTY_CONFIG_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(args: UpdateRoyaltiesArgs)]
+pub struct ModifyGroupRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = mint,
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+        realloc = RoyaltyConfig::size(args.creators.len()),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<ModifyGroupRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+    // only the group update authority can change the royalties inherited by its members
+    let update_authority = get_group_update_authority(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+    )?;
+    require!(
+        update_authority == Some(ctx.accounts.authority.key()),
+        MetadataErrors::InvalidGroupAuthority
+    );
+
+    // overwrite the group royalty config, members pick up the change on their next sale
+    let royalty_config = get_group_royalty_config(&ctx.accounts.mint.key(), &args)?;
+    ctx.accounts.royalty_config.set_inner(royalty_config);
+
+    Ok(())
+}
@@ -4,14 +4,20 @@ use anchor_spl::token_interface::{
     TokenMetadataUpdateField,
 };
 
-use crate::{MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED};
+use crate::{Manager, MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED, MANAGER_SEED};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateGroupAccountArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub max_size: u32,
+    /// `None` for an unbounded group
+    pub max_size: Option<u32>,
+    /// lock the max size for good, once locked it can't be changed
+    pub lock_max_size: bool,
+    /// require the group update authority to sign along with `update_mint_account` for member
+    /// mints, advisory only as member mints stay updatable through the token extensions program
+    pub advisory_member_update_gate: bool,
 }
 
 #[derive(Accounts)]
@@ -20,10 +26,14 @@ pub struct UpdateGroupAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account()]
-    /// CHECK: can be any account
-    pub authority: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    // group accounts created before the group flags grow to hold them
     #[account(
         mut,
+        realloc = 8 + TokenGroup::INIT_SPACE,
+        realloc::payer = payer,
+        realloc::zero = false,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
         seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
         bump,
     )]
@@ -33,6 +43,12 @@ pub struct UpdateGroupAccount<'info> {
         mint::token_program = token_program,
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token2022>,
 }
@@ -51,12 +67,12 @@ impl<'info> UpdateGroupAccount<'info> {
 }
 
 pub fn handler(ctx: Context<UpdateGroupAccount>, args: UpdateGroupAccountArgs) -> Result<()> {
-    if args.max_size < ctx.accounts.group.size {
-        return Err(MetadataErrors::MaxSizeBelowCurrentSize.into());
-    }
-
     // update group max size
-    ctx.accounts.group.max_size = args.max_size;
+    ctx.accounts.group.update_max_size(args.max_size)?;
+    if args.lock_max_size {
+        ctx.accounts.group.lock_max_size();
+    }
+    ctx.accounts.group.advisory_member_update_gate = args.advisory_member_update_gate;
 
     // update metadata name
     ctx.accounts.update_metadata(Field::Name, args.name)?;
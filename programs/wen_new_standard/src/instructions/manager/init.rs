@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 
-use crate::{Manager, MANAGER_SEED};
+use crate::{program::WenNewStandard, Manager, MetadataErrors, MANAGER_SEED};
 
 #[derive(Accounts)]
 #[instruction()]
 pub struct InitManagerAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// upgrade authority of the program, becomes the manager admin
+    pub upgrade_authority: Signer<'info>,
     #[account(
         init,
         payer = payer,
@@ -15,10 +17,22 @@ pub struct InitManagerAccount<'info> {
         bump
     )]
     pub manager: Account<'info, Manager>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, WenNewStandard>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ MetadataErrors::InvalidManagerAdmin,
+    )]
+    pub program_data: Account<'info, ProgramData>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(_ctx: Context<InitManagerAccount>) -> Result<()> {
-    // ctx.accounts.manager.bump = ctx.bumps.manager;
+pub fn handler(ctx: Context<InitManagerAccount>) -> Result<()> {
+    // upgrade authority is the first admin, no protocol fee until configured
+    let upgrade_authority = ctx.accounts.upgrade_authority.key();
+    ctx.accounts
+        .manager
+        .update(upgrade_authority, 0, upgrade_authority)?;
     Ok(())
 }
@@ -0,0 +1,66 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    program::WenNewStandard, update_account_lamports_to_minimum_balance, Manager, MetadataErrors,
+    UpdateManagerAccountArgs, MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MigrateManagerAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// upgrade authority of the program, the manager has no admin before the migration
+    pub upgrade_authority: Signer<'info>,
+    /// CHECK: manager account of an older layout, checked in the handler
+    #[account(
+        mut,
+        seeds = [MANAGER_SEED],
+        bump,
+        owner = crate::id(),
+    )]
+    pub manager: UncheckedAccount<'info>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, WenNewStandard>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ MetadataErrors::InvalidManagerAdmin,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateManagerAccount>, args: UpdateManagerAccountArgs) -> Result<()> {
+    let manager_info = ctx.accounts.manager.to_account_info();
+    // manager accounts created before the protocol config hold the discriminator only
+    {
+        let manager_data = manager_info.try_borrow_data()?;
+        require!(
+            manager_data.len() >= 8 && manager_data[..8] == Manager::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            manager_data.len() == 8,
+            MetadataErrors::ManagerAlreadyMigrated
+        );
+    }
+
+    let mut manager = Manager {
+        admin: Pubkey::default(),
+        protocol_fee_basis_points: 0,
+        treasury: Pubkey::default(),
+        paused: false,
+    };
+    manager.update(args.admin, args.protocol_fee_basis_points, args.treasury)?;
+
+    manager_info.realloc(8 + Manager::INIT_SPACE, false)?;
+    update_account_lamports_to_minimum_balance(
+        manager_info.clone(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    manager.try_serialize(&mut &mut manager_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -1,3 +1,9 @@
 pub mod init;
+pub mod migrate;
+pub mod pause;
+pub mod update;
 
 pub use init::*;
+pub use migrate::*;
+pub use pause::*;
+pub use update::*;
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{Manager, MetadataErrors, MANAGER_SEED};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct PauseProgram<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MANAGER_SEED],
+        bump,
+        has_one = admin @ MetadataErrors::InvalidManagerAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+}
+
+pub fn handler(ctx: Context<PauseProgram>, paused: bool) -> Result<()> {
+    ctx.accounts.manager.paused = paused;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{Manager, MetadataErrors, MANAGER_SEED};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateManagerAccountArgs {
+    pub admin: Pubkey,
+    pub protocol_fee_basis_points: u16,
+    pub treasury: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct UpdateManagerAccount<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MANAGER_SEED],
+        bump,
+        has_one = admin @ MetadataErrors::InvalidManagerAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+}
+
+pub fn handler(ctx: Context<UpdateManagerAccount>, args: UpdateManagerAccountArgs) -> Result<()> {
+    ctx.accounts
+        .manager
+        .update(args.admin, args.protocol_fee_basis_points, args.treasury)?;
+    Ok(())
+}
@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
 
 use anchor_spl::token_interface::{
-    burn, close_account, Burn, CloseAccount, Mint, Token2022, TokenAccount,
+    burn, close_account, spl_token_2022::extension::group_member_pointer::GroupMemberPointer, Burn,
+    CloseAccount, Mint, Token2022, TokenAccount,
 };
 
-use crate::{Manager, MANAGER_SEED};
+use crate::{
+    close_program_account, get_extension_data, get_group_ancestors, get_member_size,
+    update_group_ancestors, Manager, MetadataErrors, MintErrors, TokenGroup, TokenGroupMember,
+    APPROVE_ACCOUNT_SEED, GROUP_ACCOUNT_SEED, MANAGER_SEED, MEMBER_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED, ROYALTY_CONFIG_SEED,
+};
 
 #[derive(Accounts)]
 pub struct BurnMintAccount<'info> {
@@ -26,6 +32,46 @@ pub struct BurnMintAccount<'info> {
         bump
     )]
     pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds = [GROUP_ACCOUNT_SEED, group.mint.as_ref()],
+        bump,
+    )]
+    pub group: Option<Account<'info, TokenGroup>>,
+    #[account(
+        mut,
+        has_one = mint @ MintErrors::InvalidTokenGroupMemberMint,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<Account<'info, TokenGroupMember>>,
+    /// CHECK: wns group account of the mint, required when the mint is a sub group
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub sub_group: Option<UncheckedAccount<'info>>,
+    /// CHECK: approve account of the mint, closed if it was ever initialized
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub approve_account: UncheckedAccount<'info>,
+    /// CHECK: This account's data is a buffer of TLV data, closed if it was ever initialized
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: royalty config of the mint, closed if it was ever initialized
+    #[account(
+        mut,
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub royalty_config: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token2022>,
 }
 
@@ -75,6 +121,65 @@ impl<'info> BurnMintAccount<'info> {
 }
 
 pub fn handler(ctx: Context<BurnMintAccount>) -> Result<()> {
+    // a mint that is still a member of a wns group must be burned along with its member account
+    let group_member_pointer =
+        get_extension_data::<GroupMemberPointer>(&mut ctx.accounts.mint.to_account_info()).ok();
+    let is_group_member = group_member_pointer
+        .and_then(|pointer| Option::<Pubkey>::from(pointer.member_address))
+        .is_some_and(|member_address| {
+            member_address
+                == Pubkey::find_program_address(
+                    &[MEMBER_ACCOUNT_SEED, ctx.accounts.mint.key().as_ref()],
+                    &crate::id(),
+                )
+                .0
+        });
+
+    match (&mut ctx.accounts.group, &mut ctx.accounts.member) {
+        (Some(group), Some(member)) => {
+            require!(
+                member.group == group.key(),
+                MetadataErrors::InvalidGroupMember
+            );
+
+            // decrease collection number of the group by the mint and, for a sub group, its members
+            let size = get_member_size(
+                &ctx.accounts.mint.to_account_info(),
+                ctx.accounts.sub_group.as_deref(),
+            )?;
+
+            // remaining accounts hold the ancestry of the group which shrinks along with it. they
+            // can be left out for a group without a parent, leaving them out for a sub group only
+            // keeps its parents counting the burned mint, which never lets them grow past max size
+            if !ctx.remaining_accounts.is_empty() {
+                let ancestors =
+                    get_group_ancestors(&group.mint, &mut ctx.remaining_accounts.iter())?;
+                update_group_ancestors(&ancestors, |ancestor| ancestor.decrease_size(size))?;
+            }
+            group.decrease_size(size)?;
+
+            // close the member account
+            member.close(ctx.accounts.payer.to_account_info())?;
+        }
+        (None, None) => require!(!is_group_member, MetadataErrors::MissingGroupMember),
+        _ => return err!(MetadataErrors::MissingGroupMember),
+    }
+
+    // close the approve, royalty config and extra metas accounts, if they were ever initialized.
+    // they are required so that none of them can be left behind once the mint is closed
+    for account in [
+        &ctx.accounts.approve_account,
+        &ctx.accounts.royalty_config,
+        &ctx.accounts.extra_metas_account,
+    ] {
+        if account.owner == &crate::id() {
+            close_program_account(
+                account.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+            )?;
+        }
+    }
+
     // burn the token
     ctx.accounts.burn_token()?;
 
@@ -84,7 +189,5 @@ pub fn handler(ctx: Context<BurnMintAccount>) -> Result<()> {
     // close the mint account
     ctx.accounts.close_mint_account(ctx.bumps)?;
 
-    // TODO: decrease collection number of the group
-
     Ok(())
 }
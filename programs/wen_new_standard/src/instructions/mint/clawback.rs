@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        spl_pod::solana_program::program_option::COption,
+        spl_token_2022::{
+            extension::{permanent_delegate::PermanentDelegate, transfer_hook::TransferHook},
+            onchain::invoke_transfer_checked,
+        },
+        thaw_account, transfer_hook_update, Mint, ThawAccount, Token2022, TokenAccount,
+        TransferHookUpdate,
+    },
+};
+
+use crate::{
+    get_extension_data, get_mint_metadata, ClawbackEvent, Manager, MetadataErrors, MintErrors,
+    MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// permanent delegate of the mint, or its metadata update authority when the manager is the
+    /// permanent delegate
+    pub authority: Signer<'info>,
+    /// transfer hook authority of the mint, signs along with a permanent delegate other than the
+    /// hook authority to claw back mints with royalties
+    pub transfer_hook_authority: Option<Signer<'info>>,
+    #[account()]
+    /// CHECK: can be any account
+    pub user: UncheckedAccount<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = mint.freeze_authority == COption::Some(manager.key()) @MintErrors::InvalidFreezeAuthority
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = mint,
+        token::authority = user,
+    )]
+    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::token_program = token_program,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> Clawback<'info> {
+    fn thaw(&self, bumps: &ClawbackBumps) -> Result<()> {
+        let seeds: &[&[u8]; 2] = &[MANAGER_SEED, &[bumps.manager]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = ThawAccount {
+            account: self.mint_token_account.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        thaw_account(cpi_ctx)?;
+
+        Ok(())
+    }
+
+    fn update_transfer_hook_program_id(
+        &self,
+        authority: AccountInfo<'info>,
+        program_id: Option<Pubkey>,
+    ) -> Result<()> {
+        let cpi_accounts = TransferHookUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority,
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_hook_update(cpi_ctx, program_id)?;
+        Ok(())
+    }
+
+    /// remaining accounts are forwarded for a transfer hook other than wns, if any
+    fn transfer_to_recipient(
+        &self,
+        bumps: &ClawbackBumps,
+        sign_as_manager: bool,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let seeds: &[&[u8]; 2] = &[MANAGER_SEED, &[bumps.manager]];
+        let (authority, signer_seeds): (AccountInfo<'info>, &[&[&[u8]]]) = if sign_as_manager {
+            (self.manager.to_account_info(), &[&seeds[..]])
+        } else {
+            (self.authority.to_account_info(), &[])
+        };
+
+        invoke_transfer_checked(
+            self.token_program.key,
+            self.mint_token_account.to_account_info(),
+            self.mint.to_account_info(),
+            self.recipient_token_account.to_account_info(),
+            authority,
+            remaining_accounts,
+            self.mint_token_account.amount,
+            self.mint.decimals,
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
+    let mut mint_account_info = ctx.accounts.mint.to_account_info();
+    let permanent_delegate =
+        get_extension_data::<PermanentDelegate>(&mut mint_account_info)?.delegate;
+    let permanent_delegate =
+        Option::<Pubkey>::from(permanent_delegate).ok_or(MintErrors::MissingPermanentDelegate)?;
+
+    // the manager signs for mints it is the permanent delegate of, on behalf of the issuer
+    let sign_as_manager = permanent_delegate == ctx.accounts.manager.key();
+    let clawback_authority = if sign_as_manager {
+        Option::<Pubkey>::from(get_mint_metadata(&mut mint_account_info)?.update_authority)
+            .ok_or(MintErrors::InvalidClawbackAuthority)?
+    } else {
+        permanent_delegate
+    };
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        clawback_authority,
+        MintErrors::InvalidClawbackAuthority
+    );
+
+    // the wns transfer hook cannot be re-entered from this program, so the hook is detached for the
+    // transfer by its authority, no approve account is needed for a clawback. the clawback authority
+    // signs for the hook unless the hook authority signs along
+    let transfer_hook = get_extension_data::<TransferHook>(&mut mint_account_info).ok();
+    let detach_transfer_hook = transfer_hook
+        .is_some_and(|hook| Option::<Pubkey>::from(hook.program_id) == Some(crate::id()));
+    let transfer_hook_signer = ctx
+        .accounts
+        .transfer_hook_authority
+        .as_ref()
+        .map_or(ctx.accounts.authority.to_account_info(), |signer| {
+            signer.to_account_info()
+        });
+    if detach_transfer_hook {
+        let transfer_hook_authority = transfer_hook
+            .and_then(|hook| Option::<Pubkey>::from(hook.authority))
+            .ok_or(MintErrors::InvalidClawbackAuthority)?;
+        require_keys_eq!(
+            transfer_hook_signer.key(),
+            transfer_hook_authority,
+            MintErrors::InvalidClawbackAuthority
+        );
+        ctx.accounts
+            .update_transfer_hook_program_id(transfer_hook_signer.clone(), None)?;
+    }
+
+    if ctx.accounts.mint_token_account.is_frozen() {
+        ctx.accounts.thaw(&ctx.bumps)?;
+    }
+
+    ctx.accounts
+        .transfer_to_recipient(&ctx.bumps, sign_as_manager, ctx.remaining_accounts)?;
+
+    if detach_transfer_hook {
+        ctx.accounts
+            .update_transfer_hook_program_id(transfer_hook_signer, Some(crate::id()))?;
+    }
+
+    emit!(ClawbackEvent {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        from: ctx.accounts.user.key(),
+        to: ctx.accounts.recipient.key(),
+        amount: ctx.accounts.mint_token_account.amount,
+    });
+
+    Ok(())
+}
@@ -1,15 +1,26 @@
-use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
+use anchor_lang::{
+    prelude::*,
+    solana_program::entrypoint::ProgramResult,
+    system_program::{create_account, CreateAccount},
+};
 
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{
+        create, get_associated_token_address_with_program_id, AssociatedToken, Create,
+    },
     token_interface::{
-        mint_to, set_authority, spl_token_2022::instruction::AuthorityType,
-        token_metadata_initialize, Mint, MintTo, SetAuthority, Token2022, TokenAccount,
-        TokenMetadataInitialize,
+        group_member_pointer_initialize, initialize_mint2, metadata_pointer_initialize,
+        mint_close_authority_initialize, mint_to, non_transferable_mint_initialize,
+        permanent_delegate_initialize, set_authority,
+        spl_token_2022::{extension::ExtensionType, instruction::AuthorityType, state::Mint},
+        token_metadata_initialize, transfer_hook_initialize, GroupMemberPointerInitialize,
+        InitializeMint2, MetadataPointerInitialize, MintCloseAuthorityInitialize, MintTo,
+        NonTransferableMintInitialize, PermanentDelegateInitialize, SetAuthority, Token2022,
+        TokenMetadataInitialize, TransferHookInitialize,
     },
 };
 
-use crate::{update_account_lamports_to_minimum_balance, Manager, MANAGER_SEED};
+use crate::{update_account_lamports_to_minimum_balance, Manager, MetadataErrors, MANAGER_SEED};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateMintAccountArgs {
@@ -17,6 +28,8 @@ pub struct CreateMintAccountArgs {
     pub symbol: String,
     pub uri: String,
     pub permanent_delegate: Option<Pubkey>,
+    /// non transferable mint without a transfer hook, e.g. credentials and tickets
+    pub soulbound: bool,
 }
 
 #[derive(Accounts)]
@@ -30,34 +43,19 @@ pub struct CreateMintAccount<'info> {
     #[account()]
     /// CHECK: can be any account
     pub receiver: UncheckedAccount<'info>,
+    /// initialized in the handler, its extensions depend on the args
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    /// CHECK: initialized in the handler once the mint exists
     #[account(
-        init,
-        signer,
-        payer = payer,
-        mint::token_program = token_program,
-        mint::decimals = 0,
-        mint::authority = authority,
-        mint::freeze_authority = manager,
-        extensions::metadata_pointer::authority = authority,
-        extensions::metadata_pointer::metadata_address = mint,
-        extensions::group_member_pointer::authority = manager,
-        extensions::transfer_hook::authority = authority,
-        extensions::permanent_delegate::delegate = args.permanent_delegate.unwrap_or_else(|| manager.key()),
-        // temporary mint close authority until a better program accounts can be used
-        extensions::close_authority::authority = manager,
-    )]
-    pub mint: Box<InterfaceAccount<'info, Mint>>,
-    #[account(
-        init,
-        payer = payer,
-        associated_token::token_program = token_program,
-        associated_token::mint = mint,
-        associated_token::authority = receiver,
+        mut,
+        address = get_associated_token_address_with_program_id(&receiver.key(), &mint.key(), &token_program.key()),
     )]
-    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub mint_token_account: UncheckedAccount<'info>,
     #[account(
         seeds = [MANAGER_SEED],
-        bump
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
     )]
     pub manager: Account<'info, Manager>,
     pub system_program: Program<'info, System>,
@@ -66,6 +64,126 @@ pub struct CreateMintAccount<'info> {
 }
 
 impl<'info> CreateMintAccount<'info> {
+    /// soulbound mints are non transferable and skip the transfer hook set up by `add_royalties`
+    fn initialize_mint(&self, permanent_delegate: &Pubkey, soulbound: bool) -> Result<()> {
+        let transfer_extension = if soulbound {
+            ExtensionType::NonTransferable
+        } else {
+            ExtensionType::TransferHook
+        };
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::MetadataPointer,
+            ExtensionType::GroupMemberPointer,
+            transfer_extension,
+            ExtensionType::PermanentDelegate,
+            // temporary mint close authority until a better program accounts can be used
+            ExtensionType::MintCloseAuthority,
+        ])?;
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.payer.to_account_info(),
+                    to: self.mint.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            self.token_program.key,
+        )?;
+
+        let token_program = self.token_program.to_account_info();
+        let mint = self.mint.to_account_info();
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            Some(self.authority.key()),
+            Some(self.mint.key()),
+        )?;
+        group_member_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                GroupMemberPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            Some(self.manager.key()),
+            None,
+        )?;
+        if soulbound {
+            non_transferable_mint_initialize(CpiContext::new(
+                token_program.clone(),
+                NonTransferableMintInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ))?;
+        } else {
+            transfer_hook_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    TransferHookInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: mint.clone(),
+                    },
+                ),
+                Some(self.authority.key()),
+                None,
+            )?;
+        }
+        permanent_delegate_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                PermanentDelegateInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            permanent_delegate,
+        )?;
+        mint_close_authority_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MintCloseAuthorityInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            Some(&self.manager.key()),
+        )?;
+
+        initialize_mint2(
+            CpiContext::new(token_program, InitializeMint2 { mint }),
+            0,
+            self.authority.key,
+            Some(&self.manager.key()),
+        )?;
+        Ok(())
+    }
+
+    fn create_mint_token_account(&self) -> Result<()> {
+        let cpi_accounts = Create {
+            payer: self.payer.to_account_info(),
+            associated_token: self.mint_token_account.to_account_info(),
+            authority: self.receiver.to_account_info(),
+            mint: self.mint.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            cpi_accounts,
+        );
+        create(cpi_ctx)?;
+        Ok(())
+    }
+
     fn initialize_token_metadata(
         &self,
         name: String,
@@ -125,6 +243,15 @@ impl<'info> CreateMintAccount<'info> {
 }
 
 pub fn handler(ctx: Context<CreateMintAccount>, args: CreateMintAccountArgs) -> Result<()> {
+    // initialize mint, manager is the permanent delegate until the default is removed below
+    ctx.accounts.initialize_mint(
+        &args
+            .permanent_delegate
+            .unwrap_or_else(|| ctx.accounts.manager.key()),
+        args.soulbound,
+    )?;
+    ctx.accounts.create_mint_token_account()?;
+
     if args.permanent_delegate.is_none() {
         ctx.accounts
             .set_default_permanent_delegate(ctx.bumps.manager)?;
@@ -5,7 +5,7 @@ use anchor_spl::token_interface::{
     Token2022, TokenAccount,
 };
 
-use crate::{Manager, MintErrors, MANAGER_SEED};
+use crate::{Manager, MetadataErrors, MintErrors, MANAGER_SEED};
 
 #[derive(Accounts)]
 pub struct FreezeDelegatedAccount<'info> {
@@ -28,7 +28,8 @@ pub struct FreezeDelegatedAccount<'info> {
     pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         seeds = [MANAGER_SEED],
-        bump
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
     )]
     pub manager: Account<'info, Manager>,
     pub token_program: Program<'info, Token2022>,
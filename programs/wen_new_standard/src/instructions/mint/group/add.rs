@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
 
 use anchor_spl::token_interface::{
-    group_member_pointer_update, GroupMemberPointerUpdate, Mint, Token2022,
+    group_member_pointer_update, GroupMemberPointerUpdate, Mint, Token2022, TokenAccount,
 };
 
 use crate::{
-    get_bump_in_seed_form, Manager, TokenGroup, TokenGroupMember, GROUP_ACCOUNT_SEED, MANAGER_SEED,
-    MEMBER_ACCOUNT_SEED, TOKEN22,
+    get_bump_in_seed_form, get_group_ancestors, get_member_size, has_asset_consent,
+    update_group_ancestors, Manager, MetadataErrors, TokenGroup, TokenGroupMember,
+    GROUP_ACCOUNT_SEED, MANAGER_SEED, MEMBER_ACCOUNT_SEED, TOKEN22,
 };
 
 #[derive(Accounts)]
@@ -36,9 +37,25 @@ pub struct AddGroup<'info> {
         mint::token_program = TOKEN22
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account of the mint, required when the mint is a group mint joining as a sub group
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub sub_group: Option<UncheckedAccount<'info>>,
+    /// metadata update authority of the mint, or holder of the mint when the token account is passed
+    #[account()]
+    pub asset_authority: Signer<'info>,
+    #[account(
+        token::mint = mint,
+        token::authority = asset_authority,
+        token::token_program = token_program,
+    )]
+    pub asset_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     #[account(
         seeds = [MANAGER_SEED],
-        bump
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
     )]
     pub manager: Box<Account<'info, Manager>>,
     pub system_program: Program<'info, System>,
@@ -46,6 +63,21 @@ pub struct AddGroup<'info> {
 }
 
 impl AddGroup<'_> {
+    /// a mint can only join a group with the consent of its metadata update authority or its holder
+    fn check_asset_consent(&self) -> Result<()> {
+        require!(
+            has_asset_consent(
+                &self.mint.to_account_info(),
+                self.asset_authority.key,
+                self.asset_token_account
+                    .as_deref()
+                    .map(|account| &**account),
+            )?,
+            MetadataErrors::InvalidAssetAuthority
+        );
+        Ok(())
+    }
+
     fn update_group_member_pointer_member_address(
         &self,
         member: Pubkey,
@@ -67,13 +99,35 @@ impl AddGroup<'_> {
 }
 
 pub fn handler(ctx: Context<AddGroup>) -> Result<()> {
+    ctx.accounts.check_asset_consent()?;
+
+    let size = get_member_size(
+        &ctx.accounts.mint.to_account_info(),
+        ctx.accounts.sub_group.as_deref(),
+    )?;
+
+    // remaining accounts hold the ancestry of the group, its size rolls up into every parent group
+    let ancestors =
+        get_group_ancestors(&ctx.accounts.group.mint, &mut ctx.remaining_accounts.iter())?;
+    if let Some(sub_group) = &ctx.accounts.sub_group {
+        require!(
+            sub_group.key() != ctx.accounts.group.key()
+                && ancestors
+                    .iter()
+                    .all(|ancestor| ancestor.key() != sub_group.key()),
+            MetadataErrors::CircularGroupNesting
+        );
+    }
+    update_group_ancestors(&ancestors, |ancestor| ancestor.increase_size(size))?;
+
     let group = &mut ctx.accounts.group;
-    group.increment_size()?;
+    let new_size = group.increase_size(size)?;
 
     let member = &mut ctx.accounts.member;
     member.group = group.key();
     member.mint = ctx.accounts.mint.key();
-    member.member_number = group.size;
+    // a sub group takes the next member number, its own members are counted along with it
+    member.member_number = new_size - size + 1;
 
     let member_address = member.key();
 
@@ -0,0 +1,187 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
+
+use anchor_spl::token_interface::{
+    group_member_pointer_update, set_authority,
+    spl_token_2022::{
+        extension::{group_pointer::GroupPointer, ExtensionType},
+        instruction::AuthorityType,
+    },
+    spl_token_metadata_interface::state::Field,
+    token_member_initialize, token_metadata_update_field, GroupMemberPointerUpdate, Mint,
+    SetAuthority, Token2022, TokenMemberInitialize, TokenMetadataUpdateField,
+};
+use spl_token_group_interface::state::TokenGroup as TokenGroupExtension;
+
+use crate::{
+    get_bump_in_seed_form, get_extension_data, get_mint_extension_types,
+    update_account_lamports_to_minimum_balance, Manager, MetadataErrors, MintErrors, TokenGroup,
+    TokenGroupMember, GROUP_ACCOUNT_SEED, MANAGER_SEED, MEMBER_ACCOUNT_SEED, MEMBER_NUMBER_FIELD,
+    TOKEN22,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MigrateMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// group update authority
+    #[account()]
+    pub authority: Signer<'info>,
+    /// legacy group account, closed once its last member is migrated
+    #[account(
+        mut,
+        seeds = [GROUP_ACCOUNT_SEED, group_mint.key().as_ref()],
+        bump,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        mint::token_program = TOKEN22
+    )]
+    pub group_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = mint @ MintErrors::InvalidTokenGroupMemberMint,
+        constraint = member.group == group.key() @ MetadataErrors::InvalidGroupMember,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mut,
+        mint::token_program = TOKEN22
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// metadata update authority of the mint, the legacy member number is mirrored in its metadata
+    #[account()]
+    pub metadata_authority: Signer<'info>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl MigrateMember<'_> {
+    fn update_group_member_pointer_member_address(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = GroupMemberPointerUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        // member data now lives inside the mint itself
+        group_member_pointer_update(cpi_ctx, Some(self.mint.key()))?;
+        Ok(())
+    }
+
+    fn initialize_token_group_member(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = TokenMemberInitialize {
+            token_program_id: self.token_program.to_account_info(),
+            member: self.mint.to_account_info(),
+            member_mint: self.mint.to_account_info(),
+            member_mint_authority: self.manager.to_account_info(),
+            group: self.group_mint.to_account_info(),
+            group_update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_member_initialize(cpi_ctx)?;
+        Ok(())
+    }
+
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.metadata_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, field, value)?;
+        Ok(())
+    }
+
+    fn remove_mint_authority(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.manager.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<MigrateMember>) -> Result<()> {
+    // the group needs to be migrated before any of its members
+    let group_mint_extensions =
+        get_mint_extension_types(&ctx.accounts.group_mint.to_account_info())?;
+    require!(
+        group_mint_extensions.contains(&ExtensionType::TokenGroup),
+        MetadataErrors::GroupNotMigrated
+    );
+    // sub groups count their own members in the size of the legacy group, they have to be removed
+    // from it first
+    let is_sub_group = get_extension_data::<GroupPointer>(&mut ctx.accounts.mint.to_account_info())
+        .ok()
+        .and_then(|pointer| Option::<Pubkey>::from(pointer.group_address))
+        .is_some();
+    require!(!is_sub_group, MetadataErrors::NestedGroupMigration);
+
+    let signer_seeds = &[MANAGER_SEED, &get_bump_in_seed_form(&ctx.bumps.manager)];
+
+    // point the group member pointer at the mint
+    ctx.accounts
+        .update_group_member_pointer_member_address(&[&signer_seeds[..]])?;
+
+    // initialize the member extension, token extensions assigns the member number and increments the group size
+    ctx.accounts
+        .initialize_token_group_member(&[&signer_seeds[..]])?;
+
+    // token extensions numbers members in migration order, the legacy member number is kept in the metadata
+    ctx.accounts.update_token_metadata_field(
+        Field::Key(MEMBER_NUMBER_FIELD.to_string()),
+        ctx.accounts.member.member_number.to_string(),
+    )?;
+
+    // update mint lamports to minimum rent balance
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // manager no longer needs to hold on to the mint authority
+    ctx.accounts.remove_mint_authority(&[&signer_seeds[..]])?;
+
+    // close the legacy member account and refund rent
+    let member = &mut ctx.accounts.member;
+    member.close(ctx.accounts.payer.to_account_info())?;
+
+    // the legacy group account holds the size of the group until the group extension has caught up
+    // with it, i.e. no legacy members are left
+    let migrated_size = u32::from(
+        get_extension_data::<TokenGroupExtension>(&mut ctx.accounts.group_mint.to_account_info())?
+            .size,
+    );
+    if migrated_size >= ctx.accounts.group.size {
+        let group = &mut ctx.accounts.group;
+        group.close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
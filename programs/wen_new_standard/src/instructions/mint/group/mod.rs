@@ -1,5 +1,9 @@
 pub mod add;
+pub mod migrate;
 pub mod remove;
+pub mod transfer;
 
 pub use add::*;
+pub use migrate::*;
 pub use remove::*;
+pub use transfer::*;
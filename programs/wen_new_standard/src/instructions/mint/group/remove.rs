@@ -5,8 +5,9 @@ use anchor_spl::token_interface::{
 };
 
 use crate::{
-    get_bump_in_seed_form, Manager, MintErrors, TokenGroup, TokenGroupMember, GROUP_ACCOUNT_SEED,
-    MANAGER_SEED, MEMBER_ACCOUNT_SEED, TOKEN22,
+    get_bump_in_seed_form, get_group_ancestors, get_member_size, update_group_ancestors, Manager,
+    MetadataErrors, MintErrors, TokenGroup, TokenGroupMember, GROUP_ACCOUNT_SEED, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, TOKEN22,
 };
 
 #[derive(Accounts)]
@@ -35,9 +36,16 @@ pub struct RemoveGroup<'info> {
         mint::token_program = TOKEN22
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account of the mint, required when the mint is a sub group
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub sub_group: Option<UncheckedAccount<'info>>,
     #[account(
         seeds = [MANAGER_SEED],
-        bump
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
     )]
     pub manager: Box<Account<'info, Manager>>,
     pub system_program: Program<'info, System>,
@@ -62,8 +70,18 @@ impl RemoveGroup<'_> {
 }
 
 pub fn handler(ctx: Context<RemoveGroup>) -> Result<()> {
+    let size = get_member_size(
+        &ctx.accounts.mint.to_account_info(),
+        ctx.accounts.sub_group.as_deref(),
+    )?;
+
+    // remaining accounts hold the ancestry of the group, every parent group shrinks along with it
+    let ancestors =
+        get_group_ancestors(&ctx.accounts.group.mint, &mut ctx.remaining_accounts.iter())?;
+    update_group_ancestors(&ancestors, |ancestor| ancestor.decrease_size(size))?;
+
     let group = &mut ctx.accounts.group;
-    group.decrement_size()?;
+    group.decrease_size(size)?;
 
     let member = &mut ctx.accounts.member;
     member.close(ctx.accounts.payer.to_account_info())?;
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+
+use crate::{
+    get_group_ancestors, get_member_size, has_asset_consent, update_group_ancestors, Manager,
+    MetadataErrors, MintErrors, TokenGroup, TokenGroupMember, TransferMemberEvent,
+    GROUP_ACCOUNT_SEED, MANAGER_SEED, MEMBER_ACCOUNT_SEED, TOKEN22,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct TransferMember<'info> {
+    /// update authority of the group the mint leaves
+    #[account()]
+    pub authority: Signer<'info>,
+    /// update authority of the group the mint joins
+    #[account()]
+    pub new_authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
+        seeds = [GROUP_ACCOUNT_SEED, group.mint.as_ref()],
+        bump,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        constraint = new_group.update_authority == new_authority.key() @ MetadataErrors::InvalidGroupAuthority,
+        constraint = new_group.key() != group.key() @ MetadataErrors::SameGroup,
+        seeds = [GROUP_ACCOUNT_SEED, new_group.mint.as_ref()],
+        bump,
+    )]
+    pub new_group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        has_one = mint @ MintErrors::InvalidTokenGroupMemberMint,
+        constraint = member.group == group.key() @ MetadataErrors::InvalidGroupMember,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mint::token_program = TOKEN22
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns group account of the mint, required when the mint is a sub group
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub sub_group: Option<UncheckedAccount<'info>>,
+    /// metadata update authority of the mint, or holder of the mint when the token account is passed
+    #[account()]
+    pub asset_authority: Signer<'info>,
+    #[account(
+        token::mint = mint,
+        token::authority = asset_authority,
+        token::token_program = token_program,
+    )]
+    pub asset_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<TransferMember>) -> Result<()> {
+    // joining the new group needs the same consent as `add_mint_to_group`
+    require!(
+        has_asset_consent(
+            &ctx.accounts.mint.to_account_info(),
+            ctx.accounts.asset_authority.key,
+            ctx.accounts
+                .asset_token_account
+                .as_deref()
+                .map(|account| &**account),
+        )?,
+        MetadataErrors::InvalidAssetAuthority
+    );
+
+    let size = get_member_size(
+        &ctx.accounts.mint.to_account_info(),
+        ctx.accounts.sub_group.as_deref(),
+    )?;
+
+    // remaining accounts hold the ancestry of the old group followed by the ancestry of the new group
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let ancestors = get_group_ancestors(&ctx.accounts.group.mint, remaining_accounts)?;
+    let new_ancestors = get_group_ancestors(&ctx.accounts.new_group.mint, remaining_accounts)?;
+    if let Some(sub_group) = &ctx.accounts.sub_group {
+        require!(
+            sub_group.key() != ctx.accounts.new_group.key()
+                && new_ancestors
+                    .iter()
+                    .all(|ancestor| ancestor.key() != sub_group.key()),
+            MetadataErrors::CircularGroupNesting
+        );
+    }
+
+    // either group can be an ancestor of the other, so each group is written back before rolling up
+    // its ancestry and reloaded after the other side rolled up
+    let group = &mut ctx.accounts.group;
+    group.decrease_size(size)?;
+    group.exit(&crate::id())?;
+    update_group_ancestors(&ancestors, |ancestor| ancestor.decrease_size(size))?;
+
+    let new_group = &mut ctx.accounts.new_group;
+    new_group.reload()?;
+    let new_size = new_group.increase_size(size)?;
+    new_group.exit(&crate::id())?;
+    update_group_ancestors(&new_ancestors, |ancestor| ancestor.increase_size(size))?;
+
+    ctx.accounts.group.reload()?;
+
+    // member account and the group member pointer of the mint stay in place
+    let member = &mut ctx.accounts.member;
+    let old_member_number = member.member_number;
+    member.group = ctx.accounts.new_group.key();
+    member.member_number = new_size - size + 1;
+
+    emit!(TransferMemberEvent {
+        mint: member.mint,
+        old_group: ctx.accounts.group.key(),
+        new_group: ctx.accounts.new_group.key(),
+        old_member_number,
+        new_member_number: member.member_number,
+    });
+
+    Ok(())
+}
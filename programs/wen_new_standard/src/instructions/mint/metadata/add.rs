@@ -7,7 +7,9 @@ use anchor_spl::token_interface::{
     TokenMetadataUpdateField,
 };
 
-use crate::{errors::MetadataErrors, update_account_lamports_to_minimum_balance};
+use crate::{
+    errors::MetadataErrors, update_account_lamports_to_minimum_balance, Manager, MANAGER_SEED,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct AddMetadataArgs {
@@ -26,6 +28,12 @@ pub struct AddMetadata<'info> {
         mint::token_program = token_program,
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token2022>,
 }
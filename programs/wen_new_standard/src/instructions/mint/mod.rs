@@ -1,16 +1,20 @@
 pub mod burn;
+pub mod clawback;
 pub mod create;
 pub mod freeze;
 pub mod thaw;
+pub mod update;
 
 pub mod group;
 pub mod metadata;
 pub mod royalties;
 
 pub use burn::*;
+pub use clawback::*;
 pub use create::*;
 pub use freeze::*;
 pub use group::*;
 pub use metadata::*;
 pub use royalties::*;
 pub use thaw::*;
+pub use update::*;
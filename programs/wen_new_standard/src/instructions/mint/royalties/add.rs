@@ -2,15 +2,17 @@ use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
 use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 
 use anchor_spl::token_interface::{
-    spl_token_metadata_interface::state::Field, token_metadata_update_field, transfer_hook_update,
-    Mint, Token2022, TokenMetadataUpdateField, TransferHookUpdate,
+    spl_token_2022::extension::ExtensionType, spl_token_metadata_interface::state::Field,
+    token_metadata_update_field, transfer_hook_update, Mint, Token2022, TokenMetadataUpdateField,
+    TransferHookUpdate,
 };
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 use crate::{
-    get_approve_account_pda, get_meta_list, get_meta_list_size,
-    update_account_lamports_to_minimum_balance, MetadataErrors, UpdateRoyaltiesArgs,
-    META_LIST_ACCOUNT_SEED, ROYALTY_BASIS_POINTS_FIELD,
+    get_approve_account_pda, get_meta_list, get_meta_list_size, get_mint_extension_types,
+    is_group_mint, update_account_lamports_to_minimum_balance, Manager, MetadataErrors,
+    RoyaltyConfig, RoyaltyCreator, UpdateRoyaltiesArgs, CREATOR_SHARES_TOTAL, MANAGER_SEED,
+    META_LIST_ACCOUNT_SEED, ROYALTY_BASIS_POINTS_FIELD, ROYALTY_CONFIG_SEED,
 };
 
 #[derive(Accounts)]
@@ -34,6 +36,20 @@ pub struct AddRoyalties<'info> {
         payer = payer,
     )]
     pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        init,
+        space = RoyaltyConfig::size(args.creators.len()),
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token2022>,
 }
@@ -63,6 +79,20 @@ impl<'info> AddRoyalties<'info> {
 }
 
 pub fn handler(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+    // the royalty config of a group mint is the one its members inherit, it is only set through
+    // the group royalty instructions
+    require!(
+        !is_group_mint(&ctx.accounts.mint.to_account_info()),
+        MetadataErrors::GroupMintRoyalties
+    );
+
+    // soulbound mints never move, so there is no transfer to enforce royalties on
+    require!(
+        !get_mint_extension_types(&ctx.accounts.mint.to_account_info())?
+            .contains(&ExtensionType::NonTransferable),
+        MetadataErrors::SoulboundRoyalties
+    );
+
     // validate that the fee_basis_point is less than 10000 (100%)
     require!(
         args.royalty_basis_points <= 10000,
@@ -75,9 +105,9 @@ pub fn handler(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<
         args.royalty_basis_points.to_string(),
     )?;
 
-    let mut total_share: u8 = 0;
+    let mut total_share: u16 = 0;
     // add creators and their respective shares to metadata
-    for creator in args.creators {
+    for creator in args.creators.iter() {
         total_share = total_share
             .checked_add(creator.share)
             .ok_or(MetadataErrors::CreatorShareInvalid)?;
@@ -87,10 +117,25 @@ pub fn handler(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<
         )?;
     }
 
-    if total_share != 100 {
+    if total_share != CREATOR_SHARES_TOTAL {
         return Err(MetadataErrors::CreatorShareInvalid.into());
     }
 
+    // write the typed royalty config
+    let creators = args
+        .creators
+        .iter()
+        .map(|creator| RoyaltyCreator {
+            address: creator.address,
+            share: creator.share,
+        })
+        .collect();
+    ctx.accounts.royalty_config.set_inner(RoyaltyConfig::new(
+        &ctx.accounts.mint.key(),
+        args.royalty_basis_points,
+        creators,
+    ));
+
     // initialize the extra metas account
     let extra_metas_account = &ctx.accounts.extra_metas_account;
     let metas = get_meta_list(get_approve_account_pda(ctx.accounts.mint.key()));
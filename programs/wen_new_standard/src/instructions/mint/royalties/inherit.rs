@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+
+use anchor_spl::token_interface::{
+    spl_token_2022::extension::ExtensionType, transfer_hook_update, Mint, Token2022,
+    TransferHookUpdate,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    get_approve_account_pda, get_group_path_len, get_meta_list, get_meta_list_size,
+    get_mint_extension_types, Manager, MetadataErrors, RoyaltyConfig, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct InheritRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// transfer hook authority of the mint
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: member account of the mint, checked in the handler
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: UncheckedAccount<'info>,
+    /// royalty config of the group the royalties are inherited from
+    #[account()]
+    pub group_royalty_config: Account<'info, RoyaltyConfig>,
+    /// CHECK: This account's data is a buffer of TLV data
+    #[account(
+        init,
+        space = get_meta_list_size(get_approve_account_pda(mint.key())),
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+    )]
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> InheritRoyalties<'info> {
+    fn update_transfer_hook_program_id(&self) -> Result<()> {
+        let cpi_accounts = TransferHookUpdate {
+            token_program_id: self.token_program.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_hook_update(cpi_ctx, Some(crate::id()))?;
+        Ok(())
+    }
+}
+
+/// remaining accounts hold the group path from the group of the mint up to the group of the
+/// royalty config, see [`get_group_path_len`]
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, InheritRoyalties<'info>>) -> Result<()> {
+    // soulbound mints never move, so there is no transfer to enforce royalties on
+    require!(
+        !get_mint_extension_types(&ctx.accounts.mint.to_account_info())?
+            .contains(&ExtensionType::NonTransferable),
+        MetadataErrors::SoulboundRoyalties
+    );
+
+    // royalties can only be inherited from a group the mint belongs to, directly or through its
+    // sub groups
+    require!(
+        get_group_path_len(
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.member.to_account_info(),
+            &ctx.accounts.group_royalty_config.mint,
+            ctx.remaining_accounts,
+        )?
+        .is_some(),
+        MetadataErrors::InvalidGroupRoyaltyConfig
+    );
+
+    // initialize the extra metas account, the royalties themselves are resolved from the group
+    // on every sale
+    let extra_metas_account = &ctx.accounts.extra_metas_account;
+    let metas = get_meta_list(get_approve_account_pda(ctx.accounts.mint.key()));
+    let mut data = extra_metas_account.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+    // add metadata program as the transfer hook program
+    ctx.accounts.update_transfer_hook_program_id()?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_mint_metadata, is_group_mint, Manager, MetadataErrors, RoyaltyConfig, MANAGER_SEED,
+    ROYALTY_BASIS_POINTS_FIELD, ROYALTY_CONFIG_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct MigrateRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        space = RoyaltyConfig::size(get_royalty_creators_len(&mint.to_account_info())?),
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+fn get_royalty_creators_len(mint: &AccountInfo) -> Result<usize> {
+    let metadata = get_mint_metadata(&mut mint.clone())?;
+    let royalty_config = RoyaltyConfig::from_metadata(&mint.key(), &metadata)?;
+    Ok(royalty_config.creators.len())
+}
+
+pub fn handler(ctx: Context<MigrateRoyalties>) -> Result<()> {
+    // group mints can't get a mint level royalty config, its pda is the group royalty config
+    require!(
+        !is_group_mint(&ctx.accounts.mint.to_account_info()),
+        MetadataErrors::GroupMintRoyalties
+    );
+
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+
+    // only mints that already have royalties in their metadata can be migrated
+    require!(
+        metadata
+            .additional_metadata
+            .iter()
+            .any(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD),
+        MetadataErrors::RoyaltiesNotFound
+    );
+
+    // build the typed royalty config from the metadata mirror
+    let royalty_config = RoyaltyConfig::from_metadata(&ctx.accounts.mint.key(), &metadata)?;
+
+    royalty_config.validate_creator_shares()?;
+
+    ctx.accounts.royalty_config.set_inner(royalty_config);
+
+    Ok(())
+}
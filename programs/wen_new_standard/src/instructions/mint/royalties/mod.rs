@@ -3,7 +3,8 @@ use anchor_lang::prelude::*;
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct CreatorWithShare {
     pub address: Pubkey,
-    pub share: u8,
+    /// share in basis points
+    pub share: u16,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
@@ -13,7 +14,11 @@ pub struct UpdateRoyaltiesArgs {
 }
 
 pub mod add;
+pub mod inherit;
+pub mod migrate;
 pub mod modify;
 
 pub use add::*;
+pub use inherit::*;
+pub use migrate::*;
 pub use modify::*;
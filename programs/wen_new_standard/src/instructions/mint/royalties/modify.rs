@@ -3,11 +3,12 @@ use std::str::FromStr;
 use anchor_lang::{
     prelude::*,
     solana_program::{entrypoint::ProgramResult, program::invoke},
+    system_program::{allocate, assign, create_account, Allocate, Assign, CreateAccount},
 };
 
 use anchor_spl::token_interface::{
     spl_token_2022::{
-        extension::{BaseStateWithExtensions, StateWithExtensions},
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
         state::Mint as BaseStateMint,
     },
     spl_token_metadata_interface::instruction::remove_key,
@@ -17,8 +18,9 @@ use anchor_spl::token_interface::{
 };
 
 use crate::{
-    update_account_lamports_to_minimum_balance, MetadataErrors, UpdateRoyaltiesArgs,
-    ROYALTY_BASIS_POINTS_FIELD,
+    get_mint_extension_types, is_group_mint, update_account_lamports_to_minimum_balance, Manager,
+    MetadataErrors, RoyaltyConfig, RoyaltyCreator, UpdateRoyaltiesArgs, CREATOR_SHARES_TOTAL,
+    MANAGER_SEED, ROYALTY_BASIS_POINTS_FIELD, ROYALTY_CONFIG_SEED,
 };
 
 #[derive(Accounts)]
@@ -33,11 +35,72 @@ pub struct ModifyRoyalties<'info> {
         mint::token_program = token_program,
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: royalty config of the mint, created here for mints with royalties from before the
+    /// royalty config account
+    #[account(
+        mut,
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub royalty_config: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token2022>,
 }
 
 impl<'info> ModifyRoyalties<'info> {
+    fn create_royalty_config_account(&self, space: usize, bump: u8) -> Result<()> {
+        let mint = self.mint.key();
+        let seeds: &[&[u8]; 3] = &[ROYALTY_CONFIG_SEED, mint.as_ref(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        // the account may already hold lamports, in which case it can't be created in one go
+        if self.royalty_config.get_lamports() == 0 {
+            let cpi_accounts = CreateAccount {
+                from: self.payer.to_account_info(),
+                to: self.royalty_config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            create_account(
+                cpi_ctx,
+                Rent::get()?.minimum_balance(space),
+                space as u64,
+                &crate::id(),
+            )?;
+        } else {
+            let cpi_accounts = Allocate {
+                account_to_allocate: self.royalty_config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            allocate(cpi_ctx, space as u64)?;
+
+            let cpi_accounts = Assign {
+                account_to_assign: self.royalty_config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            assign(cpi_ctx, &crate::id())?;
+        }
+
+        Ok(())
+    }
+
     fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
         let cpi_accounts = TokenMetadataUpdateField {
             token_program_id: self.token_program.to_account_info(),
@@ -70,6 +133,20 @@ impl<'info> ModifyRoyalties<'info> {
 }
 
 pub fn handler(ctx: Context<ModifyRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+    // the royalty config pda of a group mint holds the group royalties, which only
+    // `modify_group_royalties` may change
+    require!(
+        !is_group_mint(&ctx.accounts.mint.to_account_info()),
+        MetadataErrors::GroupMintRoyalties
+    );
+
+    // soulbound mints never move, so there is no transfer to enforce royalties on
+    require!(
+        !get_mint_extension_types(&ctx.accounts.mint.to_account_info())?
+            .contains(&ExtensionType::NonTransferable),
+        MetadataErrors::SoulboundRoyalties
+    );
+
     let metadata = {
         let mint_account = ctx.accounts.mint.to_account_info().clone();
         let mint_account_data = mint_account.try_borrow_data()?;
@@ -89,7 +166,7 @@ pub fn handler(ctx: Context<ModifyRoyalties>, args: UpdateRoyaltiesArgs) -> Resu
         args.royalty_basis_points.to_string(),
     )?;
 
-    let mut total_share: u8 = 0;
+    let mut total_share: u16 = 0;
     // add creators and their respective shares to metadata
     for creator in args.creators.clone() {
         // validate that the creator is a valid publickey
@@ -102,23 +179,51 @@ pub fn handler(ctx: Context<ModifyRoyalties>, args: UpdateRoyaltiesArgs) -> Resu
         )?;
     }
 
-    if total_share != 100 {
+    if total_share != CREATOR_SHARES_TOTAL {
         return Err(MetadataErrors::CreatorShareInvalid.into());
     }
 
+    // update the typed royalty config, mints with royalties from before the royalty config account
+    // get one here instead of having to be migrated with `migrate_royalties` first
+    let royalty_config = RoyaltyConfig::new(
+        &ctx.accounts.mint.key(),
+        args.royalty_basis_points,
+        args.creators
+            .iter()
+            .map(|creator| RoyaltyCreator {
+                address: creator.address,
+                share: creator.share,
+            })
+            .collect(),
+    );
+    let royalty_config_space = RoyaltyConfig::size(royalty_config.creators.len());
+    if ctx.accounts.royalty_config.owner == &crate::id() {
+        ctx.accounts
+            .royalty_config
+            .realloc(royalty_config_space, false)?;
+    } else {
+        ctx.accounts
+            .create_royalty_config_account(royalty_config_space, ctx.bumps.royalty_config)?;
+    }
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.royalty_config.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    royalty_config
+        .try_serialize(&mut &mut ctx.accounts.royalty_config.try_borrow_mut_data()?[..])?;
+
     // for all the keys in metadata.additional_metadata, if the key is not in the args, remove it
     let creators = args.creators;
-    let creators_additional_metadata: Vec<&(String, String)> = metadata
+    let creators_additional_metadata: Vec<(&String, Pubkey)> = metadata
         .additional_metadata
         .iter()
         .filter(|(key, _)| !key.starts_with("royalty"))
+        .filter_map(|(key, _)| Pubkey::from_str(key).ok().map(|address| (key, address)))
         .collect();
 
-    for (key, _) in creators_additional_metadata {
-        if !creators
-            .iter()
-            .any(|creator| creator.address == Pubkey::from_str(key).unwrap())
-        {
+    for (key, address) in creators_additional_metadata {
+        if !creators.iter().any(|creator| creator.address == address) {
             ctx.accounts.remove_token_metadata_field(key)?;
         }
     }
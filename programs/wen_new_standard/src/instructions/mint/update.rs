@@ -0,0 +1,130 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenMetadataUpdateField,
+};
+
+use crate::{
+    get_group_account_pda, get_group_update_authority, has_advisory_member_update_gate,
+    is_any_group_member, is_group_member, update_account_lamports_to_minimum_balance, Manager,
+    MetadataErrors, MANAGER_SEED, MEMBER_ACCOUNT_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateMintAccountArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateMintAccountArgs)]
+pub struct UpdateMintAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: group mint of the asset, required when the asset belongs to a group
+    #[account()]
+    pub group_mint: Option<UncheckedAccount<'info>>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account()]
+    pub group: Option<UncheckedAccount<'info>>,
+    /// CHECK: wns member account, can be uninitialized for members migrated to the group member extension
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: Option<UncheckedAccount<'info>>,
+    #[account()]
+    pub group_authority: Option<Signer<'info>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Box<Account<'info, Manager>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> UpdateMintAccount<'info> {
+    fn update_metadata(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, field, value)?;
+        Ok(())
+    }
+
+    /// assets of a group with the advisory member update gate can only be updated here with the
+    /// group update authority signing along, the metadata update authority can still update them
+    /// directly through the token extensions program
+    fn check_group_authority(&self) -> Result<()> {
+        let mint = self.mint.to_account_info();
+        if !is_any_group_member(&mint) {
+            return Ok(());
+        }
+
+        let (Some(group_mint), Some(group), Some(member)) =
+            (&self.group_mint, &self.group, &self.member)
+        else {
+            return err!(MetadataErrors::MissingGroupMember);
+        };
+
+        require_keys_eq!(
+            group.key(),
+            get_group_account_pda(group_mint.key()),
+            ErrorCode::ConstraintSeeds
+        );
+        require!(
+            is_group_member(&mint, member, group_mint.key)?,
+            MetadataErrors::InvalidGroupMember
+        );
+        if !has_advisory_member_update_gate(group)? {
+            return Ok(());
+        }
+
+        let update_authority = get_group_update_authority(group_mint, group)?;
+        require!(
+            update_authority
+                == self
+                    .group_authority
+                    .as_ref()
+                    .map(|group_authority| group_authority.key()),
+            MetadataErrors::InvalidGroupAuthority
+        );
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<UpdateMintAccount>, args: UpdateMintAccountArgs) -> Result<()> {
+    ctx.accounts.check_group_authority()?;
+
+    // update metadata name
+    ctx.accounts.update_metadata(Field::Name, args.name)?;
+
+    // update metadata symbol
+    ctx.accounts.update_metadata(Field::Symbol, args.symbol)?;
+
+    // update metadata uri
+    ctx.accounts.update_metadata(Field::Uri, args.uri)?;
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -1,26 +1,25 @@
-use std::str::FromStr;
-
 use anchor_lang::{
     accounts::{interface_account::InterfaceAccount, program::Program},
     prelude::*,
     solana_program::account_info::AccountInfo,
-    system_program::System,
+    system_program::{transfer, System, Transfer},
 };
 use anchor_spl::token_interface::{
     spl_token_2022::{
-        extension::{BaseStateWithExtensions, StateWithExtensions},
-        state::Mint as BaseStateMint,
+        extension::StateWithExtensions, onchain::invoke_transfer_checked, state::Mint as StateMint,
     },
-    spl_token_metadata_interface::state::TokenMetadata,
     Mint, Token2022, TokenAccount, TokenInterface,
 };
 use wen_royalty_distribution::{
     cpi::{accounts::UpdateDistribution, update_distribution},
     program::WenRoyaltyDistribution,
-    UpdateDistributionArgs,
+    DistributionAccount, UpdateDistributionArgs,
 };
 
-use crate::{ApproveAccount, APPROVE_ACCOUNT_SEED, ROYALTY_BASIS_POINTS_FIELD};
+use crate::{
+    split_group_path, ApproveAccount, Manager, MetadataErrors, RoyaltyConfig, APPROVE_ACCOUNT_SEED,
+    MANAGER_SEED, MEMBER_ACCOUNT_SEED, ROYALTY_CONFIG_SEED,
+};
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
@@ -41,6 +40,26 @@ pub struct ApproveTransfer<'info> {
         space = 8 + ApproveAccount::INIT_SPACE,
     )]
     pub approve_account: Account<'info, ApproveAccount>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump,
+        constraint = !manager.paused @ MetadataErrors::ProgramPaused,
+    )]
+    pub manager: Account<'info, Manager>,
+    /// CHECK: can be uninitialized for mints whose royalties have not been migrated
+    #[account(
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub royalty_config: UncheckedAccount<'info>,
+    /// CHECK: can be uninitialized for mints that are not members of a wns group
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub member: UncheckedAccount<'info>,
+    /// CHECK: royalty config of the distribution group, checked in the handler
+    pub group_royalty_config: UncheckedAccount<'info>,
     /// CHECK: This account can be any mint or Pubkey::default()
     pub payment_mint: UncheckedAccount<'info>,
     #[account(
@@ -57,6 +76,19 @@ pub struct ApproveTransfer<'info> {
         token::token_program = payment_token_program,
     )]
     pub authority_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: protocol treasury, required when a protocol fee is charged in SOL
+    #[account(
+        mut,
+        address = manager.treasury @ MetadataErrors::InvalidTreasury,
+    )]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        token::authority = manager.treasury,
+        token::mint = payment_mint,
+        token::token_program = payment_token_program,
+    )]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     #[account(mut)]
     /// CHECK: CPI Checks
     pub distribution_account: UncheckedAccount<'info>,
@@ -66,8 +98,71 @@ pub struct ApproveTransfer<'info> {
     pub payment_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-impl ApproveTransfer<'_> {
-    pub fn distribute_royalties(&self, amount: u64) -> Result<()> {
+impl<'info> ApproveTransfer<'info> {
+    /// remaining accounts are forwarded for the transfer hook of the payment mint, if any
+    pub fn transfer_protocol_fee(
+        &self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if self.payment_mint.key() == Pubkey::default() {
+            let treasury = self
+                .treasury
+                .as_ref()
+                .ok_or(MetadataErrors::InvalidTreasury)?;
+            return transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.authority.to_account_info(),
+                        to: treasury.to_account_info(),
+                    },
+                ),
+                amount,
+            );
+        }
+
+        let treasury_token_account = self
+            .treasury_token_account
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidTreasury)?;
+        let authority_token_account = self
+            .authority_token_account
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidTreasury)?;
+        let payment_token_program = self
+            .payment_token_program
+            .as_ref()
+            .ok_or(MetadataErrors::InvalidTreasury)?;
+        let decimals = {
+            let payment_mint_data = self.payment_mint.try_borrow_data()?;
+            StateWithExtensions::<StateMint>::unpack(&payment_mint_data)?
+                .base
+                .decimals
+        };
+
+        invoke_transfer_checked(
+            payment_token_program.key,
+            authority_token_account.to_account_info(),
+            self.payment_mint.to_account_info(),
+            treasury_token_account.to_account_info(),
+            self.authority.to_account_info(),
+            remaining_accounts,
+            amount,
+            decimals,
+            &[],
+        )?;
+        Ok(())
+    }
+
+    /// remaining accounts are forwarded for the group path of the mint, its first `group_path_len`
+    /// accounts, and the transfer hook of the payment mint, if any
+    pub fn distribute_royalties(
+        &self,
+        amount: u64,
+        group_path_len: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
         let distribution_token_account_info = self
             .distribution_token_account
             .as_ref()
@@ -86,6 +181,10 @@ impl ApproveTransfer<'_> {
         let cpi_accounts = UpdateDistribution {
             authority: self.authority.to_account_info(),
             mint: self.mint.to_account_info(),
+            royalty_config: self.royalty_config.to_account_info(),
+            group_royalty_config: self.group_royalty_config.to_account_info(),
+            // always verify that the mint belongs to the group of the distribution account
+            member: Some(self.member.to_account_info()),
             payment_mint: self.payment_mint.to_account_info(),
             distribution_account: self.distribution_account.to_account_info(),
             distribution_token_account: distribution_token_account_info,
@@ -94,34 +193,69 @@ impl ApproveTransfer<'_> {
             token_program: self.token_program.to_account_info(),
             payment_token_program: payment_token_program_account_info,
         };
-        let cpi_ctx = CpiContext::new(self.distribution_program.to_account_info(), cpi_accounts);
-        update_distribution(cpi_ctx, UpdateDistributionArgs { amount })
+        let cpi_ctx = CpiContext::new(self.distribution_program.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(remaining_accounts.to_vec());
+        update_distribution(
+            cpi_ctx,
+            UpdateDistributionArgs {
+                amount,
+                group_path_len,
+            },
+        )
     }
 }
 
-pub fn handler(ctx: Context<ApproveTransfer>, amount: u64) -> Result<()> {
-    let mint_account = ctx.accounts.mint.to_account_info();
-    let mint_account_data = mint_account.try_borrow_data()?;
-    let mint_data = StateWithExtensions::<BaseStateMint>::unpack(&mint_account_data)?;
-    let metadata = mint_data.get_variable_len_extension::<TokenMetadata>()?;
-
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApproveTransfer<'info>>,
+    amount: u64,
+    group_path_len: u8,
+) -> Result<()> {
     // Load clock and write slot
     let clock = Clock::get()?;
     ctx.accounts.approve_account.slot = clock.slot;
 
-    // get royalty basis points from metadata Vec(String, String)
-    let royalty_basis_points = metadata
-        .additional_metadata
-        .iter()
-        .find(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
-        .map(|(_, value)| value)
-        .map(|value| u64::from_str(value).unwrap())
-        .unwrap_or(0);
+    // group royalty config has to belong to the group of the distribution account, owner is checked in the cpi
+    let group_mint = {
+        let distribution_account_data = ctx.accounts.distribution_account.try_borrow_data()?;
+        DistributionAccount::try_deserialize(&mut &distribution_account_data[..])?.group_mint
+    };
+    require_keys_eq!(
+        ctx.accounts.group_royalty_config.key(),
+        Pubkey::find_program_address(&[ROYALTY_CONFIG_SEED, group_mint.as_ref()], &crate::id()).0,
+        MetadataErrors::InvalidGroupRoyaltyConfig
+    );
+
+    // remaining accounts start with the path up to the distribution group for members of sub groups,
+    // followed by the extra accounts of the payment mint transfer hook
+    let (group_path, hook_accounts) = split_group_path(ctx.remaining_accounts, group_path_len)?;
 
-    let royalty_amount = (amount * royalty_basis_points) / 10000;
+    // get royalty basis points from the royalty config, inherited from the group if the mint has none
+    let royalty_config = RoyaltyConfig::load(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.royalty_config.to_account_info(),
+        &ctx.accounts.member.to_account_info(),
+        &group_mint,
+        &ctx.accounts.group_royalty_config.to_account_info(),
+        group_path,
+    )?;
+    let royalty_amount = royalty_config.royalty_amount(amount)?;
+
+    // protocol cut of the royalties goes to the treasury
+    let protocol_fee_amount = ctx.accounts.manager.protocol_fee_amount(royalty_amount)?;
+    if protocol_fee_amount > 0 {
+        ctx.accounts
+            .transfer_protocol_fee(protocol_fee_amount, hook_accounts)?;
+    }
 
-    // transfer royalty amount to distribution pda
-    ctx.accounts.distribute_royalties(royalty_amount)?;
+    // transfer the rest of the royalty amount to distribution pda
+    let creator_royalty_amount = royalty_amount
+        .checked_sub(protocol_fee_amount)
+        .ok_or(MetadataErrors::ArithmeticOverflow)?;
+    ctx.accounts.distribute_royalties(
+        creator_royalty_amount,
+        group_path_len,
+        ctx.remaining_accounts,
+    )?;
 
     Ok(())
 }
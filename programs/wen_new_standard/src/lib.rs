@@ -3,11 +3,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -26,6 +28,32 @@ pub mod wen_new_standard {
         instructions::manager::init::handler(ctx)
     }
 
+    /// Update protocol config of manager account
+    pub fn update_manager_account(
+        ctx: Context<UpdateManagerAccount>,
+        args: UpdateManagerAccountArgs,
+    ) -> Result<()> {
+        instructions::manager::update::handler(ctx, args)
+    }
+
+    /// Migrate zero-sized manager account to hold the protocol config
+    pub fn migrate_manager_account(
+        ctx: Context<MigrateManagerAccount>,
+        args: UpdateManagerAccountArgs,
+    ) -> Result<()> {
+        instructions::manager::migrate::handler(ctx, args)
+    }
+
+    /// Pause state-changing instructions of the program
+    pub fn pause(ctx: Context<PauseProgram>) -> Result<()> {
+        instructions::manager::pause::handler(ctx, true)
+    }
+
+    /// Unpause the program
+    pub fn unpause(ctx: Context<PauseProgram>) -> Result<()> {
+        instructions::manager::pause::handler(ctx, false)
+    }
+
     /// Token group instructions
     /// create group
     pub fn create_group_account(
@@ -43,6 +71,45 @@ pub mod wen_new_standard {
         instructions::group::update::handler(ctx, args)
     }
 
+    /// migrate group to the token extensions group extension
+    pub fn migrate_group(ctx: Context<MigrateGroup>) -> Result<()> {
+        instructions::group::migrate::handler(ctx)
+    }
+
+    /// propose a new update authority for group, pending until it accepts
+    pub fn propose_group_authority(
+        ctx: Context<ProposeGroupAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::group::authority::propose::handler(ctx, new_authority)
+    }
+
+    /// accept the update authority of group as the pending authority
+    pub fn accept_group_authority(ctx: Context<AcceptGroupAuthority>) -> Result<()> {
+        instructions::group::authority::accept::handler(ctx)
+    }
+
+    /// cancel the pending update authority of group, the group goes back to its proposer
+    pub fn cancel_group_authority(ctx: Context<CancelGroupAuthority>) -> Result<()> {
+        instructions::group::authority::cancel::handler(ctx)
+    }
+
+    /// add royalties to group, inherited by members without royalties of their own
+    pub fn add_group_royalties(
+        ctx: Context<AddGroupRoyalties>,
+        args: UpdateRoyaltiesArgs,
+    ) -> Result<()> {
+        instructions::group::royalties::add::handler(ctx, args)
+    }
+
+    /// modify royalties of group
+    pub fn modify_group_royalties(
+        ctx: Context<ModifyGroupRoyalties>,
+        args: UpdateRoyaltiesArgs,
+    ) -> Result<()> {
+        instructions::group::royalties::modify::handler(ctx, args)
+    }
+
     /// create mint
     pub fn create_mint_account(
         ctx: Context<CreateMintAccount>,
@@ -51,6 +118,14 @@ pub mod wen_new_standard {
         instructions::mint::create::handler(ctx, args)
     }
 
+    /// update mint
+    pub fn update_mint_account(
+        ctx: Context<UpdateMintAccount>,
+        args: UpdateMintAccountArgs,
+    ) -> Result<()> {
+        instructions::mint::update::handler(ctx, args)
+    }
+
     /// add mint to group
     pub fn add_mint_to_group(ctx: Context<AddGroup>) -> Result<()> {
         instructions::mint::group::add::handler(ctx)
@@ -61,11 +136,28 @@ pub mod wen_new_standard {
         instructions::mint::group::remove::handler(ctx)
     }
 
+    /// move mint from its group to another group
+    pub fn transfer_member(ctx: Context<TransferMember>) -> Result<()> {
+        instructions::mint::group::transfer::handler(ctx)
+    }
+
+    /// migrate group member to the token extensions group member extension
+    pub fn migrate_member(ctx: Context<MigrateMember>) -> Result<()> {
+        instructions::mint::group::migrate::handler(ctx)
+    }
+
     /// add royalties to mint
     pub fn add_royalties(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
         instructions::mint::royalties::add::handler(ctx, args)
     }
 
+    /// enforce the royalties a mint inherits from its group on transfers
+    pub fn inherit_royalties<'info>(
+        ctx: Context<'_, '_, '_, 'info, InheritRoyalties<'info>>,
+    ) -> Result<()> {
+        instructions::mint::royalties::inherit::handler(ctx)
+    }
+
     /// modify royalties of mint
     pub fn modify_royalties(
         ctx: Context<ModifyRoyalties>,
@@ -74,6 +166,11 @@ pub mod wen_new_standard {
         instructions::mint::royalties::modify::handler(ctx, args)
     }
 
+    /// migrate royalties of mint from metadata to the royalty config account
+    pub fn migrate_royalties(ctx: Context<MigrateRoyalties>) -> Result<()> {
+        instructions::mint::royalties::migrate::handler(ctx)
+    }
+
     /// add additional metadata to mint
     pub fn add_metadata(ctx: Context<AddMetadata>, args: Vec<AddMetadataArgs>) -> Result<()> {
         instructions::mint::metadata::add::handler(ctx, args)
@@ -102,6 +199,11 @@ pub mod wen_new_standard {
         instructions::mint::burn::handler(ctx)
     }
 
+    /// claw back mint from its holder to a recovery address as the issuer
+    pub fn clawback<'info>(ctx: Context<'_, '_, '_, 'info, Clawback<'info>>) -> Result<()> {
+        instructions::mint::clawback::handler(ctx)
+    }
+
     /// Royalty distribution + enforcement instructions
     /// validate transfer
     #[interface(spl_transfer_hook_interface::execute)]
@@ -110,7 +212,11 @@ pub mod wen_new_standard {
     }
 
     /// approve transfer
-    pub fn approve_transfer(ctx: Context<ApproveTransfer>, buy_amount: u64) -> Result<()> {
-        instructions::royalty::approve::handler(ctx, buy_amount)
+    pub fn approve_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveTransfer<'info>>,
+        buy_amount: u64,
+        group_path_len: u8,
+    ) -> Result<()> {
+        instructions::royalty::approve::handler(ctx, buy_amount, group_path_len)
     }
 }
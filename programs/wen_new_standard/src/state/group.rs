@@ -1,11 +1,12 @@
-use anchor_lang::prelude::*;
+use std::io::Write;
+
+use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::token_interface::spl_pod::error::PodSliceError;
 
 use crate::MetadataErrors;
 
 /// Data struct for a `TokenGroup`
-#[account()]
-#[derive(InitSpace)]
+#[derive(AnchorSerialize, Clone, InitSpace)]
 pub struct TokenGroup {
     /// The authority that can sign to update the group
     pub update_authority: Pubkey,
@@ -14,53 +15,175 @@ pub struct TokenGroup {
     pub mint: Pubkey,
     /// The current number of group members
     pub size: u32,
-    /// The maximum number of group members
+    /// The maximum number of group members, `u32::MAX` for unbounded groups
     pub max_size: u32,
+    /// Whether the max size is locked for good
+    pub max_size_locked: bool,
+    /// Whether `update_mint_account` needs the update authority of the group to sign along for
+    /// member mints. Advisory only, the metadata update authority of a member can still update it
+    /// directly through the token extensions program
+    pub advisory_member_update_gate: bool,
+}
+
+impl Discriminator for TokenGroup {
+    const DISCRIMINATOR: [u8; 8] = [184, 107, 4, 187, 196, 55, 142, 134];
+}
+
+impl Owner for TokenGroup {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+// group accounts created before the group flags end after the max size. They are read with both
+// flags off and written back in their own size as long as the flags stay off, so they can be used
+// without a migration
+impl AnchorDeserialize for TokenGroup {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Self {
+            update_authority: Pubkey::deserialize(buf)?,
+            mint: Pubkey::deserialize(buf)?,
+            size: u32::deserialize(buf)?,
+            max_size: u32::deserialize(buf)?,
+            max_size_locked: Self::deserialize_flag(buf)?,
+            advisory_member_update_gate: Self::deserialize_flag(buf)?,
+        })
+    }
+}
+
+impl AccountSerialize for TokenGroup {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let mut data = Self::DISCRIMINATOR.to_vec();
+        self.serialize(&mut data)?;
+        let written = writer
+            .write(&data)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        let has_flags = self.max_size_locked || self.advisory_member_update_gate;
+        require!(
+            written == data.len() || (written == Self::LEGACY_LEN && !has_flags),
+            ErrorCode::AccountDidNotSerialize
+        );
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for TokenGroup {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        require!(
+            buf.len() >= Self::DISCRIMINATOR.len(),
+            ErrorCode::AccountDiscriminatorNotFound
+        );
+        require!(
+            buf[..8] == Self::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data: &[u8] = &buf[8..];
+        Self::deserialize(&mut data).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
 }
 
 impl TokenGroup {
-    /// Creates a new `TokenGroup` state
-    pub fn new(mint: &Pubkey, update_authority: Pubkey, max_size: u32) -> Self {
+    /// Size of group accounts created before the group flags
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 4 + 4;
+
+    fn deserialize_flag(buf: &mut &[u8]) -> std::io::Result<bool> {
+        if buf.is_empty() {
+            return Ok(false);
+        }
+        bool::deserialize(buf)
+    }
+
+    /// Creates a new `TokenGroup` state, unbounded without a max size
+    pub fn new(mint: &Pubkey, update_authority: Pubkey, max_size: Option<u32>) -> Self {
         Self {
             mint: *mint,
             update_authority,
             size: 0,
-            max_size,
+            max_size: max_size.unwrap_or(u32::MAX),
+            max_size_locked: false,
+            advisory_member_update_gate: false,
         }
     }
 
-    /// Updates the max size for a group
-    pub fn update_max_size(&mut self, new_max_size: u32) -> Result<()> {
+    /// The maximum number of group members, `None` for unbounded groups
+    pub fn get_max_size(&self) -> Option<u32> {
+        (self.max_size != u32::MAX).then_some(self.max_size)
+    }
+
+    /// Updates the max size for a group, `None` makes the group unbounded
+    pub fn update_max_size(&mut self, new_max_size: Option<u32>) -> Result<()> {
+        if new_max_size == self.get_max_size() {
+            return Ok(());
+        }
+        if self.max_size_locked {
+            return Err(MetadataErrors::MaxSizeLocked.into());
+        }
         // The new max size cannot be less than the current size
-        if new_max_size < self.size {
+        if new_max_size.is_some_and(|new_max_size| new_max_size < self.size) {
             return Err(MetadataErrors::MaxSizeBelowCurrentSize.into());
         }
-        self.max_size = new_max_size;
+        self.max_size = new_max_size.unwrap_or(u32::MAX);
         Ok(())
     }
 
+    /// Locks the max size of a group, it can't be updated anymore
+    pub fn lock_max_size(&mut self) {
+        self.max_size_locked = true;
+    }
+
     /// Increment the size for a group, returning the new size
     pub fn increment_size(&mut self) -> Result<u32> {
+        self.increase_size(1)
+    }
+
+    /// Increment the size for a group, returning the new size
+    pub fn decrement_size(&mut self) -> Result<u32> {
+        self.decrease_size(1)
+    }
+
+    /// Increase the size for a group by `amount`, e.g. a sub group joining along with its members
+    pub fn increase_size(&mut self, amount: u32) -> Result<u32> {
         // The new size cannot be greater than the max size
         let new_size = self
             .size
-            .checked_add(1)
+            .checked_add(amount)
             .ok_or::<ProgramError>(PodSliceError::CalculationFailure.into())?;
-        if new_size > self.max_size {
+        if self
+            .get_max_size()
+            .is_some_and(|max_size| new_size > max_size)
+        {
             return Err(MetadataErrors::SizeExceedsMaxSize.into());
         }
         self.size = new_size;
         Ok(new_size)
     }
 
-    /// Increment the size for a group, returning the new size
-    pub fn decrement_size(&mut self) -> Result<u32> {
-        // The new size cannot be greater than the max size
+    /// Decrease the size for a group by `amount`, returning the new size
+    pub fn decrease_size(&mut self, amount: u32) -> Result<u32> {
         let new_size = self
             .size
-            .checked_sub(1)
+            .checked_sub(amount)
             .ok_or::<ProgramError>(PodSliceError::CalculationFailure.into())?;
         self.size = new_size;
         Ok(new_size)
     }
 }
+
+/// Pending transfer of the update authority of a group. The proposal PDA is the group update
+/// authority and the metadata update authority of the group mint until the pending authority
+/// accepts or the proposer cancels
+#[account()]
+#[derive(InitSpace)]
+pub struct GroupAuthorityProposal {
+    pub bump: u8,
+    /// The group mint
+    pub mint: Pubkey,
+    /// The update authority that proposed the transfer
+    pub authority: Pubkey,
+    /// The authority that has to sign to take over the group
+    pub pending_authority: Pubkey,
+}
@@ -1,7 +1,45 @@
 use anchor_lang::prelude::*;
 
+use crate::{MetadataErrors, MAX_PROTOCOL_FEE_BASIS_POINTS};
+
 /// Data struct for a `Manager`
 #[account()]
 #[derive(InitSpace)]
-pub struct Manager {}
-impl Manager {}
+pub struct Manager {
+    /// authority that can update the protocol config
+    pub admin: Pubkey,
+    /// cut of every royalty payment routed to the treasury, in basis points of the royalty amount
+    pub protocol_fee_basis_points: u16,
+    /// receiver of the protocol fees
+    pub treasury: Pubkey,
+    /// state-changing instructions are rejected while paused, users can still exit
+    pub paused: bool,
+}
+
+impl Manager {
+    pub fn update(
+        &mut self,
+        admin: Pubkey,
+        protocol_fee_basis_points: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        require!(
+            protocol_fee_basis_points <= MAX_PROTOCOL_FEE_BASIS_POINTS,
+            MetadataErrors::ProtocolFeeBasisPointsInvalid
+        );
+
+        self.admin = admin;
+        self.protocol_fee_basis_points = protocol_fee_basis_points;
+        self.treasury = treasury;
+        Ok(())
+    }
+
+    /// protocol cut out of `royalty_amount` (royalty_amount * protocol_fee_basis_points / 10000)
+    pub fn protocol_fee_amount(&self, royalty_amount: u64) -> Result<u64> {
+        let protocol_fee_amount = (royalty_amount as u128)
+            .checked_mul(self.protocol_fee_basis_points as u128)
+            .ok_or(MetadataErrors::ArithmeticOverflow)?
+            / 10_000;
+        Ok(protocol_fee_amount as u64)
+    }
+}
@@ -1,12 +1,21 @@
 use anchor_lang::solana_program::pubkey::Pubkey;
 
-pub const ROYALTY_BASIS_POINTS_FIELD: &str = "royalty_basis_points";
+pub use wen_royalty_distribution::{
+    CREATOR_SHARES_TOTAL, LEGACY_CREATOR_SHARES_TOTAL, ROYALTY_BASIS_POINTS_FIELD,
+};
+
+/// member number of a mint in its legacy group, kept in the metadata when the mint is migrated
+pub const MEMBER_NUMBER_FIELD: &str = "member_number";
+/// protocol fee is a cut of the royalty amount, at most 5% of it
+pub const MAX_PROTOCOL_FEE_BASIS_POINTS: u16 = 500;
 
 pub const MANAGER_SEED: &[u8] = b"manager";
 pub const GROUP_ACCOUNT_SEED: &[u8] = b"group";
+pub const GROUP_AUTHORITY_PROPOSAL_SEED: &[u8] = b"group-authority-proposal";
 pub const MEMBER_ACCOUNT_SEED: &[u8] = b"member";
 pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
 pub const APPROVE_ACCOUNT_SEED: &[u8] = b"approve-account";
+pub const ROYALTY_CONFIG_SEED: &[u8] = b"royalty-config";
 
 pub const TOKEN22: Pubkey = anchor_spl::token_2022::ID;
 
@@ -14,8 +23,10 @@ pub mod approve;
 pub mod group;
 pub mod manager;
 pub mod member;
+pub mod royalty;
 
 pub use approve::*;
 pub use group::*;
 pub use manager::*;
 pub use member::*;
+pub use royalty::*;
@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+
+use wen_royalty_distribution::CreatorShare;
+
+use crate::{
+    get_group_path_len, get_mint_metadata, MetadataErrors, CREATOR_SHARES_TOTAL,
+    ROYALTY_BASIS_POINTS_FIELD,
+};
+
+/// Data struct for a royalty `Creator`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RoyaltyCreator {
+    /// The creator address
+    pub address: Pubkey,
+    /// The creator share in basis points
+    pub share: u16,
+}
+
+/// Data struct for a `RoyaltyConfig`
+#[account()]
+#[derive(InitSpace)]
+pub struct RoyaltyConfig {
+    /// The royalty config version
+    pub version: u8,
+    /// The associated mint
+    pub mint: Pubkey,
+    /// The royalty basis points charged on every sale
+    pub royalty_basis_points: u16,
+    /// The creators splitting the royalties
+    #[max_len(0)]
+    pub creators: Vec<RoyaltyCreator>,
+}
+
+impl RoyaltyConfig {
+    pub const VERSION: u8 = 1;
+
+    /// Size of a `RoyaltyConfig` account holding `creators_len` creators
+    pub fn size(creators_len: usize) -> usize {
+        8 + Self::INIT_SPACE + creators_len * RoyaltyCreator::INIT_SPACE
+    }
+
+    /// Creates a new `RoyaltyConfig` state
+    pub fn new(mint: &Pubkey, royalty_basis_points: u16, creators: Vec<RoyaltyCreator>) -> Self {
+        Self {
+            version: Self::VERSION,
+            mint: *mint,
+            royalty_basis_points,
+            creators,
+        }
+    }
+
+    /// Builds a `RoyaltyConfig` from the royalty fields mirrored in the mint additional metadata,
+    /// legacy creator shares in whole percentages are converted to basis points
+    pub fn from_metadata(mint: &Pubkey, metadata: &TokenMetadata) -> Result<Self> {
+        let royalty_basis_points = match metadata
+            .additional_metadata
+            .iter()
+            .find(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
+        {
+            Some((_, value)) => {
+                u16::from_str(value).map_err(|_| MetadataErrors::RoyaltyBasisPointsInvalid)?
+            }
+            None => 0,
+        };
+        let creators = CreatorShare::from_metadata(&metadata.additional_metadata)?
+            .into_iter()
+            .map(|creator| RoyaltyCreator {
+                address: creator.address,
+                share: creator.share,
+            })
+            .collect();
+
+        Ok(Self::new(mint, royalty_basis_points, creators))
+    }
+
+    /// Loads the `RoyaltyConfig` that applies to a mint: its own royalty config, then the royalties
+    /// mirrored in its metadata, then the royalty config of the group it is a member of. Members of
+    /// sub groups inherit from the nearest group above them with a royalty config, `group_path` leads
+    /// up to it from the group of the mint, see [`get_group_path_len`]
+    pub fn load(
+        mint: &AccountInfo,
+        royalty_config: &AccountInfo,
+        member: &AccountInfo,
+        group_mint: &Pubkey,
+        group_royalty_config: &AccountInfo,
+        group_path: &[AccountInfo],
+    ) -> Result<Self> {
+        if let Some(royalty_config) = Self::try_from_account_info(royalty_config)? {
+            return Ok(royalty_config);
+        }
+
+        let metadata = get_mint_metadata(&mut mint.clone())?;
+        if metadata
+            .additional_metadata
+            .iter()
+            .any(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
+        {
+            return Self::from_metadata(&mint.key(), &metadata);
+        }
+
+        if let Some(group_path_len) = get_group_path_len(mint, member, group_mint, group_path)? {
+            // groups in between can't have a royalty config, the nearest one applies
+            for accounts in group_path[..group_path_len].chunks_exact(3) {
+                require!(
+                    Self::try_from_account_info(&accounts[1])?.is_none(),
+                    MetadataErrors::InvalidGroupRoyaltyConfig
+                );
+            }
+            if let Some(group_royalty_config) = Self::try_from_account_info(group_royalty_config)? {
+                return Ok(group_royalty_config);
+            }
+        }
+
+        Ok(Self::new(&mint.key(), 0, vec![]))
+    }
+
+    /// Returns `None` if the royalty config account has not been created
+    pub fn try_from_account_info(royalty_config: &AccountInfo) -> Result<Option<Self>> {
+        if royalty_config.owner != &crate::id() || royalty_config.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = royalty_config.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Checks that the creator shares add up to `CREATOR_SHARES_TOTAL`
+    pub fn validate_creator_shares(&self) -> Result<()> {
+        require!(
+            total_creator_shares(&self.creators) == Some(CREATOR_SHARES_TOTAL),
+            MetadataErrors::CreatorShareInvalid
+        );
+        Ok(())
+    }
+
+    /// Royalty amount owed for a sale of `amount`
+    pub fn royalty_amount(&self, amount: u64) -> Result<u64> {
+        let royalty_amount = (amount as u128)
+            .checked_mul(self.royalty_basis_points as u128)
+            .ok_or(MetadataErrors::ArithmeticOverflow)?
+            / 10000;
+        Ok(royalty_amount as u64)
+    }
+}
+
+fn total_creator_shares(creators: &[RoyaltyCreator]) -> Option<u16> {
+    creators
+        .iter()
+        .try_fold(0u16, |total, creator| total.checked_add(creator.share))
+}
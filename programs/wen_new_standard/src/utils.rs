@@ -1,6 +1,13 @@
-use crate::{APPROVE_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED};
+use std::slice::Iter;
+
+use crate::{
+    MetadataErrors, TokenGroup, TokenGroupMember, APPROVE_ACCOUNT_SEED, GROUP_ACCOUNT_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED, ROYALTY_CONFIG_SEED,
+};
 use anchor_lang::{
+    err, error,
     prelude::Result,
+    require, require_keys_eq,
     solana_program::{
         account_info::AccountInfo,
         instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
@@ -8,19 +15,27 @@ use anchor_lang::{
         pubkey::Pubkey,
         rent::Rent,
         system_instruction::transfer,
+        system_program,
         sysvar::Sysvar,
     },
-    Lamports,
+    AccountDeserialize, AccountSerialize, Key, Lamports,
 };
 use anchor_spl::token_interface::{
     spl_token_2022::{
-        extension::{BaseStateWithExtensions, Extension, StateWithExtensions},
+        extension::{
+            group_member_pointer::GroupMemberPointer, group_pointer::GroupPointer,
+            BaseStateWithExtensions, Extension, ExtensionType, StateWithExtensions,
+        },
         solana_zk_token_sdk::zk_token_proof_instruction::Pod,
         state::Mint,
     },
     spl_token_metadata_interface::state::TokenMetadata,
+    TokenAccount,
 };
 use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_token_group_interface::state::{
+    TokenGroup as TokenGroupExtension, TokenGroupMember as TokenGroupMemberExtension,
+};
 
 pub fn get_bump_in_seed_form(bump: &u8) -> [u8; 1] {
     let bump_val = *bump;
@@ -44,6 +59,21 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
+pub fn close_program_account<'info>(
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+) -> Result<()> {
+    let destination_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = destination_lamports
+        .checked_add(account.lamports())
+        .unwrap();
+    **account.lamports.borrow_mut() = 0;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
+
 pub fn get_mint_metadata(account: &mut AccountInfo) -> Result<TokenMetadata> {
     let mint_data = account.data.borrow();
     let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
@@ -58,6 +88,267 @@ pub fn get_extension_data<T: Extension + Pod>(account: &mut AccountInfo) -> Resu
     Ok(extension_data)
 }
 
+pub fn get_mint_extension_types(account: &AccountInfo) -> Result<Vec<ExtensionType>> {
+    let mint_data = account.data.borrow();
+    let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let extension_types = mint_with_extension.get_extension_types()?;
+    Ok(extension_types)
+}
+
+pub fn get_group_account_pda(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[GROUP_ACCOUNT_SEED, mint.as_ref()], &crate::id()).0
+}
+
+/// Check if a mint is a member of the group of `group_mint`, either through a wns member account or
+/// through the token extensions group member extension
+pub fn is_group_member(
+    mint: &AccountInfo,
+    member: &AccountInfo,
+    group_mint: &Pubkey,
+) -> Result<bool> {
+    if member.owner == &crate::id() && !member.data_is_empty() {
+        let member_data = member.try_borrow_data()?;
+        let member = TokenGroupMember::try_deserialize(&mut &member_data[..])?;
+        return Ok(member.mint == *mint.key && member.group == get_group_account_pda(*group_mint));
+    }
+
+    let group_member = get_extension_data::<TokenGroupMemberExtension>(&mut mint.clone()).ok();
+    Ok(group_member.is_some_and(|group_member| group_member.group == *group_mint))
+}
+
+/// Get the update authority of a group, from the wns group account if the group has not been
+/// migrated yet, else from the token extensions group extension of the mint
+pub fn get_group_update_authority(
+    group_mint: &AccountInfo,
+    group: &AccountInfo,
+) -> Result<Option<Pubkey>> {
+    if group.owner == &crate::id() && !group.data_is_empty() {
+        let group_data = group.try_borrow_data()?;
+        let group = TokenGroup::try_deserialize(&mut &group_data[..])?;
+        return Ok(Some(group.update_authority));
+    }
+
+    let group = get_extension_data::<TokenGroupExtension>(&mut group_mint.clone()).ok();
+    Ok(group.and_then(|group| Option::<Pubkey>::from(group.update_authority)))
+}
+
+/// Check if the group authority has to sign along with `update_mint_account` for the members of a
+/// group. Groups migrated to the group extension are not gated
+pub fn has_advisory_member_update_gate(group: &AccountInfo) -> Result<bool> {
+    Ok(try_get_group(group)?.is_some_and(|group| group.advisory_member_update_gate))
+}
+
+/// Check if a mint is a member of any group, the group member pointer of a wns mint only points at a
+/// member while the mint belongs to a group
+pub fn is_any_group_member(mint: &AccountInfo) -> bool {
+    get_extension_data::<GroupMemberPointer>(&mut mint.clone())
+        .ok()
+        .and_then(|pointer| Option::<Pubkey>::from(pointer.member_address))
+        .is_some()
+}
+
+/// Check if a mint is a group mint, whose royalty config is the one inherited by its members
+pub fn is_group_mint(mint: &AccountInfo) -> bool {
+    get_extension_data::<GroupPointer>(&mut mint.clone())
+        .ok()
+        .and_then(|pointer| Option::<Pubkey>::from(pointer.group_address))
+        .is_some()
+}
+
+/// Check if the asset consents to joining a group, `asset_authority` has to be the metadata update
+/// authority of the mint or the owner of `asset_token_account` holding the mint
+pub fn has_asset_consent(
+    mint: &AccountInfo,
+    asset_authority: &Pubkey,
+    asset_token_account: Option<&TokenAccount>,
+) -> Result<bool> {
+    let metadata = get_mint_metadata(&mut mint.clone())?;
+    let is_update_authority =
+        Option::<Pubkey>::from(metadata.update_authority) == Some(*asset_authority);
+    let is_holder = asset_token_account.is_some_and(|token_account| {
+        token_account.owner == *asset_authority && token_account.amount > 0
+    });
+    Ok(is_update_authority || is_holder)
+}
+
+/// Wns group account of a legacy group, `None` once the group and its members were migrated to the
+/// group extension
+fn try_get_group(group: &AccountInfo) -> Result<Option<TokenGroup>> {
+    if group.owner != &crate::id() || group.data_is_empty() {
+        return Ok(None);
+    }
+    let group_data = group.try_borrow_data()?;
+    Ok(Some(TokenGroup::try_deserialize(&mut &group_data[..])?))
+}
+
+/// Wns member account of a mint, `None` if the mint is not a member or was migrated to the group
+/// member extension
+fn try_get_member(member: &AccountInfo) -> Result<Option<TokenGroupMember>> {
+    if member.owner != &crate::id() || member.data_is_empty() {
+        return Ok(None);
+    }
+    let member_data = member.try_borrow_data()?;
+    Ok(Some(TokenGroupMember::try_deserialize(
+        &mut &member_data[..],
+    )?))
+}
+
+/// Splits the remaining accounts of a sale into the group path of the mint, its first
+/// `group_path_len` accounts, and the extra accounts of the payment mint transfer hook
+pub fn split_group_path<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    group_path_len: u8,
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    let group_path_len = group_path_len as usize;
+    require!(
+        group_path_len <= remaining_accounts.len(),
+        MetadataErrors::InvalidGroupAncestry
+    );
+    Ok(remaining_accounts.split_at(group_path_len))
+}
+
+/// Parent groups of the group of `group_mint`, nearest first. `accounts` hold the member account of
+/// the group mint followed by the parent group, repeated up to the member account of a group mint
+/// without a parent. The walk also ends at a parent migrated to the group extension, which keeps
+/// track of its own size
+pub fn get_group_ancestors<'a, 'info>(
+    group_mint: &Pubkey,
+    accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Vec<&'a AccountInfo<'info>>> {
+    let mut ancestors = vec![];
+    let mut mint = *group_mint;
+    loop {
+        let member = accounts
+            .next()
+            .ok_or(MetadataErrors::InvalidGroupAncestry)?;
+        require_keys_eq!(
+            member.key(),
+            Pubkey::find_program_address(&[MEMBER_ACCOUNT_SEED, mint.as_ref()], &crate::id()).0,
+            MetadataErrors::InvalidGroupAncestry
+        );
+        let Some(member) = try_get_member(member)? else {
+            return Ok(ancestors);
+        };
+
+        let parent = accounts
+            .next()
+            .ok_or(MetadataErrors::InvalidGroupAncestry)?;
+        require_keys_eq!(
+            parent.key(),
+            member.group,
+            MetadataErrors::InvalidGroupAncestry
+        );
+        let Some(parent_group) = try_get_group(parent)? else {
+            return Ok(ancestors);
+        };
+
+        ancestors.push(parent);
+        mint = parent_group.mint;
+    }
+}
+
+/// Applies `update` to the size of every ancestor group, written back right away as the same group
+/// can show up in more than one ancestry
+pub fn update_group_ancestors(
+    ancestors: &[&AccountInfo],
+    update: impl Fn(&mut TokenGroup) -> Result<u32>,
+) -> Result<()> {
+    for ancestor in ancestors {
+        let Some(mut group) = try_get_group(ancestor)? else {
+            return err!(MetadataErrors::InvalidGroupAncestry);
+        };
+        update(&mut group)?;
+        group.try_serialize(&mut &mut ancestor.try_borrow_mut_data()?[..])?;
+    }
+    Ok(())
+}
+
+/// Number of members a mint counts for in the size of a group: itself, plus the members of its own
+/// group when the mint is a wns group mint nested as a sub group
+pub fn get_member_size(mint: &AccountInfo, sub_group: Option<&AccountInfo>) -> Result<u32> {
+    let group_address = get_extension_data::<GroupPointer>(&mut mint.clone())
+        .ok()
+        .and_then(|pointer| Option::<Pubkey>::from(pointer.group_address));
+    match (group_address, sub_group) {
+        (None, None) => Ok(1),
+        (Some(group_address), Some(sub_group)) if group_address == sub_group.key() => {
+            let Some(sub_group) = try_get_group(sub_group)? else {
+                return err!(MetadataErrors::InvalidSubGroup);
+            };
+            Ok(sub_group
+                .size
+                .checked_add(1)
+                .ok_or(MetadataErrors::ArithmeticOverflow)?)
+        }
+        _ => err!(MetadataErrors::InvalidSubGroup),
+    }
+}
+
+/// Number of `group_path` accounts leading from the group of a mint up to the group of `group_mint`,
+/// `None` if the mint does not belong to it. The path lists the group account, the royalty config of
+/// the group mint and the member account of the group mint for every group in between, nearest first
+pub fn get_group_path_len(
+    mint: &AccountInfo,
+    member: &AccountInfo,
+    group_mint: &Pubkey,
+    group_path: &[AccountInfo],
+) -> Result<Option<usize>> {
+    if is_group_member(mint, member, group_mint)? {
+        return Ok(Some(0));
+    }
+    let Some(member) = try_get_member(member)? else {
+        return Ok(None);
+    };
+    if member.mint != mint.key() {
+        return Ok(None);
+    }
+
+    let group = get_group_account_pda(*group_mint);
+    let mut parent = member.group;
+    for (depth, accounts) in group_path.chunks_exact(3).enumerate() {
+        let [group_account, royalty_config, group_member] = accounts else {
+            return Ok(None);
+        };
+        require_keys_eq!(
+            group_account.key(),
+            parent,
+            MetadataErrors::InvalidGroupAncestry
+        );
+        let Some(group_account) = try_get_group(group_account)? else {
+            return Ok(None);
+        };
+        let group_account_mint = group_account.mint;
+        require_keys_eq!(
+            royalty_config.key(),
+            Pubkey::find_program_address(
+                &[ROYALTY_CONFIG_SEED, group_account_mint.as_ref()],
+                &crate::id()
+            )
+            .0,
+            MetadataErrors::InvalidGroupAncestry
+        );
+        require_keys_eq!(
+            group_member.key(),
+            Pubkey::find_program_address(
+                &[MEMBER_ACCOUNT_SEED, group_account_mint.as_ref()],
+                &crate::id()
+            )
+            .0,
+            MetadataErrors::InvalidGroupAncestry
+        );
+        let Some(group_member) = try_get_member(group_member)? else {
+            return Ok(None);
+        };
+
+        parent = group_member.group;
+        if parent == group {
+            return Ok(Some((depth + 1) * 3));
+        }
+    }
+
+    Ok(None)
+}
+
 pub fn get_extra_meta_list_account_pda(mint: Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[META_LIST_ACCOUNT_SEED, mint.as_ref()], &crate::id()).0
 }
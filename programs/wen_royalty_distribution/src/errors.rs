@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 pub enum DistributionErrors {
     #[msg("Invalid Group Authority for collection account")]
     InvalidGroupAuthority,
-    #[msg("Invalid creator pct amount. Must add up to 100")]
+    #[msg("Invalid creator share amount. Must add up to 10000 basis points")]
     InvalidCreatorPctAmount,
     #[msg("Invalid payment token account")]
     InvalidPaymentTokenAccount,
@@ -12,4 +12,28 @@ pub enum DistributionErrors {
     InvalidPaymentTokenProgram,
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
+    #[msg("Invalid dust recipient")]
+    InvalidDustRecipient,
+    #[msg("Crank tip is above the maximum allowed")]
+    InvalidCrankTip,
+    #[msg("Distribution account still holds unclaimed royalties")]
+    DistributionNotEmpty,
+    #[msg("Mint is not a member of the distribution group")]
+    InvalidGroupMember,
+    #[msg("Distribution account has to be migrated to the current version first")]
+    OutdatedDistributionVersion,
+    #[msg("Invalid distribution account version")]
+    InvalidDistributionVersion,
+    #[msg("Invalid split")]
+    InvalidSplit,
+    #[msg("Split path is deeper than the maximum allowed")]
+    SplitDepthExceeded,
+    #[msg("Split path contains a cycle")]
+    SplitCycle,
+    #[msg("Creator is a split, royalties have to be forwarded to it")]
+    CreatorIsSplit,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Payment mints with a transfer hook can not be forwarded into splits")]
+    HookedPaymentMint,
 }
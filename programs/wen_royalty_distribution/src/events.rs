@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimSummary {
+    /// distribution account claimed from
+    pub distribution: Pubkey,
+    /// payment mint of the distribution account
+    pub payment_mint: Pubkey,
+    /// amount claimed
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClaimManyEvent {
+    /// creator that claimed
+    pub creator: Pubkey,
+    /// claimed amount per distribution account, zero balances are skipped
+    pub claims: Vec<ClaimSummary>,
+}
@@ -1,16 +1,9 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::{program::invoke, program_pack::Pack, system_instruction::transfer},
-};
-use anchor_spl::{
-    token::{spl_token::state::Mint as TokenMint, ID as token_keg_program_id},
-    token_2022::spl_token_2022::{extension::StateWithExtensions, state::Mint as Token2022Mint},
-    token_interface::{transfer_checked, TokenAccount, TokenInterface, TransferChecked},
-};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 
 use crate::{
-    get_and_clear_creator_royalty_amount, get_bump_in_seed_form, Creator, DistributionAccount,
-    DistributionErrors, CLAIM_DATA_OFFSET,
+    get_and_clear_creator_royalty_amount, get_bump_in_seed_form, realloc_distribution_account,
+    transfer_distribution_tokens, DistributionAccount, DistributionErrors,
 };
 
 #[derive(Accounts)]
@@ -45,17 +38,13 @@ pub struct ClaimDistribution<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl ClaimDistribution<'_> {
-    pub fn transfer_tokens(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
-        let mint_data = self.payment_mint.try_borrow_data()?;
-        let mint_decimals = if self.token_program.key.eq(&token_keg_program_id) {
-            TokenMint::unpack(&mint_data)?.decimals
-        } else {
-            StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?
-                .base
-                .decimals
-        };
-
+impl<'info> ClaimDistribution<'info> {
+    pub fn transfer_tokens(
+        &self,
+        amount: u64,
+        additional_accounts: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
         let creator_token_account = self
             .creator_token_account
             .clone()
@@ -66,38 +55,36 @@ impl ClaimDistribution<'_> {
             .clone()
             .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
 
-        let cpi_accounts = TransferChecked {
-            mint: self.payment_mint.to_account_info(),
-            from: distribution_token_account.to_account_info(),
-            to: creator_token_account.to_account_info(),
-            authority: self.distribution.to_account_info(),
-        };
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        transfer_checked(cpi_ctx, amount, mint_decimals)?;
-        Ok(())
+        transfer_distribution_tokens(
+            self.token_program.to_account_info(),
+            self.payment_mint.to_account_info(),
+            self.distribution.to_account_info(),
+            distribution_token_account.to_account_info(),
+            creator_token_account.to_account_info(),
+            additional_accounts,
+            amount,
+            signer_seeds,
+        )
     }
+}
 
-    pub fn transfer_sol(&self, amount: u64) -> Result<()> {
-        invoke(
-            &transfer(self.creator.key, &self.distribution.key(), amount),
-            &[
-                self.creator.to_account_info(),
-                self.distribution.to_account_info(),
-                self.system_program.to_account_info(),
-            ],
-        )?;
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimDistribution<'info>>) -> Result<()> {
+    ctx.accounts.distribution.check_version()?;
 
-        Ok(())
-    }
-}
+    // only vested royalties can be claimed
+    let clock = Clock::get()?;
+    ctx.accounts
+        .distribution
+        .release_vested(clock.unix_timestamp)?;
 
-pub fn handler(ctx: Context<ClaimDistribution>) -> Result<()> {
     let mut claim_data = ctx.accounts.distribution.claim_data.clone();
     let payment_mint = &ctx.accounts.payment_mint;
 
-    let claim_amount =
-        get_and_clear_creator_royalty_amount(ctx.accounts.creator.key(), &mut claim_data);
+    let claim_amount = get_and_clear_creator_royalty_amount(
+        ctx.accounts.creator.key(),
+        &mut claim_data,
+        clock.slot,
+    )?;
 
     if claim_amount == 0 {
         return Ok(()); // No royalties to claim
@@ -110,34 +97,24 @@ pub fn handler(ctx: Context<ClaimDistribution>) -> Result<()> {
         payment_mint.as_ref(),
         &get_bump_in_seed_form(&ctx.bumps.distribution),
     ];
-    let rent = Rent::get()?;
     // Transfer the claim amount
     if payment_mint == Pubkey::default() {
         ctx.accounts.distribution.sub_lamports(claim_amount)?;
         ctx.accounts.creator.add_lamports(claim_amount)?;
     } else {
+        // remaining accounts are the extra accounts of the payment mint transfer hook, if any
         ctx.accounts
-            .transfer_tokens(claim_amount, &[&signer_seeds])?;
+            .transfer_tokens(claim_amount, ctx.remaining_accounts, &[&signer_seeds])?;
     }
 
     // Update the distribution account data
-    let new_creator_size =
-        std::cmp::max(claim_data.len() * Creator::INIT_SPACE, Creator::INIT_SPACE);
-    let realloc_size = CLAIM_DATA_OFFSET + new_creator_size;
-
-    // Transfer min rent in or out of distribution account
-    let min_rent = rent.minimum_balance(realloc_size);
-    let current_lamports = ctx.accounts.distribution.to_account_info().lamports();
-    if min_rent > current_lamports {
-        let updated_amount = min_rent - current_lamports;
-        ctx.accounts.transfer_sol(updated_amount)?;
-    }
-
+    realloc_distribution_account(
+        &ctx.accounts.distribution.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        claim_data.len(),
+    )?;
     ctx.accounts.distribution.claim_data = claim_data;
 
-    ctx.accounts
-        .distribution
-        .to_account_info()
-        .realloc(realloc_size, false)?;
     Ok(())
 }
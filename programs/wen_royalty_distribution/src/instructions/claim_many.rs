@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{Token2022, TokenAccount},
+};
+
+use crate::{
+    get_and_clear_creator_royalty_amount, get_bump_in_seed_form, realloc_distribution_account,
+    transfer_distribution_tokens, ClaimManyEvent, ClaimSummary, DistributionAccount,
+    DistributionErrors,
+};
+
+/// number of remaining accounts per claimed distribution account:
+/// (distribution, payment_mint, distribution_token_account, creator_token_account)
+/// the payment mint is needed for checked transfers, token accounts are ignored for SOL
+pub const CLAIM_MANY_ACCOUNTS_LEN: usize = 4;
+
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimMany<'info> {
+    fn get_token_program(&self, payment_mint: &AccountInfo<'info>) -> Result<AccountInfo<'info>> {
+        if payment_mint.owner == self.token_program.key {
+            Ok(self.token_program.to_account_info())
+        } else if payment_mint.owner == self.token_2022_program.key {
+            Ok(self.token_2022_program.to_account_info())
+        } else {
+            err!(DistributionErrors::InvalidPaymentTokenProgram)
+        }
+    }
+
+    /// claims the creator balance of a single distribution account, returns the claimed amount
+    pub fn claim(&self, accounts: &'info [AccountInfo<'info>]) -> Result<u64> {
+        let [distribution_info, payment_mint, distribution_token_account, creator_token_account] =
+            accounts
+        else {
+            return err!(ErrorCode::AccountNotEnoughKeys);
+        };
+
+        let mut distribution = Account::<DistributionAccount>::try_from(distribution_info)?;
+        distribution.check_version()?;
+        require_keys_eq!(
+            distribution.payment_mint,
+            payment_mint.key(),
+            ErrorCode::ConstraintHasOne
+        );
+        let (distribution_address, bump) = Pubkey::find_program_address(
+            &[
+                distribution.group_mint.as_ref(),
+                distribution.payment_mint.as_ref(),
+            ],
+            &crate::id(),
+        );
+        require_keys_eq!(
+            distribution_address,
+            distribution_info.key(),
+            ErrorCode::ConstraintSeeds
+        );
+
+        // only vested royalties can be claimed
+        let clock = Clock::get()?;
+        distribution.release_vested(clock.unix_timestamp)?;
+
+        let mut claim_data = distribution.claim_data.clone();
+        let claim_amount =
+            get_and_clear_creator_royalty_amount(self.creator.key(), &mut claim_data, clock.slot)?;
+
+        if claim_amount == 0 {
+            return Ok(0); // No royalties to claim
+        }
+
+        let signer_seeds = [
+            distribution.group_mint.as_ref(),
+            distribution.payment_mint.as_ref(),
+            &get_bump_in_seed_form(&bump),
+        ];
+        // Transfer the claim amount
+        if payment_mint.key() == Pubkey::default() {
+            distribution.sub_lamports(claim_amount)?;
+            self.creator.add_lamports(claim_amount)?;
+        } else {
+            let token_program = self.get_token_program(payment_mint)?;
+
+            let distribution_token =
+                InterfaceAccount::<TokenAccount>::try_from(distribution_token_account)?;
+            require!(
+                distribution_token.owner == distribution_info.key()
+                    && distribution_token.mint == payment_mint.key(),
+                DistributionErrors::InvalidPaymentTokenAccount
+            );
+            let creator_token = InterfaceAccount::<TokenAccount>::try_from(creator_token_account)?;
+            require!(
+                creator_token.owner == self.creator.key()
+                    && creator_token.mint == payment_mint.key(),
+                DistributionErrors::InvalidPaymentTokenAccount
+            );
+
+            transfer_distribution_tokens(
+                token_program,
+                payment_mint.clone(),
+                distribution_info.clone(),
+                distribution_token_account.clone(),
+                creator_token_account.clone(),
+                // remaining accounts are taken by the claim groups, hooked payment mints go through claim
+                &[],
+                claim_amount,
+                &[&signer_seeds],
+            )?;
+        }
+
+        // Update the distribution account data
+        realloc_distribution_account(
+            distribution_info,
+            &self.creator.to_account_info(),
+            &self.system_program.to_account_info(),
+            claim_data.len(),
+        )?;
+        distribution.claim_data = claim_data;
+        distribution.exit(&crate::id())?;
+
+        Ok(claim_amount)
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>) -> Result<()> {
+    let claim_accounts = ctx.remaining_accounts.chunks_exact(CLAIM_MANY_ACCOUNTS_LEN);
+    require!(
+        claim_accounts.remainder().is_empty(),
+        ErrorCode::AccountNotEnoughKeys
+    );
+
+    let mut claims = vec![];
+    for accounts in claim_accounts {
+        let amount = ctx.accounts.claim(accounts)?;
+        if amount > 0 {
+            claims.push(ClaimSummary {
+                distribution: accounts[0].key(),
+                payment_mint: accounts[1].key(),
+                amount,
+            });
+        }
+    }
+
+    emit!(ClaimManyEvent {
+        creator: ctx.accounts.creator.key(),
+        claims,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount, TokenInterface};
+
+use crate::{
+    get_bump_in_seed_form, get_group_update_authority, DistributionAccount, DistributionErrors,
+    GROUP_ACCOUNT_SEED, WEN_NEW_STANDARD_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+pub struct CloseDistribution<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: group mint of the distribution account
+    #[account(
+        address = distribution.group_mint,
+    )]
+    pub group_mint: UncheckedAccount<'info>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, group_mint.key().as_ref()],
+        seeds::program = WEN_NEW_STANDARD_PROGRAM_ID,
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = destination,
+        has_one = payment_mint,
+        seeds = [distribution.group_mint.as_ref(), payment_mint.key().as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, DistributionAccount>,
+    /// CHECK: can be Pubkey::default() or mint address
+    #[account()]
+    pub payment_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::authority = distribution,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: receives the rent of the closed accounts
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl CloseDistribution<'_> {
+    pub fn close_token_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let distribution_token_account = self
+            .distribution_token_account
+            .clone()
+            .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+
+        let cpi_accounts = CloseAccount {
+            account: distribution_token_account.to_account_info(),
+            destination: self.destination.to_account_info(),
+            authority: self.distribution.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        close_account(cpi_ctx)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CloseDistribution>) -> Result<()> {
+    ctx.accounts.distribution.check_version()?;
+
+    // only the group update authority can close its distribution accounts
+    let update_authority = get_group_update_authority(
+        &ctx.accounts.group_mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+    )?;
+    require!(
+        update_authority == Some(ctx.accounts.authority.key()),
+        DistributionErrors::InvalidGroupAuthority
+    );
+
+    // nothing owed to creators or dust recipient can be left behind
+    let distribution = &ctx.accounts.distribution;
+    require!(
+        distribution.dust_amount == 0
+            && distribution
+                .claim_data
+                .iter()
+                .all(|creator| creator.claim_amount == 0 && creator.unvested_amount == 0),
+        DistributionErrors::DistributionNotEmpty
+    );
+
+    let payment_mint = ctx.accounts.payment_mint.key();
+    if payment_mint != Pubkey::default() {
+        let distribution_token_account = ctx
+            .accounts
+            .distribution_token_account
+            .as_ref()
+            .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+        require!(
+            distribution_token_account.amount == 0,
+            DistributionErrors::DistributionNotEmpty
+        );
+
+        let signer_seeds = [
+            distribution.group_mint.as_ref(),
+            payment_mint.as_ref(),
+            &get_bump_in_seed_form(&ctx.bumps.distribution),
+        ];
+        ctx.accounts.close_token_account(&[&signer_seeds])?;
+    }
+
+    // distribution account is closed to the destination by anchor
+    Ok(())
+}
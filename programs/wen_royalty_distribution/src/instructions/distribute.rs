@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    get_and_clear_creator_royalty_amount, get_bump_in_seed_form, realloc_distribution_account,
+    transfer_distribution_tokens, DistributionAccount, DistributionErrors,
+};
+
+#[derive(Accounts)]
+pub struct DistributeToCreator<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    /// CHECK: creator receiving the payout, does not need to sign
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = payment_mint,
+        seeds = [distribution.group_mint.as_ref(), payment_mint.key().as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, DistributionAccount>,
+    /// CHECK: can be Pubkey::default() or mint address
+    #[account()]
+    pub payment_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::authority = distribution,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::authority = creator,
+        associated_token::mint = payment_mint,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        token::authority = cranker,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub cranker_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DistributeToCreator<'info> {
+    pub fn transfer_tokens(
+        &self,
+        to: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        amount: u64,
+        additional_accounts: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let to = to
+            .clone()
+            .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+
+        let distribution_token_account = self
+            .distribution_token_account
+            .clone()
+            .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+
+        transfer_distribution_tokens(
+            self.token_program.to_account_info(),
+            self.payment_mint.to_account_info(),
+            self.distribution.to_account_info(),
+            distribution_token_account.to_account_info(),
+            to.to_account_info(),
+            additional_accounts,
+            amount,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, DistributeToCreator<'info>>) -> Result<()> {
+    ctx.accounts.distribution.check_version()?;
+    // royalties of splits are forwarded into the split claim data with forward_to_split
+    require!(
+        ctx.accounts.creator.owner != &crate::id(),
+        DistributionErrors::CreatorIsSplit
+    );
+
+    // only vested royalties can be distributed
+    let clock = Clock::get()?;
+    ctx.accounts
+        .distribution
+        .release_vested(clock.unix_timestamp)?;
+
+    let mut claim_data = ctx.accounts.distribution.claim_data.clone();
+
+    let claim_amount = get_and_clear_creator_royalty_amount(
+        ctx.accounts.creator.key(),
+        &mut claim_data,
+        clock.slot,
+    )?;
+
+    if claim_amount == 0 {
+        return Ok(()); // No royalties to distribute
+    }
+
+    // cranker tip set by the group update authority comes out of the payout, creator gets the rest
+    let crank_tip = ((claim_amount as u128)
+        .checked_mul(ctx.accounts.distribution.crank_tip_basis_points as u128)
+        .ok_or(DistributionErrors::ArithmeticOverflow)?
+        / 10000) as u64;
+    let creator_amount = claim_amount
+        .checked_sub(crank_tip)
+        .ok_or(DistributionErrors::ArithmeticOverflow)?;
+
+    let payment_mint = ctx.accounts.payment_mint.key();
+
+    let signer_seeds = [
+        ctx.accounts.distribution.group_mint.as_ref(),
+        payment_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.distribution),
+    ];
+    // Transfer the payout to the creator system account or canonical token account
+    if payment_mint == Pubkey::default() {
+        ctx.accounts.distribution.sub_lamports(claim_amount)?;
+        ctx.accounts.creator.add_lamports(creator_amount)?;
+        ctx.accounts.cranker.add_lamports(crank_tip)?;
+    } else {
+        // remaining accounts are the extra accounts of the payment mint transfer hook, if any
+        ctx.accounts.transfer_tokens(
+            &ctx.accounts.creator_token_account,
+            creator_amount,
+            ctx.remaining_accounts,
+            &[&signer_seeds],
+        )?;
+        if crank_tip > 0 {
+            ctx.accounts.transfer_tokens(
+                &ctx.accounts.cranker_token_account,
+                crank_tip,
+                ctx.remaining_accounts,
+                &[&signer_seeds],
+            )?;
+        }
+    }
+
+    // Update the distribution account data
+    realloc_distribution_account(
+        &ctx.accounts.distribution.to_account_info(),
+        &ctx.accounts.cranker.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        claim_data.len(),
+    )?;
+    ctx.accounts.distribution.claim_data = claim_data;
+
+    Ok(())
+}
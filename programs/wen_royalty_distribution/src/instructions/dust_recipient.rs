@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    get_group_update_authority, DistributionAccount, DistributionErrors, GROUP_ACCOUNT_SEED,
+    WEN_NEW_STANDARD_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+#[instruction(dust_recipient: Pubkey)]
+pub struct UpdateDustRecipient<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: group mint of the distribution account
+    #[account(
+        address = distribution.group_mint,
+    )]
+    pub group_mint: UncheckedAccount<'info>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, group_mint.key().as_ref()],
+        seeds::program = WEN_NEW_STANDARD_PROGRAM_ID,
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [distribution.group_mint.as_ref(), distribution.payment_mint.as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, DistributionAccount>,
+}
+
+pub fn handler(ctx: Context<UpdateDustRecipient>, dust_recipient: Pubkey) -> Result<()> {
+    ctx.accounts.distribution.check_version()?;
+
+    // only the group update authority can redirect the dust of its distribution accounts
+    let update_authority = get_group_update_authority(
+        &ctx.accounts.group_mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+    )?;
+    require!(
+        update_authority == Some(ctx.accounts.authority.key()),
+        DistributionErrors::InvalidGroupAuthority
+    );
+
+    ctx.accounts.distribution.dust_recipient = dust_recipient;
+
+    Ok(())
+}
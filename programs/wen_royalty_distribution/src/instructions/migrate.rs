@@ -0,0 +1,60 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    realloc_distribution_account, DistributionAccount, DistributionAccountV1, DistributionErrors,
+};
+
+#[derive(Accounts)]
+pub struct MigrateDistribution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: version 1 distribution account, checked in the handler
+    #[account(
+        mut,
+        owner = crate::id(),
+    )]
+    pub distribution: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateDistribution>) -> Result<()> {
+    let distribution_info = ctx.accounts.distribution.to_account_info();
+
+    // dispatch on the version byte, right after the account discriminator
+    let distribution = {
+        let data = distribution_info.try_borrow_data()?;
+        if data.len() <= 8 || data[..8] != DistributionAccount::DISCRIMINATOR {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+        match data[8] {
+            DistributionAccount::VERSION_1 => {
+                DistributionAccount::from_v1(DistributionAccountV1::deserialize(&mut &data[8..])?)?
+            }
+            _ => return err!(DistributionErrors::InvalidDistributionVersion),
+        }
+    };
+
+    let (distribution_address, _) = Pubkey::find_program_address(
+        &[
+            distribution.group_mint.as_ref(),
+            distribution.payment_mint.as_ref(),
+        ],
+        &crate::id(),
+    );
+    require_keys_eq!(
+        distribution_address,
+        distribution_info.key(),
+        ErrorCode::ConstraintSeeds
+    );
+
+    // payer tops up the rent of the larger layout
+    realloc_distribution_account(
+        &distribution_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        distribution.claim_data.len(),
+    )?;
+    distribution.try_serialize(&mut &mut distribution_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -1,9 +1,27 @@
 #![allow(ambiguous_glob_reexports)]
 
 pub mod claim;
+pub mod claim_many;
+pub mod close;
+pub mod crank_tip;
+pub mod distribute;
+pub mod dust_recipient;
 pub mod initialize;
+pub mod migrate;
+pub mod split;
+pub mod sweep;
 pub mod update;
+pub mod vesting;
 
 pub use claim::*;
+pub use claim_many::*;
+pub use close::*;
+pub use crank_tip::*;
+pub use distribute::*;
+pub use dust_recipient::*;
 pub use initialize::*;
+pub use migrate::*;
+pub use split::*;
+pub use sweep::*;
 pub use update::*;
+pub use vesting::*;
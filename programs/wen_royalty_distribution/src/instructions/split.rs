@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    get_and_clear_creator_royalty_amount, get_bump_in_seed_form, has_transfer_hook,
+    realloc_distribution_account, transfer_distribution_tokens, CreatorShare, DistributionAccount,
+    DistributionErrors, RoyaltyConfig, MAX_SPLIT_DEPTH, ROYALTY_CONFIG_SEED,
+    WEN_NEW_STANDARD_PROGRAM_ID,
+};
+
+/// number of remaining accounts per split in the path:
+/// (distribution, group_royalty_config, distribution_token_account)
+/// the group royalty config holds the creator shares of the split, the token account is ignored for SOL
+pub const SPLIT_ACCOUNTS_LEN: usize = 3;
+
+#[derive(Accounts)]
+pub struct ForwardToSplit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: can be Pubkey::default() or mint address
+    #[account()]
+    pub payment_mint: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// distribution account of a split in the forwarded path
+pub struct Split<'info> {
+    pub distribution: Account<'info, DistributionAccount>,
+    pub bump: u8,
+    pub group_royalty_config: &'info AccountInfo<'info>,
+    pub distribution_token_account: &'info AccountInfo<'info>,
+}
+
+impl<'info> Split<'info> {
+    /// creator shares of the split, from the royalty config of its group
+    pub fn get_creators(&self) -> Result<Vec<CreatorShare>> {
+        let (group_royalty_config, _) = Pubkey::find_program_address(
+            &[ROYALTY_CONFIG_SEED, self.distribution.group_mint.as_ref()],
+            &WEN_NEW_STANDARD_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            group_royalty_config,
+            self.group_royalty_config.key(),
+            DistributionErrors::InvalidSplit
+        );
+
+        let royalty_config = RoyaltyConfig::try_from_account_info(self.group_royalty_config)?
+            .ok_or(DistributionErrors::InvalidSplit)?;
+        Ok(royalty_config
+            .creators
+            .iter()
+            .map(|creator| CreatorShare {
+                address: creator.address,
+                share: creator.share,
+            })
+            .collect())
+    }
+}
+
+impl<'info> ForwardToSplit<'info> {
+    pub fn load_split(&self, accounts: &'info [AccountInfo<'info>]) -> Result<Split<'info>> {
+        let [distribution_info, group_royalty_config, distribution_token_account] = accounts else {
+            return err!(ErrorCode::AccountNotEnoughKeys);
+        };
+
+        let distribution = Account::<DistributionAccount>::try_from(distribution_info)?;
+        distribution.check_version()?;
+        require_keys_eq!(
+            distribution.payment_mint,
+            self.payment_mint.key(),
+            ErrorCode::ConstraintHasOne
+        );
+        let (distribution_address, bump) = Pubkey::find_program_address(
+            &[
+                distribution.group_mint.as_ref(),
+                distribution.payment_mint.as_ref(),
+            ],
+            &crate::id(),
+        );
+        require_keys_eq!(
+            distribution_address,
+            distribution_info.key(),
+            ErrorCode::ConstraintSeeds
+        );
+
+        Ok(Split {
+            distribution,
+            bump,
+            group_royalty_config,
+            distribution_token_account,
+        })
+    }
+
+    /// moves the balance of `child` in the claim data of `parent` into `child`, returns the amount
+    /// received by `child`, which is less for payment mints with a transfer fee
+    pub fn forward(
+        &self,
+        parent: &mut Split<'info>,
+        child: &mut Split<'info>,
+        clock: &Clock,
+    ) -> Result<u64> {
+        // only vested royalties are forwarded, they vest again under the schedule of the split
+        parent.distribution.release_vested(clock.unix_timestamp)?;
+        let amount = get_and_clear_creator_royalty_amount(
+            child.distribution.key(),
+            &mut parent.distribution.claim_data,
+            clock.slot,
+        )?;
+
+        if amount == 0 {
+            return Ok(0); // Nothing to forward
+        }
+
+        let payment_mint = self.payment_mint.key();
+        if payment_mint == Pubkey::default() {
+            parent.distribution.sub_lamports(amount)?;
+            child.distribution.add_lamports(amount)?;
+            return Ok(amount);
+        }
+
+        let parent_token =
+            InterfaceAccount::<TokenAccount>::try_from(parent.distribution_token_account)?;
+        require!(
+            parent_token.owner == parent.distribution.key() && parent_token.mint == payment_mint,
+            DistributionErrors::InvalidPaymentTokenAccount
+        );
+        let mut child_token =
+            InterfaceAccount::<TokenAccount>::try_from(child.distribution_token_account)?;
+        require!(
+            child_token.owner == child.distribution.key() && child_token.mint == payment_mint,
+            DistributionErrors::InvalidPaymentTokenAccount
+        );
+        let balance_before = child_token.amount;
+
+        let signer_seeds = [
+            parent.distribution.group_mint.as_ref(),
+            payment_mint.as_ref(),
+            &get_bump_in_seed_form(&parent.bump),
+        ];
+        transfer_distribution_tokens(
+            self.token_program.to_account_info(),
+            self.payment_mint.to_account_info(),
+            parent.distribution.to_account_info(),
+            parent.distribution_token_account.clone(),
+            child.distribution_token_account.clone(),
+            // remaining accounts are taken by the split path, hooked payment mints are rejected upfront
+            &[],
+            amount,
+            &[&signer_seeds],
+        )?;
+
+        child_token.reload()?;
+        let received_amount = child_token
+            .amount
+            .checked_sub(balance_before)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+        Ok(received_amount)
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ForwardToSplit<'info>>) -> Result<()> {
+    // remaining accounts are taken by the split path, there is no room for the extra accounts of a hook
+    if ctx.accounts.payment_mint.key() != Pubkey::default() {
+        require!(
+            !has_transfer_hook(&ctx.accounts.payment_mint.to_account_info())?,
+            DistributionErrors::HookedPaymentMint
+        );
+    }
+
+    let split_accounts = ctx.remaining_accounts.chunks_exact(SPLIT_ACCOUNTS_LEN);
+    require!(
+        split_accounts.remainder().is_empty(),
+        ErrorCode::AccountNotEnoughKeys
+    );
+    // the path starts at the distribution holding the split entry, every following split is a creator of
+    // the previous one
+    require!(split_accounts.len() >= 2, DistributionErrors::InvalidSplit);
+    require!(
+        split_accounts.len() <= MAX_SPLIT_DEPTH + 1,
+        DistributionErrors::SplitDepthExceeded
+    );
+
+    let mut splits = split_accounts
+        .map(|accounts| ctx.accounts.load_split(accounts))
+        .collect::<Result<Vec<Split>>>()?;
+    let path = splits
+        .iter()
+        .map(|split| split.distribution.key())
+        .collect::<Vec<Pubkey>>();
+
+    // every split appears once in the path
+    for (index, distribution) in path.iter().enumerate() {
+        require!(
+            !path[..index].contains(distribution),
+            DistributionErrors::SplitCycle
+        );
+    }
+
+    let clock = Clock::get()?;
+    for index in 1..splits.len() {
+        let (parents, children) = splits.split_at_mut(index);
+        let parent = &mut parents[index - 1];
+        let child = &mut children[0];
+
+        // a split can not route funds back into itself or any split it is forwarded from, the share of
+        // those creators is tracked as dust
+        let creators = child
+            .get_creators()?
+            .into_iter()
+            .filter(|creator| !path[..=index].contains(&creator.address))
+            .collect::<Vec<CreatorShare>>();
+
+        let amount = ctx.accounts.forward(parent, child, &clock)?;
+        if amount > 0 {
+            child
+                .distribution
+                .credit_creators(&creators, amount, clock.unix_timestamp)?;
+        }
+    }
+
+    // Update the distribution accounts data
+    for split in splits.iter_mut() {
+        // payer tops up the rent of the larger claim data
+        realloc_distribution_account(
+            &split.distribution.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            split.distribution.claim_data.len(),
+        )?;
+        split.distribution.exit(&crate::id())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    get_bump_in_seed_form, transfer_distribution_tokens, DistributionAccount, DistributionErrors,
+};
+
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    #[account(
+        mut,
+        has_one = payment_mint,
+        seeds = [distribution.group_mint.as_ref(), payment_mint.key().as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, DistributionAccount>,
+    /// CHECK: can be Pubkey::default() or mint address
+    #[account()]
+    pub payment_mint: UncheckedAccount<'info>,
+    /// CHECK: checked against the dust recipient of the distribution account
+    #[account(
+        mut,
+        constraint = distribution.get_dust_recipient() == Some(dust_recipient.key()) @ DistributionErrors::InvalidDustRecipient,
+    )]
+    pub dust_recipient: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::authority = distribution,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub distribution_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        token::authority = dust_recipient,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub dust_recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SweepDust<'info> {
+    pub fn transfer_tokens(
+        &self,
+        amount: u64,
+        additional_accounts: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let dust_recipient_token_account = self
+            .dust_recipient_token_account
+            .clone()
+            .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+
+        let distribution_token_account = self
+            .distribution_token_account
+            .clone()
+            .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+
+        transfer_distribution_tokens(
+            self.token_program.to_account_info(),
+            self.payment_mint.to_account_info(),
+            self.distribution.to_account_info(),
+            distribution_token_account.to_account_info(),
+            dust_recipient_token_account.to_account_info(),
+            additional_accounts,
+            amount,
+            signer_seeds,
+        )
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SweepDust<'info>>) -> Result<()> {
+    ctx.accounts.distribution.check_version()?;
+
+    let dust_amount = ctx.accounts.distribution.dust_amount;
+
+    if dust_amount == 0 {
+        return Ok(()); // No dust to sweep
+    }
+
+    let payment_mint = ctx.accounts.payment_mint.key();
+
+    let signer_seeds = [
+        ctx.accounts.distribution.group_mint.as_ref(),
+        payment_mint.as_ref(),
+        &get_bump_in_seed_form(&ctx.bumps.distribution),
+    ];
+    // Transfer the dust amount, rent of the distribution account is not part of the dust
+    if payment_mint == Pubkey::default() {
+        ctx.accounts.distribution.sub_lamports(dust_amount)?;
+        ctx.accounts.dust_recipient.add_lamports(dust_amount)?;
+    } else {
+        // remaining accounts are the extra accounts of the payment mint transfer hook, if any
+        ctx.accounts
+            .transfer_tokens(dust_amount, ctx.remaining_accounts, &[&signer_seeds])?;
+    }
+
+    ctx.accounts.distribution.dust_amount = 0;
+
+    Ok(())
+}
@@ -1,37 +1,29 @@
-use std::str::FromStr;
-
 use anchor_lang::{
     prelude::*,
-    solana_program::{program::invoke, program_pack::Pack, system_instruction::transfer},
+    solana_program::{program::invoke, system_instruction::transfer},
 };
 
-use anchor_spl::{
-    token::{spl_token::state::Mint as TokenMint, ID as token_keg_program_id},
-    token_interface::{
-        spl_token_2022::{
-            extension::{BaseStateWithExtensions, StateWithExtensions},
-            state::Mint as Token2022Mint,
-        },
-        spl_token_metadata_interface::state::TokenMetadata,
-        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint as Token2022Mint,
     },
+    spl_token_metadata_interface::state::TokenMetadata,
+    Mint, TokenAccount, TokenInterface,
 };
 
 use crate::{
-    Creator, DistributionAccount, DistributionErrors, CLAIM_DATA_OFFSET, ROYALTY_BASIS_POINTS_FIELD,
+    is_group_descendant, realloc_distribution_account, transfer_payment_tokens, CreatorShare,
+    DistributionAccount, DistributionErrors, RoyaltyConfig, MEMBER_ACCOUNT_SEED,
+    ROYALTY_BASIS_POINTS_FIELD, ROYALTY_CONFIG_SEED, WEN_NEW_STANDARD_PROGRAM_ID,
 };
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct CreatorShare {
-    /// creator address
-    pub address: Pubkey,
-    /// creator share percentage
-    pub pct: u8,
-}
-
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateDistributionArgs {
     pub amount: u64,
+    /// number of remaining accounts holding the group path of the mint, the rest are the extra
+    /// accounts of the payment mint transfer hook
+    pub group_path_len: u8,
 }
 
 #[derive(Accounts)]
@@ -43,6 +35,27 @@ pub struct UpdateDistribution<'info> {
         mint::token_program = anchor_spl::token_interface::spl_token_2022::id(),
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: wns royalty config, can be uninitialized for mints whose royalties have not been migrated
+    #[account(
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = WEN_NEW_STANDARD_PROGRAM_ID,
+        bump,
+    )]
+    pub royalty_config: UncheckedAccount<'info>,
+    /// CHECK: wns royalty config of the distribution group, inherited by members without royalties of their own
+    #[account(
+        seeds = [ROYALTY_CONFIG_SEED, distribution_account.group_mint.as_ref()],
+        seeds::program = WEN_NEW_STANDARD_PROGRAM_ID,
+        bump,
+    )]
+    pub group_royalty_config: UncheckedAccount<'info>,
+    /// CHECK: wns member account of the mint, group membership is verified when passed
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        seeds::program = WEN_NEW_STANDARD_PROGRAM_ID,
+        bump,
+    )]
+    pub member: Option<UncheckedAccount<'info>>,
     /// CHECK: can be Pubkey::default() or mint address
     #[account()]
     pub payment_mint: UncheckedAccount<'info>,
@@ -72,22 +85,18 @@ pub struct UpdateDistribution<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl UpdateDistribution<'_> {
-    pub fn transfer_royalty_amount(&self, amount: u64) -> Result<()> {
-        if self.payment_token_program.is_none() {
-            return err!(DistributionErrors::InvalidPaymentTokenProgram);
-        }
-
-        let cpi_program = self.payment_token_program.clone().unwrap();
-
-        let mint_data = self.payment_mint.try_borrow_data()?;
-        let mint_decimals = if self.token_program.key.eq(&token_keg_program_id) {
-            TokenMint::unpack(&mint_data)?.decimals
-        } else {
-            StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?
-                .base
-                .decimals
-        };
+impl<'info> UpdateDistribution<'info> {
+    /// transfer the royalty amount in, returns the amount received by the distribution token account
+    /// which is less than `amount` for payment mints with a transfer fee
+    pub fn transfer_royalty_amount(
+        &mut self,
+        amount: u64,
+        additional_accounts: &[AccountInfo<'info>],
+    ) -> Result<u64> {
+        let payment_token_program = self
+            .payment_token_program
+            .clone()
+            .ok_or(DistributionErrors::InvalidPaymentTokenProgram)?;
 
         let authority_token_account = self
             .authority_token_account
@@ -96,19 +105,27 @@ impl UpdateDistribution<'_> {
 
         let distribution_token_account = self
             .distribution_token_account
-            .clone()
+            .as_mut()
             .ok_or(DistributionErrors::InvalidPaymentTokenAccount)?;
+        let balance_before = distribution_token_account.amount;
+
+        transfer_payment_tokens(
+            payment_token_program.to_account_info(),
+            self.payment_mint.to_account_info(),
+            authority_token_account.to_account_info(),
+            distribution_token_account.to_account_info(),
+            self.authority.to_account_info(),
+            additional_accounts,
+            amount,
+            &[],
+        )?;
 
-        let cpi_accounts = TransferChecked {
-            mint: self.payment_mint.to_account_info(),
-            from: authority_token_account.to_account_info(),
-            to: distribution_token_account.to_account_info(),
-            authority: self.authority.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new(cpi_program.to_account_info(), cpi_accounts);
-        transfer_checked(cpi_ctx, amount, mint_decimals)?;
-        Ok(())
+        distribution_token_account.reload()?;
+        let received_amount = distribution_token_account
+            .amount
+            .checked_sub(balance_before)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+        Ok(received_amount)
     }
 
     pub fn transfer_sol(&self, amount: u64) -> Result<()> {
@@ -125,7 +142,12 @@ impl UpdateDistribution<'_> {
     }
 }
 
-pub fn handler(ctx: Context<UpdateDistribution>, args: UpdateDistributionArgs) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateDistribution<'info>>,
+    args: UpdateDistributionArgs,
+) -> Result<()> {
+    ctx.accounts.distribution_account.check_version()?;
+
     let mint_account = ctx.accounts.mint.to_account_info();
     let mint_account_data = mint_account.try_borrow_data()?;
     let mint_data = StateWithExtensions::<Token2022Mint>::unpack(&mint_account_data)?;
@@ -134,106 +156,76 @@ pub fn handler(ctx: Context<UpdateDistribution>, args: UpdateDistributionArgs) -
         return Ok(());
     }
 
-    // get all creators from metadata Vec(String, String), only royalty_basis_points needs to be removed
-    let creators = metadata
-        .additional_metadata
-        .iter()
-        .filter(|(key, _)| key != ROYALTY_BASIS_POINTS_FIELD)
-        .filter_map(|(key, value)| match Pubkey::from_str(key) {
-            Ok(pubkey) => Some(CreatorShare {
-                address: pubkey,
-                pct: u8::from_str(value).unwrap(),
-            }),
-            Err(_) => None,
-        })
-        .collect::<Vec<CreatorShare>>();
-
-    // update creator amounts in distribution account. add creator if not present, else update amount (amount * pct / 100)
-    let current_data = ctx.accounts.distribution_account.claim_data.clone();
-    let rent = Rent::get()?;
-    let current_rent = rent.minimum_balance(
-        ctx.accounts
-            .distribution_account
-            .to_account_info()
-            .data_len(),
+    let group_path_len = args.group_path_len as usize;
+    require!(
+        group_path_len <= ctx.remaining_accounts.len(),
+        DistributionErrors::InvalidGroupMember
     );
-    let mut new_data = vec![];
-    // Incoming creator updates
-    for creator in creators.iter() {
-        let mut creator_found = false;
-        for current_creator in current_data.iter() {
-            if creator.address == current_creator.address {
-                creator_found = true;
-                new_data.push(Creator {
-                    address: creator.address,
-                    claim_amount: current_creator
-                        .claim_amount
-                        .checked_add(
-                            (args
-                                .amount
-                                .checked_mul(creator.pct as u64)
-                                .ok_or(DistributionErrors::ArithmeticOverflow)?)
-                            .checked_div(100)
-                            .ok_or(DistributionErrors::ArithmeticOverflow)?,
-                        )
-                        .ok_or(DistributionErrors::ArithmeticOverflow)?,
-                });
-                break;
-            }
-        }
-        if !creator_found {
-            new_data.push(Creator {
-                address: creator.address,
-                claim_amount: (args
-                    .amount
-                    .checked_mul(creator.pct as u64)
-                    .ok_or(DistributionErrors::ArithmeticOverflow)?)
-                .checked_div(100)
-                .ok_or(DistributionErrors::ArithmeticOverflow)?,
-            });
-        }
-    }
-
-    // add creators from old data with claim amount > 0 if not present in incoming creator updates
-    for creator in current_data.iter() {
-        if creator.claim_amount > 0 {
-            let mut creator_found = false;
-            for new_creator in new_data.iter() {
-                if creator.address == new_creator.address {
-                    creator_found = true;
-                    break;
-                }
-            }
-            if !creator_found {
-                new_data.push(creator.clone());
-            }
-        }
+    let (group_path, hook_accounts) = ctx.remaining_accounts.split_at(group_path_len);
+
+    // royalties of a mint can only be credited to the distribution of its own group, or of a group
+    // above it for members of sub groups, through the group path
+    if let Some(member) = &ctx.accounts.member {
+        require!(
+            is_group_descendant(
+                &ctx.accounts.mint.to_account_info(),
+                &member.to_account_info(),
+                &ctx.accounts.distribution_account.group_mint,
+                group_path,
+            )?,
+            DistributionErrors::InvalidGroupMember
+        );
     }
 
-    let payment_mint = &ctx.accounts.payment_mint;
-    let payment_mint_pubkey = payment_mint.key();
+    let payment_mint_pubkey = ctx.accounts.payment_mint.key();
 
-    ctx.accounts.distribution_account.claim_data = new_data.clone();
-
-    let new_creator_size = std::cmp::max(new_data.len() * Creator::INIT_SPACE, Creator::INIT_SPACE);
-    let realloc_size = CLAIM_DATA_OFFSET + new_creator_size;
-
-    if payment_mint_pubkey == Pubkey::default() {
+    // split the amount actually received, net of the payment mint transfer fee (if any). remaining
+    // accounts past the group path are the extra accounts of the payment mint transfer hook, if any
+    let amount = if payment_mint_pubkey == Pubkey::default() {
         ctx.accounts.transfer_sol(args.amount)?;
+        args.amount
     } else {
-        ctx.accounts.transfer_royalty_amount(args.amount)?;
-        // transfer min rent in or out of distribution account
-        let min_rent = rent.minimum_balance(realloc_size);
-        if current_rent < min_rent {
-            let new_rent = min_rent - current_rent;
-            ctx.accounts.transfer_sol(new_rent)?;
-        }
-    }
+        ctx.accounts
+            .transfer_royalty_amount(args.amount, hook_accounts)?
+    };
 
-    ctx.accounts
-        .distribution_account
-        .to_account_info()
-        .realloc(realloc_size, false)?;
+    // get all creators from the royalty config, falling back to metadata Vec(String, String) for unmigrated mints
+    // and to the group royalty config for members without royalties of their own
+    let has_metadata_royalties = metadata
+        .additional_metadata
+        .iter()
+        .any(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD);
+    let royalty_config = match RoyaltyConfig::try_from_account_info(&ctx.accounts.royalty_config)? {
+        Some(royalty_config) => Some(royalty_config),
+        None if !has_metadata_royalties => {
+            RoyaltyConfig::try_from_account_info(&ctx.accounts.group_royalty_config)?
+        }
+        None => None,
+    };
+    let creators = match royalty_config {
+        Some(royalty_config) => royalty_config
+            .creators
+            .iter()
+            .map(|creator| CreatorShare {
+                address: creator.address,
+                share: creator.share,
+            })
+            .collect::<Vec<CreatorShare>>(),
+        None => CreatorShare::from_metadata(&metadata.additional_metadata)?,
+    };
+
+    ctx.accounts.distribution_account.credit_creators(
+        &creators,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    realloc_distribution_account(
+        &ctx.accounts.distribution_account.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.distribution_account.claim_data.len(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    get_group_update_authority, DistributionAccount, DistributionErrors, GROUP_ACCOUNT_SEED,
+    WEN_NEW_STANDARD_PROGRAM_ID,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateVestingScheduleArgs {
+    /// unix timestamp vesting starts at
+    pub start: i64,
+    /// seconds after the start before anything vests
+    pub cliff: i64,
+    /// seconds after the start until everything is vested, 0 to stop vesting
+    pub duration: i64,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVestingSchedule<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: group mint of the distribution account
+    #[account(
+        address = distribution.group_mint,
+    )]
+    pub group_mint: UncheckedAccount<'info>,
+    /// CHECK: wns group account, can be uninitialized for groups migrated to the group extension
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, group_mint.key().as_ref()],
+        seeds::program = WEN_NEW_STANDARD_PROGRAM_ID,
+        bump,
+    )]
+    pub group: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [distribution.group_mint.as_ref(), distribution.payment_mint.as_ref()],
+        bump,
+    )]
+    pub distribution: Account<'info, DistributionAccount>,
+}
+
+pub fn handler(ctx: Context<UpdateVestingSchedule>, args: UpdateVestingScheduleArgs) -> Result<()> {
+    ctx.accounts.distribution.check_version()?;
+
+    // only the group update authority can change how royalties of its distribution accounts vest
+    let update_authority = get_group_update_authority(
+        &ctx.accounts.group_mint.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+    )?;
+    require!(
+        update_authority == Some(ctx.accounts.authority.key()),
+        DistributionErrors::InvalidGroupAuthority
+    );
+
+    ctx.accounts.distribution.set_vesting_schedule(
+        args.start,
+        args.cliff,
+        args.duration,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -26,15 +28,75 @@ pub mod wen_royalty_distribution {
     }
 
     /// Update royalty amount for creators a distribution account.
-    pub fn update_distribution(
-        ctx: Context<UpdateDistribution>,
+    pub fn update_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateDistribution<'info>>,
         args: UpdateDistributionArgs,
     ) -> Result<()> {
         instructions::update::handler(ctx, args)
     }
 
     /// Claim royalties from a distribution account.
-    pub fn claim_distribution(ctx: Context<ClaimDistribution>) -> Result<()> {
+    pub fn claim_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimDistribution<'info>>,
+    ) -> Result<()> {
         instructions::claim::handler(ctx)
     }
+
+    /// Claim royalties from many distribution accounts, passed as remaining accounts.
+    pub fn claim_many<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>) -> Result<()> {
+        instructions::claim_many::handler(ctx)
+    }
+
+    /// Close an empty distribution account and its token account.
+    pub fn close_distribution(ctx: Context<CloseDistribution>) -> Result<()> {
+        instructions::close::handler(ctx)
+    }
+
+    /// Push the royalties of a creator to the creator, callable by anyone.
+    pub fn distribute_to_creator<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeToCreator<'info>>,
+    ) -> Result<()> {
+        instructions::distribute::handler(ctx)
+    }
+
+    /// Migrate a distribution account to the current version.
+    pub fn migrate_distribution(ctx: Context<MigrateDistribution>) -> Result<()> {
+        instructions::migrate::handler(ctx)
+    }
+
+    /// Forward royalties of a split creator into the split, passed as remaining accounts.
+    pub fn forward_to_split<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForwardToSplit<'info>>,
+    ) -> Result<()> {
+        instructions::split::handler(ctx)
+    }
+
+    /// Sweep the rounding dust of a distribution account to its dust recipient.
+    pub fn sweep_dust<'info>(ctx: Context<'_, '_, '_, 'info, SweepDust<'info>>) -> Result<()> {
+        instructions::sweep::handler(ctx)
+    }
+
+    /// Update the dust recipient of a distribution account.
+    pub fn update_dust_recipient(
+        ctx: Context<UpdateDustRecipient>,
+        dust_recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::dust_recipient::handler(ctx, dust_recipient)
+    }
+
+    /// Update the vesting schedule of the royalties credited to a distribution account.
+    pub fn update_vesting_schedule(
+        ctx: Context<UpdateVestingSchedule>,
+        args: UpdateVestingScheduleArgs,
+    ) -> Result<()> {
+        instructions::vesting::handler(ctx, args)
+    }
+
+    /// Update the crank tip of a distribution account.
+    pub fn update_crank_tip(
+        ctx: Context<UpdateCrankTip>,
+        crank_tip_basis_points: u16,
+    ) -> Result<()> {
+        instructions::crank_tip::handler(ctx, crank_tip_basis_points)
+    }
 }
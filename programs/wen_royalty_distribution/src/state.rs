@@ -1,15 +1,114 @@
+use std::str::FromStr;
+
 use anchor_lang::prelude::*;
 use serde::Serialize;
 
+use crate::DistributionErrors;
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace, Debug, Serialize)]
 pub struct Creator {
     /// creator address
     pub address: Pubkey,
     /// token amount that creator can claim
     pub claim_amount: u64,
+    /// token amount credited to the creator over the lifetime of the distribution account
+    pub total_earned: u64,
+    /// token amount paid out to the creator over the lifetime of the distribution account
+    pub total_claimed: u64,
+    /// slot of the last claim, 0 if the creator never claimed
+    pub last_claim_slot: u64,
+    /// token amount credited to the creator that has not vested yet
+    pub unvested_amount: u64,
+    /// token amount released from vesting under the current vesting schedule
+    pub vested_amount: u64,
+}
+
+/// creator entry of version 1 distribution accounts
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CreatorV1 {
+    /// creator address
+    pub address: Pubkey,
+    /// token amount that creator can claim
+    pub claim_amount: u64,
+}
+
+impl From<CreatorV1> for Creator {
+    /// unclaimed royalties are the only known earnings of creators before version 2
+    fn from(creator: CreatorV1) -> Self {
+        Self {
+            address: creator.address,
+            claim_amount: creator.claim_amount,
+            total_earned: creator.claim_amount,
+            total_claimed: 0,
+            last_claim_slot: 0,
+            unvested_amount: 0,
+            vested_amount: 0,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatorShare {
+    /// creator address
+    pub address: Pubkey,
+    /// creator share in basis points
+    pub share: u16,
+}
+
+impl CreatorShare {
+    /// creator shares mirrored in the mint additional metadata, legacy creator shares in whole
+    /// percentages adding up to `LEGACY_CREATOR_SHARES_TOTAL` are converted to basis points
+    pub fn from_metadata(additional_metadata: &[(String, String)]) -> Result<Vec<Self>> {
+        let mut creators = additional_metadata
+            .iter()
+            .filter(|(key, _)| key != ROYALTY_BASIS_POINTS_FIELD)
+            .filter_map(|(key, value)| {
+                Pubkey::from_str(key).ok().map(|address| {
+                    Ok(Self {
+                        address,
+                        share: u16::from_str(value)
+                            .map_err(|_| DistributionErrors::InvalidCreatorPctAmount)?,
+                    })
+                })
+            })
+            .collect::<Result<Vec<Self>>>()?;
+
+        let total_share = creators
+            .iter()
+            .try_fold(0u16, |total, creator| total.checked_add(creator.share));
+        if total_share == Some(LEGACY_CREATOR_SHARES_TOTAL) {
+            for creator in creators.iter_mut() {
+                creator.share *= CREATOR_SHARES_TOTAL / LEGACY_CREATOR_SHARES_TOTAL;
+            }
+        }
+
+        Ok(creators)
+    }
+
+    /// amount owed to the creator out of `amount` (amount * share / 10000)
+    pub fn share_of(&self, amount: u64) -> Result<u64> {
+        let share_amount = (amount as u128)
+            .checked_mul(self.share as u128)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?
+            / CREATOR_SHARES_TOTAL as u128;
+        Ok(share_amount as u64)
+    }
 }
 
 pub const ROYALTY_BASIS_POINTS_FIELD: &str = "royalty_basis_points";
+/// creator shares are in basis points and have to add up to 10000
+pub const CREATOR_SHARES_TOTAL: u16 = 10000;
+/// creator shares written to metadata before basis points were whole percentages adding up to 100
+pub const LEGACY_CREATOR_SHARES_TOTAL: u16 = 100;
+/// maximum crank tip the group update authority can set on a distribution account
+pub const MAX_CRANK_TIP_BASIS_POINTS: u16 = 50;
+/// maximum number of nested splits royalties can be forwarded through
+pub const MAX_SPLIT_DEPTH: usize = 3;
+pub const ROYALTY_CONFIG_SEED: &[u8] = b"royalty-config";
+pub const GROUP_ACCOUNT_SEED: &[u8] = b"group";
+pub const MEMBER_ACCOUNT_SEED: &[u8] = b"member";
+pub const WEN_NEW_STANDARD_PROGRAM_ID: Pubkey =
+    pubkey!("wns1gDLt8fgLcGhWi5MqAqgXpwEP1JftKE9eZnXS1HM");
 
 #[account()]
 #[derive(InitSpace)]
@@ -20,19 +119,391 @@ pub struct DistributionAccount {
     pub group_mint: Pubkey,
     /// payment mint for the distribution account
     pub payment_mint: Pubkey,
+    /// royalties received over the lifetime of the distribution account, creator shares and dust
+    pub total_earned: u64,
+    /// rounding remainder of royalty splits that has not been swept yet
+    pub dust_amount: u64,
+    /// receiver of swept dust, Pubkey::default() for the first creator in claim data
+    pub dust_recipient: Pubkey,
+    /// share of every distribute_to_creator payout kept by the cranker, in basis points
+    pub crank_tip_basis_points: u16,
+    /// number of creators in claim data
+    pub creator_count: u32,
+    /// unix timestamp vesting starts at
+    pub vesting_start: i64,
+    /// seconds after the vesting start before anything vests
+    pub vesting_cliff: i64,
+    /// seconds after the vesting start until everything is vested, 0 if royalties do not vest
+    pub vesting_duration: i64,
     #[max_len(1)] // initial length
     pub claim_data: Vec<Creator>,
 }
 
+/// layout of version 1 distribution accounts, upgraded in place by `migrate_distribution`
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct DistributionAccountV1 {
+    /// distribution version
+    pub version: u8,
+    /// group to which the distribution account belongs to
+    pub group_mint: Pubkey,
+    /// payment mint for the distribution account
+    pub payment_mint: Pubkey,
+    pub claim_data: Vec<CreatorV1>,
+}
+
 pub const CLAIM_DATA_OFFSET: usize = 8 + DistributionAccount::INIT_SPACE - Creator::INIT_SPACE;
 pub const DISTRIBUTION_ACCOUNT_MIN_LEN: usize = DistributionAccount::INIT_SPACE + 8;
 
 impl DistributionAccount {
-    pub const VERSION: u8 = 1;
+    pub const VERSION: u8 = 2;
+    pub const VERSION_1: u8 = 1;
+
     pub fn initialize_account_data(&mut self, group_mint: Pubkey, payment_mint: Pubkey) {
         self.version = Self::VERSION;
         self.group_mint = group_mint;
         self.payment_mint = payment_mint;
+        self.total_earned = 0;
+        self.dust_amount = 0;
+        self.dust_recipient = Pubkey::default();
+        self.crank_tip_basis_points = 0;
+        self.creator_count = 0;
+        self.vesting_start = 0;
+        self.vesting_cliff = 0;
+        self.vesting_duration = 0;
         self.claim_data = vec![];
     }
+
+    /// upgrade a version 1 distribution account, unclaimed royalties are the only known earnings
+    pub fn from_v1(distribution: DistributionAccountV1) -> Result<Self> {
+        let total_earned = distribution
+            .claim_data
+            .iter()
+            .try_fold(0u64, |total, creator| {
+                total.checked_add(creator.claim_amount)
+            })
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+
+        Ok(Self {
+            version: Self::VERSION,
+            group_mint: distribution.group_mint,
+            payment_mint: distribution.payment_mint,
+            total_earned,
+            dust_amount: 0,
+            dust_recipient: Pubkey::default(),
+            crank_tip_basis_points: 0,
+            creator_count: distribution.claim_data.len() as u32,
+            vesting_start: 0,
+            vesting_cliff: 0,
+            vesting_duration: 0,
+            claim_data: distribution
+                .claim_data
+                .into_iter()
+                .map(Creator::from)
+                .collect(),
+        })
+    }
+
+    /// handlers only operate on the current layout, older versions have to be migrated first
+    pub fn check_version(&self) -> Result<()> {
+        match self.version {
+            Self::VERSION => Ok(()),
+            Self::VERSION_1 => {
+                err!(DistributionErrors::OutdatedDistributionVersion)
+            }
+            _ => err!(DistributionErrors::InvalidDistributionVersion),
+        }
+    }
+
+    pub fn set_claim_data(&mut self, claim_data: Vec<Creator>) {
+        self.creator_count = claim_data.len() as u32;
+        self.claim_data = claim_data;
+    }
+
+    /// split `amount` between creators (amount * share / 10000), the rounding remainder is tracked as dust.
+    /// the account has to be reallocated to fit the returned claim data by the caller
+    pub fn credit_creators(
+        &mut self,
+        creators: &[CreatorShare],
+        amount: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        // royalties credited after the end of the schedule are vested already and claimable right away
+        let vesting = self.has_vesting() && timestamp < self.vesting_end()?;
+
+        // update creator amounts in distribution account. add creator if not present, else update amount
+        let current_data = self.claim_data.clone();
+        let mut new_data = vec![];
+        // Incoming creator updates
+        for creator in creators.iter() {
+            let share_amount = creator.share_of(amount)?;
+            let mut new_creator = current_data
+                .iter()
+                .find(|current_creator| current_creator.address == creator.address)
+                .cloned()
+                .unwrap_or(Creator {
+                    address: creator.address,
+                    claim_amount: 0,
+                    total_earned: 0,
+                    total_claimed: 0,
+                    last_claim_slot: 0,
+                    unvested_amount: 0,
+                    vested_amount: 0,
+                });
+            // royalties only become claimable once vested while the vesting schedule is running
+            if vesting {
+                new_creator.unvested_amount = new_creator
+                    .unvested_amount
+                    .checked_add(share_amount)
+                    .ok_or(DistributionErrors::ArithmeticOverflow)?;
+            } else {
+                new_creator.claim_amount = new_creator
+                    .claim_amount
+                    .checked_add(share_amount)
+                    .ok_or(DistributionErrors::ArithmeticOverflow)?;
+            }
+            new_creator.total_earned = new_creator
+                .total_earned
+                .checked_add(share_amount)
+                .ok_or(DistributionErrors::ArithmeticOverflow)?;
+            new_data.push(new_creator);
+        }
+
+        // add creators from old data with claim amount > 0 or earnings history if not present in incoming creator updates
+        for creator in current_data.iter() {
+            if (creator.claim_amount > 0 || creator.total_earned > 0)
+                && !new_data
+                    .iter()
+                    .any(|new_creator| new_creator.address == creator.address)
+            {
+                new_data.push(creator.clone());
+            }
+        }
+
+        // track the rounding remainder of the split as dust, so that the vault balance always adds up
+        let mut distributed_amount: u64 = 0;
+        for creator in creators.iter() {
+            distributed_amount = distributed_amount
+                .checked_add(creator.share_of(amount)?)
+                .ok_or(DistributionErrors::ArithmeticOverflow)?;
+        }
+        let dust_amount = amount
+            .checked_sub(distributed_amount)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+        self.dust_amount = self
+            .dust_amount
+            .checked_add(dust_amount)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+
+        self.total_earned = self
+            .total_earned
+            .checked_add(amount)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?;
+        self.set_claim_data(new_data);
+        Ok(())
+    }
+
+    pub fn has_vesting(&self) -> bool {
+        self.vesting_duration > 0
+    }
+
+    /// unix timestamp everything is vested at
+    pub fn vesting_end(&self) -> Result<i64> {
+        self.vesting_start
+            .checked_add(self.vesting_duration)
+            .ok_or(error!(DistributionErrors::ArithmeticOverflow))
+    }
+
+    /// vested part of `amount` at `timestamp`, nothing before the cliff, then linear over the duration
+    pub fn vested_amount_of(&self, amount: u64, timestamp: i64) -> Result<u64> {
+        if !self.has_vesting() {
+            return Ok(amount);
+        }
+
+        let elapsed = timestamp.saturating_sub(self.vesting_start);
+        if elapsed < self.vesting_cliff {
+            return Ok(0);
+        }
+        if elapsed >= self.vesting_duration {
+            return Ok(amount);
+        }
+
+        let vested_amount = (amount as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(DistributionErrors::ArithmeticOverflow)?
+            / self.vesting_duration as u128;
+        Ok(vested_amount as u64)
+    }
+
+    /// move the vested part of the unvested royalties of every creator to their claim amount
+    pub fn release_vested(&mut self, timestamp: i64) -> Result<()> {
+        for index in 0..self.claim_data.len() {
+            let creator = &self.claim_data[index];
+            if creator.unvested_amount == 0 {
+                continue;
+            }
+
+            let vesting_amount = creator
+                .unvested_amount
+                .checked_add(creator.vested_amount)
+                .ok_or(DistributionErrors::ArithmeticOverflow)?;
+            let released_amount = self
+                .vested_amount_of(vesting_amount, timestamp)?
+                .saturating_sub(creator.vested_amount)
+                .min(creator.unvested_amount);
+
+            let creator = &mut self.claim_data[index];
+            creator.unvested_amount -= released_amount;
+            creator.vested_amount += released_amount;
+            creator.claim_amount = creator
+                .claim_amount
+                .checked_add(released_amount)
+                .ok_or(DistributionErrors::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// royalties vested under the current schedule are released before the schedule is replaced,
+    /// the remaining unvested royalties vest under the new schedule
+    pub fn set_vesting_schedule(
+        &mut self,
+        start: i64,
+        cliff: i64,
+        duration: i64,
+        timestamp: i64,
+    ) -> Result<()> {
+        require!(
+            cliff >= 0 && duration >= 0 && cliff <= duration,
+            DistributionErrors::InvalidVestingSchedule
+        );
+        // a schedule starts at the latest now and can't be over already, so that royalties can neither be
+        // held back by a start in the future nor credited after the end
+        if duration > 0 {
+            let end = start
+                .checked_add(duration)
+                .ok_or(DistributionErrors::ArithmeticOverflow)?;
+            require!(
+                start <= timestamp && timestamp < end,
+                DistributionErrors::InvalidVestingSchedule
+            );
+        }
+
+        self.release_vested(timestamp)?;
+        for creator in self.claim_data.iter_mut() {
+            creator.vested_amount = 0;
+        }
+
+        self.vesting_start = start;
+        self.vesting_cliff = cliff;
+        self.vesting_duration = duration;
+        // without a schedule everything left is claimable right away
+        self.release_vested(timestamp)
+    }
+
+    /// dust goes to the configured dust recipient, else to the first creator in claim data
+    pub fn get_dust_recipient(&self) -> Option<Pubkey> {
+        if self.dust_recipient != Pubkey::default() {
+            return Some(self.dust_recipient);
+        }
+        self.claim_data.first().map(|creator| creator.address)
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RoyaltyConfigCreator {
+    /// creator address
+    pub address: Pubkey,
+    /// creator share in basis points
+    pub share: u16,
+}
+
+/// read-only mirror of the wns `RoyaltyConfig` account
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RoyaltyConfig {
+    /// royalty config version
+    pub version: u8,
+    /// mint the royalty config belongs to
+    pub mint: Pubkey,
+    /// royalty basis points charged on every sale
+    pub royalty_basis_points: u16,
+    /// creators splitting the royalties
+    pub creators: Vec<RoyaltyConfigCreator>,
+}
+
+impl RoyaltyConfig {
+    pub const DISCRIMINATOR: [u8; 8] = [43, 108, 34, 11, 206, 64, 166, 81];
+
+    /// returns None if the royalty config has not been created by wns yet
+    pub fn try_from_account_info(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &WEN_NEW_STANDARD_PROGRAM_ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != Self::DISCRIMINATOR {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+        let royalty_config = Self::deserialize(&mut &data[8..])?;
+        Ok(Some(royalty_config))
+    }
+}
+
+/// read-only mirror of the leading fields of the wns `TokenGroup` account, the group flags that
+/// follow them are not read
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TokenGroup {
+    /// authority that can sign to update the group
+    pub update_authority: Pubkey,
+    /// mint the group belongs to
+    pub mint: Pubkey,
+    /// current number of group members
+    pub size: u32,
+    /// maximum number of group members, `u32::MAX` for unbounded groups
+    pub max_size: u32,
+}
+
+impl TokenGroup {
+    pub const DISCRIMINATOR: [u8; 8] = [184, 107, 4, 187, 196, 55, 142, 134];
+
+    /// returns None if the group has no wns group account, e.g. after it was migrated
+    pub fn try_from_account_info(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &WEN_NEW_STANDARD_PROGRAM_ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != Self::DISCRIMINATOR {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+        let group = Self::deserialize(&mut &data[8..])?;
+        Ok(Some(group))
+    }
+}
+
+/// read-only mirror of the wns `TokenGroupMember` account
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TokenGroupMember {
+    /// mint the member belongs to
+    pub mint: Pubkey,
+    /// wns group account of the member
+    pub group: Pubkey,
+    /// member number
+    pub member_number: u32,
+}
+
+impl TokenGroupMember {
+    pub const DISCRIMINATOR: [u8; 8] = [17, 208, 50, 173, 30, 127, 245, 94];
+
+    /// returns None if the mint has no wns member account, e.g. after it was migrated
+    pub fn try_from_account_info(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &WEN_NEW_STANDARD_PROGRAM_ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != Self::DISCRIMINATOR {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+        let member = Self::deserialize(&mut &data[8..])?;
+        Ok(Some(member))
+    }
 }
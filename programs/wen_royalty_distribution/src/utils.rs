@@ -1,24 +1,255 @@
-use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    program::invoke, program_pack::Pack, system_instruction::transfer,
+};
+use anchor_spl::{
+    token::{spl_token::state::Mint as TokenMint, ID as token_keg_program_id},
+    token_interface::spl_token_2022::{
+        extension::{transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions},
+        onchain::invoke_transfer_checked,
+        state::Mint,
+    },
+};
+use spl_token_group_interface::state::{
+    TokenGroup as TokenGroupExtension, TokenGroupMember as TokenGroupMemberExtension,
+};
 
-use crate::Creator;
+use crate::{
+    Creator, DistributionErrors, TokenGroup, TokenGroupMember, CLAIM_DATA_OFFSET,
+    GROUP_ACCOUNT_SEED, MEMBER_ACCOUNT_SEED, ROYALTY_CONFIG_SEED, WEN_NEW_STANDARD_PROGRAM_ID,
+};
 
-/// search for creator, if found return amount and set claim amount to 0
+/// search for creator, if found return amount, set claim amount to 0 and record the claim
 pub fn get_and_clear_creator_royalty_amount(
     creator_address: Pubkey,
     claim_data: &mut [Creator],
-) -> u64 {
+    slot: u64,
+) -> Result<u64> {
     let mut amount = 0;
     for creator in claim_data.iter_mut() {
         if creator.address == creator_address {
             amount = creator.claim_amount;
-            creator.claim_amount = 0;
+            if amount > 0 {
+                creator.claim_amount = 0;
+                creator.total_claimed = creator
+                    .total_claimed
+                    .checked_add(amount)
+                    .ok_or(DistributionErrors::ArithmeticOverflow)?;
+                creator.last_claim_slot = slot;
+            }
             break;
         }
     }
-    amount
+    Ok(amount)
 }
 
 pub fn get_bump_in_seed_form(bump: &u8) -> [u8; 1] {
     let bump_val = *bump;
     [bump_val]
 }
+
+/// get the update authority of a group, from the wns group account if the group has not been
+/// migrated yet, else from the token extensions group extension of the group mint
+pub fn get_group_update_authority(
+    group_mint: &AccountInfo,
+    group: &AccountInfo,
+) -> Result<Option<Pubkey>> {
+    if let Some(group) = TokenGroup::try_from_account_info(group)? {
+        return Ok(Some(group.update_authority));
+    }
+
+    let group_mint_data = group_mint.try_borrow_data()?;
+    let group_mint = StateWithExtensions::<Mint>::unpack(&group_mint_data)?;
+    let update_authority = group_mint
+        .get_extension::<TokenGroupExtension>()
+        .ok()
+        .and_then(|group| Option::<Pubkey>::from(group.update_authority));
+    Ok(update_authority)
+}
+
+/// check if a mint is a member of the group of `group_mint`, either through a wns member account or
+/// through the token extensions group member extension of the mint
+pub fn is_group_member(
+    mint: &AccountInfo,
+    member: &AccountInfo,
+    group_mint: &Pubkey,
+) -> Result<bool> {
+    if let Some(member) = TokenGroupMember::try_from_account_info(member)? {
+        let (group, _) = Pubkey::find_program_address(
+            &[GROUP_ACCOUNT_SEED, group_mint.as_ref()],
+            &WEN_NEW_STANDARD_PROGRAM_ID,
+        );
+        return Ok(member.mint == mint.key() && member.group == group);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let group_member = mint.get_extension::<TokenGroupMemberExtension>().ok();
+    Ok(group_member.is_some_and(|group_member| group_member.group == *group_mint))
+}
+
+/// check if a mint belongs to the group of `group_mint` either directly or through sub groups. the
+/// group path lists the wns group account, the royalty config of the group mint and the member
+/// account of the group mint for every sub group in between, nearest first
+pub fn is_group_descendant(
+    mint: &AccountInfo,
+    member: &AccountInfo,
+    group_mint: &Pubkey,
+    group_path: &[AccountInfo],
+) -> Result<bool> {
+    if is_group_member(mint, member, group_mint)? {
+        return Ok(true);
+    }
+    let Some(member) = TokenGroupMember::try_from_account_info(member)? else {
+        return Ok(false);
+    };
+    if member.mint != mint.key() {
+        return Ok(false);
+    }
+
+    let (group, _) = Pubkey::find_program_address(
+        &[GROUP_ACCOUNT_SEED, group_mint.as_ref()],
+        &WEN_NEW_STANDARD_PROGRAM_ID,
+    );
+    let mut parent = member.group;
+    for accounts in group_path.chunks_exact(3) {
+        let [group_account, royalty_config, group_member] = accounts else {
+            return Ok(false);
+        };
+        require_keys_eq!(
+            group_account.key(),
+            parent,
+            DistributionErrors::InvalidGroupMember
+        );
+        let Some(group_account) = TokenGroup::try_from_account_info(group_account)? else {
+            return Ok(false);
+        };
+        let (royalty_config_address, _) = Pubkey::find_program_address(
+            &[ROYALTY_CONFIG_SEED, group_account.mint.as_ref()],
+            &WEN_NEW_STANDARD_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            royalty_config.key(),
+            royalty_config_address,
+            DistributionErrors::InvalidGroupMember
+        );
+        let (group_member_address, _) = Pubkey::find_program_address(
+            &[MEMBER_ACCOUNT_SEED, group_account.mint.as_ref()],
+            &WEN_NEW_STANDARD_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            group_member.key(),
+            group_member_address,
+            DistributionErrors::InvalidGroupMember
+        );
+        let Some(group_member) = TokenGroupMember::try_from_account_info(group_member)? else {
+            return Ok(false);
+        };
+
+        parent = group_member.group;
+        if parent == group {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// whether transfers of the payment mint invoke a transfer hook program
+pub fn has_transfer_hook(payment_mint: &AccountInfo) -> Result<bool> {
+    if payment_mint.owner.eq(&token_keg_program_id) {
+        return Ok(false);
+    }
+
+    let mint_data = payment_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    Ok(mint
+        .get_extension::<TransferHook>()
+        .is_ok_and(|transfer_hook| Option::<Pubkey>::from(transfer_hook.program_id).is_some()))
+}
+
+/// transfer payment tokens with `transfer_checked`, the extra accounts required by the transfer hook of
+/// the payment mint (if any) are resolved from `additional_accounts`
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_payment_tokens<'info>(
+    token_program: AccountInfo<'info>,
+    payment_mint: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mint_decimals = {
+        let mint_data = payment_mint.try_borrow_data()?;
+        if token_program.key.eq(&token_keg_program_id) {
+            TokenMint::unpack(&mint_data)?.decimals
+        } else {
+            StateWithExtensions::<Mint>::unpack(&mint_data)?
+                .base
+                .decimals
+        }
+    };
+
+    invoke_transfer_checked(
+        token_program.key,
+        from,
+        payment_mint,
+        to,
+        authority,
+        additional_accounts,
+        amount,
+        mint_decimals,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// transfer payment tokens out of the distribution token account, signed by the distribution account
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_distribution_tokens<'info>(
+    token_program: AccountInfo<'info>,
+    payment_mint: AccountInfo<'info>,
+    distribution: AccountInfo<'info>,
+    distribution_token_account: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    transfer_payment_tokens(
+        token_program,
+        payment_mint,
+        distribution_token_account,
+        to,
+        distribution,
+        additional_accounts,
+        amount,
+        signer_seeds,
+    )
+}
+
+/// resize a distribution account to fit `creator_count` creators in its claim data, the payer tops up
+/// the rent of a grown account
+pub fn realloc_distribution_account<'info>(
+    distribution: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    creator_count: usize,
+) -> Result<()> {
+    let new_creator_size = std::cmp::max(creator_count * Creator::INIT_SPACE, Creator::INIT_SPACE);
+    let realloc_size = CLAIM_DATA_OFFSET + new_creator_size;
+
+    let min_rent = Rent::get()?.minimum_balance(realloc_size);
+    let current_lamports = distribution.lamports();
+    if min_rent > current_lamports {
+        invoke(
+            &transfer(payer.key, distribution.key, min_rent - current_lamports),
+            &[payer.clone(), distribution.clone(), system_program.clone()],
+        )?;
+    }
+
+    distribution.realloc(realloc_size, false)?;
+    Ok(())
+}
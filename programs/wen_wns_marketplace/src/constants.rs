@@ -1,2 +1,3 @@
 pub const MARKETPLACE: &[u8] = b"marketplace";
 pub const LISTING: &[u8] = b"listing";
+pub const CONFIG: &[u8] = b"config";
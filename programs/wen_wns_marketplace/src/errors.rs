@@ -10,4 +10,10 @@ pub enum WenWnsMarketplaceError {
     InvalidPaymentTokenAccount,
     #[msg("Arithmetic error")]
     ArithmeticError,
+    #[msg("Payment token program required for SPL payments")]
+    InvalidPaymentTokenProgram,
+    #[msg("Marketplace is paused")]
+    MarketplacePaused,
+    #[msg("Invalid marketplace config admin")]
+    InvalidConfigAdmin,
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::program::WenWnsMarketplace;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// upgrade authority of the program, becomes the config admin
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MarketplaceConfig::size(),
+        seeds = [MARKETPLACE, CONFIG],
+        bump
+    )]
+    pub config: Account<'info, MarketplaceConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, WenWnsMarketplace>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ WenWnsMarketplaceError::InvalidConfigAdmin,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitConfig>) -> Result<()> {
+    // upgrade authority is the admin that can pause the marketplace
+    let config = &mut ctx.accounts.config;
+    config.bump = ctx.bumps.config;
+    config.admin = ctx.accounts.upgrade_authority.key();
+    config.paused = false;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod init;
+pub mod pause;
+
+pub use init::*;
+pub use pause::*;
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PauseMarketplace<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARKETPLACE, CONFIG],
+        bump = config.bump,
+        has_one = admin @ WenWnsMarketplaceError::InvalidConfigAdmin,
+    )]
+    pub config: Account<'info, MarketplaceConfig>,
+}
+
+pub fn handler(ctx: Context<PauseMarketplace>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused = paused;
+
+    Ok(())
+}
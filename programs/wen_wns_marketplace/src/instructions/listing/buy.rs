@@ -5,8 +5,11 @@ use anchor_lang::{
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_2022::{
-        spl_token_2022::{extension::StateWithExtensions, state::Mint as StateMint},
-        transfer_checked, Token2022, TransferChecked,
+        spl_token_2022::{
+            extension::StateWithExtensions, onchain::invoke_transfer_checked,
+            state::Mint as StateMint,
+        },
+        Token2022,
     },
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
@@ -16,6 +19,7 @@ use wen_new_standard::{
         approve_transfer, thaw_mint_account,
     },
     program::WenNewStandard,
+    split_group_path, MEMBER_ACCOUNT_SEED, ROYALTY_CONFIG_SEED,
 };
 use wen_royalty_distribution::{program::WenRoyaltyDistribution, DistributionAccount};
 
@@ -46,6 +50,13 @@ pub struct FulfillListing<'info> {
     )]
     pub listing: Account<'info, Listing>,
 
+    #[account(
+        seeds = [MARKETPLACE, CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ WenWnsMarketplaceError::MarketplacePaused,
+    )]
+    pub config: Account<'info, MarketplaceConfig>,
+
     /// CHECK: Could be SOL or SPL, checked in distribution program
     pub payment_mint: UncheckedAccount<'info>,
 
@@ -87,6 +98,27 @@ pub struct FulfillListing<'info> {
     /// CHECK: Checked inside WNS program
     #[account(mut)]
     pub approve_account: UncheckedAccount<'info>,
+    /// CHECK: Can be uninitialized for mints whose royalties have not been migrated
+    #[account(
+        seeds = [ROYALTY_CONFIG_SEED, mint.key().as_ref()],
+        seeds::program = wns_program.key(),
+        bump,
+    )]
+    pub royalty_config: UncheckedAccount<'info>,
+    /// CHECK: Can be uninitialized for mints that are not members of a WNS group
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        seeds::program = wns_program.key(),
+        bump,
+    )]
+    pub member: UncheckedAccount<'info>,
+    /// CHECK: Can be uninitialized for groups without group royalties
+    #[account(
+        seeds = [ROYALTY_CONFIG_SEED, distribution.group_mint.as_ref()],
+        seeds::program = wns_program.key(),
+        bump,
+    )]
+    pub group_royalty_config: UncheckedAccount<'info>,
 
     pub wns_program: Program<'info, WenNewStandard>,
     pub distribution_program: Program<'info, WenRoyaltyDistribution>,
@@ -117,9 +149,21 @@ pub struct FulfillListing<'info> {
         token::token_program = payment_token_program
     )]
     pub distribution_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: Checked inside WNS program, receives the protocol fee in SOL
+    #[account(mut)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::token_program = payment_token_program
+    )]
+    pub treasury_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
-pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FulfillListing<'info>>,
+    args: FulfillListingArgs,
+) -> Result<()> {
     let listing = &mut ctx.accounts.listing;
 
     let is_payment_mint_spl = ctx.accounts.payment_mint.key.ne(&Pubkey::default());
@@ -146,8 +190,22 @@ pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result
         signer_seeds,
     ))?;
 
+    // remaining accounts are the group path of the mint for members of sub groups, its first
+    // `group_path_len` accounts, and the extra accounts of the payment mint transfer hook, if any.
+    // both are forwarded for the royalty payment
+    let (group_path, hook_accounts) =
+        split_group_path(ctx.remaining_accounts, args.group_path_len)?;
+
     // Transfer (listing_amount - royalty) to seller
-    let royalty_funds = calculate_royalties(&ctx.accounts.mint.to_account_info(), args.buy_amount)?;
+    let royalty_funds = calculate_royalties(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.royalty_config.to_account_info(),
+        &ctx.accounts.member.to_account_info(),
+        &ctx.accounts.distribution.group_mint,
+        &ctx.accounts.group_royalty_config.to_account_info(),
+        group_path,
+        args.buy_amount,
+    )?;
 
     let funds_to_send = listing
         .listing_amount
@@ -155,8 +213,12 @@ pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result
         .ok_or(WenWnsMarketplaceError::ArithmeticError)?;
 
     let buyer_token_account_info = if is_payment_mint_spl {
-        let payment_mint = &ctx.accounts.payment_mint.try_borrow_data()?;
-        let payment_mint_data = StateWithExtensions::<StateMint>::unpack(payment_mint)?;
+        let payment_mint_decimals = {
+            let payment_mint = &ctx.accounts.payment_mint.try_borrow_data()?;
+            StateWithExtensions::<StateMint>::unpack(payment_mint)?
+                .base
+                .decimals
+        };
 
         let buyer_payment_token_account = ctx
             .accounts
@@ -170,22 +232,23 @@ pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result
             .clone()
             .ok_or(WenWnsMarketplaceError::InvalidPaymentTokenAccount)?;
 
-        transfer_checked(
-            CpiContext::new(
-                ctx.accounts
-                    .payment_token_program
-                    .clone()
-                    .unwrap()
-                    .to_account_info(),
-                TransferChecked {
-                    authority: ctx.accounts.buyer.to_account_info(),
-                    from: buyer_payment_token_account.to_account_info(),
-                    to: seller_payment_token_account.to_account_info(),
-                    mint: ctx.accounts.payment_mint.to_account_info(),
-                },
-            ),
+        let payment_token_program = ctx
+            .accounts
+            .payment_token_program
+            .clone()
+            .ok_or(WenWnsMarketplaceError::InvalidPaymentTokenProgram)?;
+
+        // a transfer fee of the payment mint is withheld from the amount the seller receives
+        invoke_transfer_checked(
+            payment_token_program.key,
+            buyer_payment_token_account.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
+            seller_payment_token_account.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            hook_accounts,
             funds_to_send,
-            payment_mint_data.base.decimals,
+            payment_mint_decimals,
+            &[],
         )?;
 
         Some(buyer_payment_token_account.to_account_info())
@@ -210,6 +273,14 @@ pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result
         .as_ref()
         .map(|d| d.to_account_info());
 
+    let treasury_info = ctx.accounts.treasury.as_ref().map(|t| t.to_account_info());
+
+    let treasury_token_account_info = ctx
+        .accounts
+        .treasury_payment_token_account
+        .as_ref()
+        .map(|t| t.to_account_info());
+
     let payment_token_program = ctx
         .accounts
         .payment_token_program
@@ -228,13 +299,21 @@ pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result
                 authority_token_account: buyer_token_account_info,
                 distribution_token_account: distribution_token_account_info,
                 approve_account: ctx.accounts.approve_account.to_account_info(),
+                manager: ctx.accounts.manager.to_account_info(),
+                treasury: treasury_info,
+                treasury_token_account: treasury_token_account_info,
+                royalty_config: ctx.accounts.royalty_config.to_account_info(),
+                member: ctx.accounts.member.to_account_info(),
+                group_royalty_config: ctx.accounts.group_royalty_config.to_account_info(),
                 distribution_program: ctx.accounts.distribution_program.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
                 payment_token_program,
                 system_program: ctx.accounts.system_program.to_account_info(),
             },
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         args.buy_amount,
+        args.group_path_len,
     )?;
 
     // Transfer NFT to buyer
@@ -265,4 +344,7 @@ pub fn handler(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct FulfillListingArgs {
     pub buy_amount: u64,
+    /// number of remaining accounts holding the group path of the mint, the rest are the extra
+    /// accounts of the payment mint transfer hook
+    pub group_path_len: u8,
 }
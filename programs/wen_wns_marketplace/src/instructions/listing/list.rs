@@ -9,6 +9,7 @@ use wen_new_standard::{
 };
 
 use crate::constants::*;
+use crate::errors::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -31,6 +32,13 @@ pub struct ListNFT<'info> {
     )]
     pub listing: Account<'info, Listing>,
 
+    #[account(
+        seeds = [MARKETPLACE, CONFIG],
+        bump = config.bump,
+        constraint = !config.paused @ WenWnsMarketplaceError::MarketplacePaused,
+    )]
+    pub config: Account<'info, MarketplaceConfig>,
+
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
@@ -1,9 +1,11 @@
 pub mod buy;
 pub mod list;
 pub mod royalty;
+pub mod royalty_many;
 pub mod unlist;
 
 pub use buy::*;
 pub use list::*;
 pub use royalty::*;
+pub use royalty_many::*;
 pub use unlist::*;
@@ -49,7 +49,7 @@ pub struct ClaimRoyalty<'info> {
     pub creator_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
-pub fn handler(ctx: Context<ClaimRoyalty>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRoyalty<'info>>) -> Result<()> {
     // CPI Claim distribution
     let distribution_token_account_info = ctx
         .accounts
@@ -63,18 +63,22 @@ pub fn handler(ctx: Context<ClaimRoyalty>) -> Result<()> {
         .as_ref()
         .map(|c| c.to_account_info());
 
-    claim_distribution(CpiContext::new(
-        ctx.accounts.wen_distribution_program.to_account_info(),
-        ClaimDistribution {
-            creator: ctx.accounts.creator.to_account_info(),
-            payment_mint: ctx.accounts.payment_mint.to_account_info(),
-            creator_token_account: creator_token_account_info,
-            distribution: ctx.accounts.distribution.to_account_info(),
-            distribution_token_account: distribution_token_account_info,
-            token_program: ctx.accounts.token_program.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-        },
-    ))?;
+    // remaining accounts are forwarded for the transfer hook of the payment mint, if any
+    claim_distribution(
+        CpiContext::new(
+            ctx.accounts.wen_distribution_program.to_account_info(),
+            ClaimDistribution {
+                creator: ctx.accounts.creator.to_account_info(),
+                payment_mint: ctx.accounts.payment_mint.to_account_info(),
+                creator_token_account: creator_token_account_info,
+                distribution: ctx.accounts.distribution.to_account_info(),
+                distribution_token_account: distribution_token_account_info,
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token::Token, token_interface::Token2022};
+use wen_royalty_distribution::{
+    cpi::{accounts::ClaimMany, claim_many},
+    program::WenRoyaltyDistribution,
+};
+
+#[derive(Accounts)]
+pub struct ClaimManyRoyalties<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub wen_distribution_program: Program<'info, WenRoyaltyDistribution>,
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    /* Remaining accounts: (distribution, payment_mint, distribution_token_account, creator_token_account) per claim, checked in distribution program */
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimManyRoyalties<'info>>) -> Result<()> {
+    // CPI Claim many distributions
+    claim_many(
+        CpiContext::new(
+            ctx.accounts.wen_distribution_program.to_account_info(),
+            ClaimMany {
+                creator: ctx.accounts.creator.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_2022_program: ctx.accounts.token_2022_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+    )?;
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+pub mod config;
 pub mod listing;
 
+pub use config::*;
 pub use listing::*;
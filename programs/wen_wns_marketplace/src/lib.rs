@@ -16,6 +16,20 @@ declare_id!("sALEeD9VGNquoGSXvUAKLeVbXdjiPCb3FTuTm1xSLod");
 pub mod wen_wns_marketplace {
     use super::*;
 
+    /* region CONFIG */
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        config::init::handler(ctx)
+    }
+
+    pub fn pause(ctx: Context<PauseMarketplace>) -> Result<()> {
+        config::pause::handler(ctx, true)
+    }
+
+    pub fn unpause(ctx: Context<PauseMarketplace>) -> Result<()> {
+        config::pause::handler(ctx, false)
+    }
+    /* endregion */
+
     /* region LISTING INSTRUCTIONS (CORE LOGIC) */
     pub fn list(ctx: Context<ListNFT>, args: ListNFTArgs) -> Result<()> {
         listing::list::handler(ctx, args)
@@ -25,14 +39,25 @@ pub mod wen_wns_marketplace {
         listing::unlist::handler(ctx)
     }
 
-    pub fn buy(ctx: Context<FulfillListing>, args: FulfillListingArgs) -> Result<()> {
+    pub fn buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, FulfillListing<'info>>,
+        args: FulfillListingArgs,
+    ) -> Result<()> {
         listing::buy::handler(ctx, args)
     }
     /* endregion */
 
     /* region CLAIM ROYALTY */
-    pub fn claim_royalty(ctx: Context<ClaimRoyalty>) -> Result<()> {
+    pub fn claim_royalty<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRoyalty<'info>>,
+    ) -> Result<()> {
         listing::royalty::handler(ctx)
     }
+
+    pub fn claim_many_royalties<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimManyRoyalties<'info>>,
+    ) -> Result<()> {
+        listing::royalty_many::handler(ctx)
+    }
     /* endregion */
 }
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct MarketplaceConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub paused: bool,
+}
+
+impl MarketplaceConfig {
+    pub fn size() -> usize {
+        8 + // anchor discriminator
+        1 + // bump
+        32 + // admin
+        1 // paused
+    }
+}
@@ -1,5 +1,7 @@
+pub mod config;
 pub mod listing;
 pub mod sale;
 
+pub use config::*;
 pub use listing::*;
 pub use sale::*;
@@ -5,34 +5,31 @@ use anchor_spl::{
         create as create_associated_token, get_associated_token_address_with_program_id,
         Create as CreateAssociatedToken,
     },
-    token_2022::{
-        self,
-        spl_token_2022::{
-            extension::{BaseStateWithExtensions, StateWithExtensions},
-            instruction::transfer_checked,
-            state::Mint as StateMint,
-        },
-    },
-    token_interface::spl_token_metadata_interface::state::TokenMetadata,
+    token_2022::{self, spl_token_2022::instruction::transfer_checked},
 };
 use spl_transfer_hook_interface::onchain::add_extra_accounts_for_execute_cpi;
-use std::str::FromStr;
-use wen_new_standard::ROYALTY_BASIS_POINTS_FIELD;
-
-pub fn calculate_royalties(mint: &AccountInfo, amount: u64) -> Result<u64> {
-    let mint_account_data = mint.try_borrow_data()?;
-    let mint_data = StateWithExtensions::<StateMint>::unpack(&mint_account_data)?;
-    let metadata = mint_data.get_variable_len_extension::<TokenMetadata>()?;
+use wen_new_standard::RoyaltyConfig;
 
-    // get royalty basis points from metadata Vec(String, String)
-    let royalty_basis_points = metadata
-        .additional_metadata
-        .iter()
-        .find(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
-        .map(|(_, value)| u64::from_str(value).unwrap())
-        .unwrap_or(0);
+pub fn calculate_royalties(
+    mint: &AccountInfo,
+    royalty_config: &AccountInfo,
+    member: &AccountInfo,
+    group_mint: &Pubkey,
+    group_royalty_config: &AccountInfo,
+    group_path: &[AccountInfo],
+    amount: u64,
+) -> Result<u64> {
+    // get royalty basis points from the royalty config, inherited from the group if the mint has none
+    let royalty_config = RoyaltyConfig::load(
+        mint,
+        royalty_config,
+        member,
+        group_mint,
+        group_royalty_config,
+        group_path,
+    )?;
 
-    Ok((amount * royalty_basis_points) / 10000)
+    royalty_config.royalty_amount(amount)
 }
 
 pub fn assert_right_associated_token_account(
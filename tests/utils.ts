@@ -17,6 +17,7 @@ import {
   createInitializeMetadataPointerInstruction,
   TOKEN_2022_PROGRAM_ID,
   createInitializeMint2Instruction,
+  createInitializeTransferFeeConfigInstruction,
   createMintToCheckedInstruction,
   createAssociatedTokenAccountInstruction,
   getMinimumBalanceForRentExemptMint,
@@ -32,10 +33,17 @@ import { faker } from "@faker-js/faker";
 
 export const MANAGER_SEED = Buffer.from("manager");
 export const GROUP_ACCOUNT_SEED = Buffer.from("group");
+export const GROUP_AUTHORITY_PROPOSAL_SEED = Buffer.from(
+  "group-authority-proposal",
+);
 export const MEMBER_ACCOUNT_SEED = Buffer.from("member");
 export const MARKETPLACE = Buffer.from("marketplace");
 export const SALE = Buffer.from("sale");
 export const LISTING = Buffer.from("listing");
+export const CONFIG = Buffer.from("config");
+export const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111",
+);
 
 export const getExtraMetasAccountPda = (
   mint: PublicKey,
@@ -57,6 +65,14 @@ export const getApproveAccountPda = (mint: PublicKey, programId: PublicKey) => {
   return approveAccount;
 };
 
+export const getRoyaltyConfigPda = (mint: PublicKey, programId: PublicKey) => {
+  const [royaltyConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("royalty-config"), mint.toBuffer()],
+    programId,
+  );
+  return royaltyConfig;
+};
+
 export const getManagerAccountPda = (programId: PublicKey) => {
   const [managerAccount] = PublicKey.findProgramAddressSync(
     [Buffer.from("manager")],
@@ -81,6 +97,41 @@ export const getMemberAccountPda = (mint: PublicKey, programId: PublicKey) => {
   return memberAccount;
 };
 
+// remaining accounts walking a group up to its top level group, group mints
+// are ordered from the group itself to its outermost parent
+export const getGroupAncestry = (
+  groupMints: PublicKey[],
+  programId: PublicKey,
+) =>
+  groupMints.flatMap((groupMint, index) => [
+    ...(index > 0
+      ? [
+          {
+            pubkey: getGroupAccountPda(groupMint, programId),
+            isSigner: false,
+            isWritable: true,
+          },
+        ]
+      : []),
+    {
+      pubkey: getMemberAccountPda(groupMint, programId),
+      isSigner: false,
+      isWritable: false,
+    },
+  ]);
+
+// remaining accounts leading from the group of a member up to a parent group
+// holding the royalties, group mints are ordered from the group of the member
+// up to the parent group, which is left out
+export const getGroupPath = (groupMints: PublicKey[], programId: PublicKey) =>
+  groupMints.flatMap((groupMint) =>
+    [
+      getGroupAccountPda(groupMint, programId),
+      getRoyaltyConfigPda(groupMint, programId),
+      getMemberAccountPda(groupMint, programId),
+    ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })),
+  );
+
 export const getDistributionAccountPda = (
   group: PublicKey,
   paymentMint: PublicKey,
@@ -105,6 +156,22 @@ export const getListingAccountPda = (
   return listingAccount;
 };
 
+export const getMarketplaceConfigPda = (programId: PublicKey) => {
+  const [configAccount] = PublicKey.findProgramAddressSync(
+    [MARKETPLACE, CONFIG],
+    programId,
+  );
+  return configAccount;
+};
+
+export const getProgramDataPda = (programId: PublicKey) => {
+  const [programData] = PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_ID,
+  );
+  return programData;
+};
+
 export async function airdrop(
   connection: Connection,
   address: PublicKey,
@@ -125,6 +192,19 @@ export async function airdrop(
   );
 }
 
+export async function waitForBlockTime(
+  connection: Connection,
+  timestamp: number,
+  commitment: Commitment = "confirmed",
+) {
+  while (
+    (await connection.getBlockTime(await connection.getSlot(commitment))) <
+    timestamp
+  ) {
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+  }
+}
+
 export async function getMinRentForWNSMint(
   connection: Connection,
   metaData: TokenMetadata,
@@ -242,6 +322,45 @@ export async function createMint2022Ix(
   };
 }
 
+export async function createTransferFeeMint2022Ix(
+  connection: Connection,
+  mint: PublicKey,
+  authority: PublicKey,
+  payer: PublicKey,
+  transferFeeBasisPoints: number,
+  maximumFee: bigint,
+) {
+  const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+  const mintRent = await connection.getMinimumBalanceForRentExemption(mintLen);
+
+  return {
+    ixs: [
+      SystemProgram.createAccount({
+        fromPubkey: payer,
+        newAccountPubkey: mint,
+        programId: TOKEN_2022_PROGRAM_ID,
+        space: mintLen,
+        lamports: mintRent,
+      }),
+      createInitializeTransferFeeConfigInstruction(
+        mint,
+        authority,
+        authority,
+        transferFeeBasisPoints,
+        maximumFee,
+        TOKEN_2022_PROGRAM_ID,
+      ),
+      createInitializeMint2Instruction(
+        mint,
+        6,
+        authority,
+        authority,
+        TOKEN_2022_PROGRAM_ID,
+      ),
+    ],
+  };
+}
+
 export function mintToBuyerSellerIx(
   mint: PublicKey,
   authority: PublicKey,
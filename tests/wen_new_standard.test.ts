@@ -27,6 +27,12 @@ import {
   createApproveCheckedInstruction,
   createAssociatedTokenAccountInstruction,
   createTransferCheckedInstruction,
+  getTokenGroupState,
+  getTokenGroupMemberState,
+  getGroupPointerState,
+  getGroupMemberPointerState,
+  getNonTransferable,
+  getTransferHook,
 } from "@solana/spl-token";
 import {
   Field,
@@ -39,7 +45,13 @@ import {
   sendAndConfirmWNSTransaction,
   getExtraMetasAccountPda,
   getApproveAccountPda,
+  getRoyaltyConfigPda,
+  getGroupAccountPda,
+  getGroupAncestry,
+  getMemberAccountPda,
+  getProgramDataPda,
   GROUP_ACCOUNT_SEED,
+  GROUP_AUTHORITY_PROPOSAL_SEED,
   MEMBER_ACCOUNT_SEED,
 } from "./utils";
 
@@ -57,8 +69,27 @@ describe("wen_new_standard", () => {
     program.programId,
   );
 
+  const initManagerAccount = (upgradeAuthority: PublicKey) =>
+    program.methods.initManagerAccount().accountsStrict({
+      payer,
+      upgradeAuthority,
+      manager,
+      program: wnsProgramId,
+      programData: getProgramDataPda(wnsProgramId),
+      systemProgram: SystemProgram.programId,
+    });
+
+  let strangerInitError;
+
   before(async () => {
-    await program.methods.initManagerAccount().rpc();
+    const stranger = Keypair.generate();
+    try {
+      await initManagerAccount(stranger.publicKey).signers([stranger]).rpc();
+    } catch (err) {
+      strangerInitError = err;
+    }
+
+    await initManagerAccount(payer).rpc();
   });
 
   describe("manager", () => {
@@ -70,7 +101,7 @@ describe("wen_new_standard", () => {
       });
 
       it("should exist with a fixed seed", async () => {
-        expect(account.data).to.eql(
+        expect(account.data.subarray(0, 8)).to.eql(
           Buffer.from([221, 78, 171, 233, 213, 142, 113, 56]),
         );
       });
@@ -80,6 +111,227 @@ describe("wen_new_standard", () => {
           program.programId.toBase58(),
         );
       });
+
+      it("should only be initialized by the upgrade authority", () => {
+        expect(strangerInitError).not.to.be.undefined;
+      });
+
+      it("should make the upgrade authority the admin", async () => {
+        const managerAccount = await program.account.manager.fetch(manager);
+        expect(managerAccount.admin.toBase58()).to.eql(payer.toBase58());
+        expect(managerAccount.protocolFeeBasisPoints).to.eql(0);
+      });
+    });
+
+    describe("after updating the protocol config", () => {
+      const treasury = Keypair.generate().publicKey;
+      const nonAdmin = Keypair.generate();
+
+      let nonAdminError;
+      let invalidFeeError;
+      let managerAccount;
+
+      const updateManagerAccount = (
+        admin: PublicKey,
+        protocolFeeBasisPoints: number,
+        treasury: PublicKey,
+      ) =>
+        program.methods
+          .updateManagerAccount({
+            admin: payer,
+            protocolFeeBasisPoints,
+            treasury,
+          })
+          .accountsStrict({ admin, manager });
+
+      before(async () => {
+        try {
+          await updateManagerAccount(nonAdmin.publicKey, 250, treasury)
+            .signers([nonAdmin])
+            .rpc();
+        } catch (err) {
+          nonAdminError = err;
+        }
+
+        try {
+          await updateManagerAccount(payer, 501, treasury).rpc();
+        } catch (err) {
+          invalidFeeError = err;
+        }
+
+        await updateManagerAccount(payer, 250, treasury).rpc({
+          commitment: "confirmed",
+        });
+        managerAccount = await program.account.manager.fetch(
+          manager,
+          "confirmed",
+        );
+      });
+
+      // sales in the other test suites run without a protocol fee
+      after(async () => {
+        await updateManagerAccount(payer, 0, payer).rpc({
+          commitment: "confirmed",
+        });
+      });
+
+      it("should only be allowed for the admin", () => {
+        expect(nonAdminError).not.to.be.undefined;
+      });
+
+      it("should reject a protocol fee above the maximum", () => {
+        expect(invalidFeeError).not.to.be.undefined;
+      });
+
+      it("should store the protocol fee and treasury", () => {
+        expect(managerAccount.protocolFeeBasisPoints).to.eql(250);
+        expect(managerAccount.treasury.toBase58()).to.eql(treasury.toBase58());
+      });
+    });
+
+    describe("after pausing", () => {
+      const nonAdmin = Keypair.generate();
+      const groupMintKeyPair = Keypair.generate();
+      const groupMintPublicKey = groupMintKeyPair.publicKey;
+      const mintKeyPair = Keypair.generate();
+      const mintPublicKey = mintKeyPair.publicKey;
+
+      let nonAdminError;
+      let createGroupError;
+      let updateMintError;
+      let pausedManagerAccount;
+      let unpausedManagerAccount;
+
+      before(async () => {
+        try {
+          await program.methods
+            .pause()
+            .accountsStrict({ admin: nonAdmin.publicKey, manager })
+            .signers([nonAdmin])
+            .rpc();
+        } catch (err) {
+          nonAdminError = err;
+        }
+
+        await program.methods
+          .createMintAccount({
+            permanentDelegate: null,
+            soulbound: false,
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority: payer,
+            mint: mintPublicKey,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              mintPublicKey,
+              payer,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            payer,
+            receiver: payer,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([mintKeyPair])
+          .rpc({ commitment: "confirmed" });
+
+        await program.methods
+          .pause()
+          .accountsStrict({ admin: payer, manager })
+          .rpc({ commitment: "confirmed" });
+        pausedManagerAccount = await program.account.manager.fetch(
+          manager,
+          "confirmed",
+        );
+
+        try {
+          await program.methods
+            .createGroupAccount({
+              name: faker.lorem.words({ max: 3, min: 2 }),
+              symbol: faker.lorem.word(),
+              uri: faker.internet.url(),
+              maxSize: 1,
+              lockMaxSize: false,
+              advisoryMemberUpdateGate: false,
+            })
+            .accountsStrict({
+              mintTokenAccount: getAssociatedTokenAddressSync(
+                groupMintPublicKey,
+                payer,
+                false,
+                TOKEN_2022_PROGRAM_ID,
+              ),
+              mint: groupMintPublicKey,
+              authority: payer,
+              receiver: payer,
+              group: PublicKey.findProgramAddressSync(
+                [GROUP_ACCOUNT_SEED, groupMintPublicKey.toBuffer()],
+                program.programId,
+              )[0],
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              payer,
+              manager,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([groupMintKeyPair])
+            .rpc({ commitment: "confirmed" });
+        } catch (err) {
+          createGroupError = err;
+        }
+
+        try {
+          await program.methods
+            .updateMintAccount({
+              name: faker.lorem.words({ max: 3, min: 2 }),
+              symbol: faker.lorem.word(),
+              uri: faker.internet.url(),
+            })
+            .accountsStrict({
+              payer,
+              authority: payer,
+              mint: mintPublicKey,
+              groupMint: null,
+              group: null,
+              member: null,
+              groupAuthority: null,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .rpc({ commitment: "confirmed" });
+        } catch (err) {
+          updateMintError = err;
+        }
+
+        await program.methods
+          .unpause()
+          .accountsStrict({ admin: payer, manager })
+          .rpc({ commitment: "confirmed" });
+        unpausedManagerAccount = await program.account.manager.fetch(
+          manager,
+          "confirmed",
+        );
+      });
+
+      it("should only be allowed for the admin", () => {
+        expect(nonAdminError).not.to.be.undefined;
+      });
+
+      it("should reject state-changing instructions while paused", () => {
+        expect(pausedManagerAccount.paused).to.be.true;
+        expect(createGroupError).not.to.be.undefined;
+        expect(updateMintError).not.to.be.undefined;
+      });
+
+      it("should accept them again once unpaused", () => {
+        expect(unpausedManagerAccount.paused).to.be.false;
+      });
     });
   });
 
@@ -117,6 +369,7 @@ describe("wen_new_standard", () => {
         await program.methods
           .createMintAccount({
             permanentDelegate: null,
+            soulbound: false,
             name,
             symbol,
             uri,
@@ -237,6 +490,56 @@ describe("wen_new_standard", () => {
       });
     });
 
+    describe("after updating through the program", () => {
+      const name = faker.lorem.words({ max: 5, min: 5 });
+      const symbol = faker.lorem.word();
+      const uri = faker.internet.url();
+      let metadata: TokenMetadata;
+      let mintAccountInfo: AccountInfo<Buffer>;
+
+      before(async () => {
+        await program.methods
+          .updateMintAccount({ name, symbol, uri })
+          .accountsStrict({
+            payer: mintAuthPublicKey,
+            authority: mintAuthPublicKey,
+            mint: mintPublicKey,
+            groupMint: null,
+            group: null,
+            member: null,
+            groupAuthority: null,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc({ commitment: "confirmed" });
+
+        metadata = await getTokenMetadata(
+          connection,
+          mintPublicKey,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+        mintAccountInfo = await connection.getAccountInfo(
+          mintPublicKey,
+          "confirmed",
+        );
+      });
+
+      it("should have updated name, symbol and uri", async () => {
+        expect(metadata.name).to.eql(name);
+        expect(metadata.symbol).to.eql(symbol);
+        expect(metadata.uri).to.eql(uri);
+      });
+
+      it("should be rent exempt", async () => {
+        const rent = await connection.getMinimumBalanceForRentExemption(
+          mintAccountInfo.data.length,
+        );
+        expect(mintAccountInfo.lamports).to.be.gte(rent);
+      });
+    });
+
     describe("after adding royalties", () => {
       const creator1 = Keypair.generate();
       const creator2 = Keypair.generate();
@@ -245,8 +548,10 @@ describe("wen_new_standard", () => {
         mintPublicKey,
         wnsProgramId,
       );
+      const royaltyConfig = getRoyaltyConfigPda(mintPublicKey, wnsProgramId);
 
       let metadata: TokenMetadata | null;
+      let royaltyConfigAccount;
 
       let royaltyBasisPoints: [string, string] | undefined;
       let creator1Data: [string, string] | undefined;
@@ -256,8 +561,8 @@ describe("wen_new_standard", () => {
         await program.methods
           .addRoyalties({
             creators: [
-              { address: creator1.publicKey, share: 20 },
-              { address: creator2.publicKey, share: 80 },
+              { address: creator1.publicKey, share: 2000 },
+              { address: creator2.publicKey, share: 8000 },
             ],
             royaltyBasisPoints: 500,
           })
@@ -266,6 +571,8 @@ describe("wen_new_standard", () => {
             mint: mintPublicKey,
             payer: mintAuthPublicKey,
             extraMetasAccount,
+            royaltyConfig,
+            manager,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
@@ -293,6 +600,11 @@ describe("wen_new_standard", () => {
         creator2Data = metadata?.additionalMetadata.find(
           ([m]) => m === creator2.publicKey.toString(),
         );
+
+        royaltyConfigAccount = await program.account.royaltyConfig.fetch(
+          royaltyConfig,
+          "confirmed",
+        );
       });
 
       it("should contain seller fee basis points", async () => {
@@ -302,9 +614,26 @@ describe("wen_new_standard", () => {
 
       it("should contain creators and their shares", async () => {
         expect(creator1Data).not.to.undefined;
-        expect(creator1Data[1]).to.eql("20");
+        expect(creator1Data[1]).to.eql("2000");
         expect(creator2Data).not.to.undefined;
-        expect(creator2Data[1]).to.eql("80");
+        expect(creator2Data[1]).to.eql("8000");
+      });
+
+      it("should write the royalty config", async () => {
+        expect(royaltyConfigAccount.version).to.eql(1);
+        expect(royaltyConfigAccount.mint.toBase58()).to.eql(
+          mintPublicKey.toBase58(),
+        );
+        expect(royaltyConfigAccount.royaltyBasisPoints).to.eql(500);
+        expect(
+          royaltyConfigAccount.creators.map((c) => [
+            c.address.toBase58(),
+            c.share,
+          ]),
+        ).to.eql([
+          [creator1.publicKey.toBase58(), 2000],
+          [creator2.publicKey.toBase58(), 8000],
+        ]);
       });
     });
 
@@ -528,6 +857,16 @@ describe("wen_new_standard", () => {
       let mintAccountInfo: AccountInfo<Buffer>;
       let mintAccountLamports: number;
 
+      const extraMetasAccount = getExtraMetasAccountPda(
+        mintPublicKey,
+        wnsProgramId,
+      );
+      const royaltyConfig = getRoyaltyConfigPda(mintPublicKey, wnsProgramId);
+      const approveAccount = getApproveAccountPda(mintPublicKey, wnsProgramId);
+      let approveAccountInfo: AccountInfo<Buffer>;
+      let extraMetasAccountInfo: AccountInfo<Buffer>;
+      let royaltyConfigAccountInfo: AccountInfo<Buffer>;
+
       let totalBurnRent: number;
       let payerPreBurnBalance: number;
       let payerPostBurnBalance: number;
@@ -540,7 +879,9 @@ describe("wen_new_standard", () => {
 
         totalBurnRent =
           (await connection.getBalance(receiverTokenAccount, "confirmed")) +
-          (await connection.getBalance(mintPublicKey, "confirmed"));
+          (await connection.getBalance(mintPublicKey, "confirmed")) +
+          (await connection.getBalance(extraMetasAccount, "confirmed")) +
+          (await connection.getBalance(royaltyConfig, "confirmed"));
 
         const burnIx = await program.methods
           .burnMintAccount()
@@ -550,6 +891,12 @@ describe("wen_new_standard", () => {
             payer: receiver.publicKey,
             user: receiver.publicKey,
             manager,
+            group: null,
+            member: null,
+            subGroup: null,
+            approveAccount,
+            extraMetasAccount,
+            royaltyConfig,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
           .instruction();
@@ -581,6 +928,19 @@ describe("wen_new_standard", () => {
           "confirmed",
         );
 
+        approveAccountInfo = await connection.getAccountInfo(
+          approveAccount,
+          "confirmed",
+        );
+        extraMetasAccountInfo = await connection.getAccountInfo(
+          extraMetasAccount,
+          "confirmed",
+        );
+        royaltyConfigAccountInfo = await connection.getAccountInfo(
+          royaltyConfig,
+          "confirmed",
+        );
+
         payerPostBurnBalance = await connection.getBalance(
           receiver.publicKey,
           "confirmed",
@@ -595,6 +955,11 @@ describe("wen_new_standard", () => {
         expect(tokenAccountInfo).to.be.null;
         expect(mintAccountInfo).to.be.null;
       });
+      it("should close the approve, extra metas and royalty config accounts", async () => {
+        expect(approveAccountInfo).to.be.null;
+        expect(extraMetasAccountInfo).to.be.null;
+        expect(royaltyConfigAccountInfo).to.be.null;
+      });
       it("should credit rent to payer", async () => {
         expect(payerPostBurnBalance).to.eql(
           payerPreBurnBalance + totalBurnRent,
@@ -603,64 +968,272 @@ describe("wen_new_standard", () => {
     });
   });
 
-  describe("group", () => {
-    const groupAuthorityKeyPair = Keypair.generate();
-    const groupAuthorityPublicKey = groupAuthorityKeyPair.publicKey;
-
-    const groupMintKeyPair = Keypair.generate();
-    const groupMintPublicKey = groupMintKeyPair.publicKey;
-
+  describe("royalty config", () => {
     const mintKeyPair = Keypair.generate();
+    const mintAuthPublicKey = wallet.publicKey;
     const mintPublicKey = mintKeyPair.publicKey;
-
-    const [group] = PublicKey.findProgramAddressSync(
-      [GROUP_ACCOUNT_SEED, groupMintPublicKey.toBuffer()],
-      program.programId,
+    const mintTokenAccount = getAssociatedTokenAddressSync(
+      mintPublicKey,
+      mintAuthPublicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
     );
+    const royaltyConfig = getRoyaltyConfigPda(mintPublicKey, wnsProgramId);
 
-    let createGroupArgs;
-    let groupAccountInfo: AccountInfo<Buffer>;
-    let groupAccount;
+    describe("after migrating royalties from metadata", () => {
+      const creator = Keypair.generate();
+      let royaltyConfigAccount;
 
-    before(async () => {
-      createGroupArgs = {
-        name: faker.lorem.text(),
-        symbol: faker.lorem.word(),
-        uri: faker.internet.url(),
-        maxSize: 1,
-      };
+      before(async () => {
+        const createMintAccountIx = await program.methods
+          .createMintAccount({
+            permanentDelegate: null,
+            soulbound: false,
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority: mintAuthPublicKey,
+            mint: mintPublicKey,
+            mintTokenAccount,
+            payer: mintAuthPublicKey,
+            receiver: mintAuthPublicKey,
+            manager,
 
-      const mintTokenAccount = getAssociatedTokenAddressSync(
-        groupMintPublicKey,
-        groupAuthorityPublicKey,
-        true,
-        TOKEN_2022_PROGRAM_ID,
-      );
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .instruction();
 
-      await program.methods
-        .createGroupAccount(createGroupArgs)
-        .accountsStrict({
-          mintTokenAccount,
-          mint: groupMintPublicKey,
-          authority: groupAuthorityPublicKey,
-          receiver: groupAuthorityPublicKey,
-          group,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          payer,
-          manager,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([groupMintKeyPair, groupAuthorityKeyPair])
-        .rpc({
-          skipPreflight: true,
-          preflightCommitment: "confirmed",
-          commitment: "confirmed",
-        });
+        // royalties written the legacy way, straight into the metadata
+        const instructions = [
+          createMintAccountIx,
+          SystemProgram.transfer({
+            fromPubkey: mintAuthPublicKey,
+            toPubkey: mintPublicKey,
+            lamports: 0.01 * LAMPORTS_PER_SOL,
+          }),
+          createUpdateFieldInstruction({
+            field: "royalty_basis_points",
+            metadata: mintPublicKey,
+            programId: TOKEN_2022_PROGRAM_ID,
+            updateAuthority: mintAuthPublicKey,
+            value: "250",
+          }),
+          createUpdateFieldInstruction({
+            field: creator.publicKey.toBase58(),
+            metadata: mintPublicKey,
+            programId: TOKEN_2022_PROGRAM_ID,
+            updateAuthority: mintAuthPublicKey,
+            value: "100",
+          }),
+        ];
 
-      groupAccountInfo = await program.account.tokenGroup.getAccountInfo(group);
-      groupAccount = program.coder.accounts.decode(
-        "tokenGroup",
+        await sendAndConfirmWNSTransaction(
+          connection,
+          instructions,
+          provider,
+          true,
+          [mintKeyPair],
+        );
+
+        await program.methods
+          .migrateRoyalties()
+          .accountsStrict({
+            payer: mintAuthPublicKey,
+            mint: mintPublicKey,
+            royaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        royaltyConfigAccount = await program.account.royaltyConfig.fetch(
+          royaltyConfig,
+          "confirmed",
+        );
+      });
+
+      it("should have the royalty basis points", async () => {
+        expect(royaltyConfigAccount.royaltyBasisPoints).to.eql(250);
+      });
+
+      it("should have the creators and their shares in basis points", async () => {
+        expect(royaltyConfigAccount.creators.length).to.eql(1);
+        expect(royaltyConfigAccount.creators[0].address.toBase58()).to.eql(
+          creator.publicKey.toBase58(),
+        );
+        expect(royaltyConfigAccount.creators[0].share).to.eql(10_000);
+      });
+    });
+
+    describe("after modifying royalties without migrating them", () => {
+      const legacyMintKeyPair = Keypair.generate();
+      const legacyMintPublicKey = legacyMintKeyPair.publicKey;
+      const legacyRoyaltyConfig = getRoyaltyConfigPda(
+        legacyMintPublicKey,
+        wnsProgramId,
+      );
+      const creator = Keypair.generate();
+      let royaltyConfigAccount;
+
+      before(async () => {
+        const createMintAccountIx = await program.methods
+          .createMintAccount({
+            permanentDelegate: null,
+            soulbound: false,
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority: mintAuthPublicKey,
+            mint: legacyMintPublicKey,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              legacyMintPublicKey,
+              mintAuthPublicKey,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            payer: mintAuthPublicKey,
+            receiver: mintAuthPublicKey,
+            manager,
+
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .instruction();
+
+        // royalties written the legacy way, straight into the metadata
+        const instructions = [
+          createMintAccountIx,
+          SystemProgram.transfer({
+            fromPubkey: mintAuthPublicKey,
+            toPubkey: legacyMintPublicKey,
+            lamports: 0.01 * LAMPORTS_PER_SOL,
+          }),
+          createUpdateFieldInstruction({
+            field: "royalty_basis_points",
+            metadata: legacyMintPublicKey,
+            programId: TOKEN_2022_PROGRAM_ID,
+            updateAuthority: mintAuthPublicKey,
+            value: "250",
+          }),
+        ];
+
+        await sendAndConfirmWNSTransaction(
+          connection,
+          instructions,
+          provider,
+          true,
+          [legacyMintKeyPair],
+        );
+
+        await program.methods
+          .modifyRoyalties({
+            royaltyBasisPoints: 500,
+            creators: [{ address: creator.publicKey, share: 10_000 }],
+          })
+          .accountsStrict({
+            payer: mintAuthPublicKey,
+            authority: mintAuthPublicKey,
+            mint: legacyMintPublicKey,
+            royaltyConfig: legacyRoyaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        royaltyConfigAccount = await program.account.royaltyConfig.fetch(
+          legacyRoyaltyConfig,
+          "confirmed",
+        );
+      });
+
+      it("should create the royalty config with the new royalties", () => {
+        expect(royaltyConfigAccount.royaltyBasisPoints).to.eql(500);
+        expect(royaltyConfigAccount.creators.length).to.eql(1);
+        expect(royaltyConfigAccount.creators[0].address.toBase58()).to.eql(
+          creator.publicKey.toBase58(),
+        );
+        expect(royaltyConfigAccount.creators[0].share).to.eql(10_000);
+      });
+    });
+  });
+
+  describe("group", () => {
+    const groupAuthorityKeyPair = Keypair.generate();
+    const groupAuthorityPublicKey = groupAuthorityKeyPair.publicKey;
+
+    const groupMintKeyPair = Keypair.generate();
+    const groupMintPublicKey = groupMintKeyPair.publicKey;
+
+    const mintKeyPair = Keypair.generate();
+    const mintPublicKey = mintKeyPair.publicKey;
+
+    const [group] = PublicKey.findProgramAddressSync(
+      [GROUP_ACCOUNT_SEED, groupMintPublicKey.toBuffer()],
+      program.programId,
+    );
+
+    let createGroupArgs;
+    let groupAccountInfo: AccountInfo<Buffer>;
+    let groupAccount;
+
+    before(async () => {
+      createGroupArgs = {
+        name: faker.lorem.text(),
+        symbol: faker.lorem.word(),
+        uri: faker.internet.url(),
+        maxSize: 1,
+        lockMaxSize: false,
+        advisoryMemberUpdateGate: true,
+      };
+
+      const mintTokenAccount = getAssociatedTokenAddressSync(
+        groupMintPublicKey,
+        groupAuthorityPublicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      await program.methods
+        .createGroupAccount(createGroupArgs)
+        .accountsStrict({
+          mintTokenAccount,
+          mint: groupMintPublicKey,
+          authority: groupAuthorityPublicKey,
+          receiver: groupAuthorityPublicKey,
+          group,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          payer,
+          manager,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([groupMintKeyPair, groupAuthorityKeyPair])
+        .rpc({
+          skipPreflight: true,
+          preflightCommitment: "confirmed",
+          commitment: "confirmed",
+        });
+
+      groupAccountInfo = await program.account.tokenGroup.getAccountInfo(group);
+      groupAccount = program.coder.accounts.decode(
+        "tokenGroup",
         groupAccountInfo.data,
       );
     });
@@ -691,6 +1264,10 @@ describe("wen_new_standard", () => {
       it("should have no members denoted by a size of 0", async () => {
         expect(groupAccount.size).to.eql(0);
       });
+
+      it("should set the advisory member update gate", async () => {
+        expect(groupAccount.advisoryMemberUpdateGate).to.be.true;
+      });
     });
 
     describe("after updating", () => {
@@ -754,6 +1331,86 @@ describe("wen_new_standard", () => {
       });
     });
 
+    describe("after adding group royalties", () => {
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+      const creator = Keypair.generate().publicKey;
+      let groupRoyaltyConfigAccount;
+      let modifyRoyaltiesError;
+
+      before(async () => {
+        await program.methods
+          .addGroupRoyalties({
+            royaltyBasisPoints: 750,
+            creators: [{ address: creator, share: 10_000 }],
+          })
+          .accountsStrict({
+            payer,
+            authority: groupAuthorityPublicKey,
+            mint: groupMintPublicKey,
+            group,
+            royaltyConfig: groupRoyaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([groupAuthorityKeyPair])
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        try {
+          await program.methods
+            .modifyRoyalties({
+              royaltyBasisPoints: 10_000,
+              creators: [{ address: groupAuthorityPublicKey, share: 10_000 }],
+            })
+            .accountsStrict({
+              payer,
+              authority: groupAuthorityPublicKey,
+              mint: groupMintPublicKey,
+              royaltyConfig: groupRoyaltyConfig,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([groupAuthorityKeyPair])
+            .rpc({ commitment: "confirmed" });
+        } catch (err) {
+          modifyRoyaltiesError = err;
+        }
+
+        groupRoyaltyConfigAccount = await program.account.royaltyConfig.fetch(
+          groupRoyaltyConfig,
+          "confirmed",
+        );
+      });
+
+      it("should reference the group mint", async () => {
+        expect(groupRoyaltyConfigAccount.mint.toBase58()).to.eql(
+          groupMintPublicKey.toBase58(),
+        );
+      });
+
+      it("should have the royalty basis points and creators", async () => {
+        expect(groupRoyaltyConfigAccount.royaltyBasisPoints).to.eql(750);
+        expect(
+          groupRoyaltyConfigAccount.creators.map((c) => [
+            c.address.toBase58(),
+            c.share,
+          ]),
+        ).to.eql([[creator.toBase58(), 10_000]]);
+      });
+
+      it("should reject modifying them as the royalties of the group mint", () => {
+        expect(modifyRoyaltiesError).not.to.be.undefined;
+      });
+    });
+
     describe("after adding a mint as a member", () => {
       const mintAuthPublicKey = wallet.publicKey;
       const mintTokenAccount = getAssociatedTokenAddressSync(
@@ -776,6 +1433,7 @@ describe("wen_new_standard", () => {
           const createMintAccountIx = await program.methods
             .createMintAccount({
               permanentDelegate: null,
+              soulbound: false,
               name: faker.lorem.text(),
               symbol: faker.lorem.word(),
               uri: faker.internet.url(),
@@ -800,12 +1458,18 @@ describe("wen_new_standard", () => {
               authority: groupAuthorityPublicKey,
               group,
               mint: mintPublicKey,
+              subGroup: null,
+              assetAuthority: mintAuthPublicKey,
+              assetTokenAccount: null,
               payer: mintAuthPublicKey,
               manager,
               member,
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], program.programId),
+            )
             .preInstructions([createMintAccountIx])
             .signers([mintKeyPair, groupAuthorityKeyPair])
             .rpc({
@@ -854,64 +1518,195 @@ describe("wen_new_standard", () => {
           expect(groupAccount.size).to.eql(1);
         });
       });
-    });
-
-    describe("after trying to add another mint as a member", () => {
-      const mintKeyPair = Keypair.generate();
-
-      const mintAuthPublicKey = wallet.publicKey;
-      const mintPublicKey = mintKeyPair.publicKey;
-      const mintTokenAccount = getAssociatedTokenAddressSync(
-        mintPublicKey,
-        mintAuthPublicKey,
-        false,
-        TOKEN_2022_PROGRAM_ID,
-      );
-
-      const [member] = PublicKey.findProgramAddressSync(
-        [MEMBER_ACCOUNT_SEED, mintPublicKey.toBuffer()],
-        program.programId,
-      );
-      let logs: string[];
-      let errorCode: { code: string; number: number };
-
-      before(async () => {
-        const createMintAccountIx = await program.methods
-          .createMintAccount({
-            permanentDelegate: null,
-            name: faker.lorem.text(),
-            symbol: faker.lorem.word(),
-            uri: faker.internet.url(),
-          })
-          .accountsStrict({
-            authority: mintAuthPublicKey,
-            mint: mintPublicKey,
-            mintTokenAccount,
-            payer: mintAuthPublicKey,
-            receiver: mintAuthPublicKey,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            manager,
-
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_2022_PROGRAM_ID,
-          })
-          .instruction();
 
-        try {
-          await program.methods
-            .addMintToGroup()
+      describe("after updating the member", () => {
+        const name = faker.lorem.words({ max: 3, min: 2 });
+        const symbol = faker.lorem.word();
+        const uri = faker.internet.url();
+
+        let missingGroupError;
+        let missingGroupAuthorityError;
+        let metadata: TokenMetadata;
+        let ungatedMetadata: TokenMetadata;
+
+        const updateMember = (
+          args: { name: string; symbol: string; uri: string },
+          groupAuthority: PublicKey | null,
+        ) =>
+          program.methods
+            .updateMintAccount(args)
             .accountsStrict({
-              authority: groupAuthorityPublicKey,
-              group,
-              mint: mintPublicKey,
               payer: mintAuthPublicKey,
-              manager,
+              authority: mintAuthPublicKey,
+              mint: mintPublicKey,
+              groupMint: groupMintPublicKey,
+              group,
               member,
+              groupAuthority,
+              manager,
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
-            .preInstructions([createMintAccountIx])
-            .signers([mintKeyPair, groupAuthorityKeyPair])
+            .signers(groupAuthority ? [groupAuthorityKeyPair] : [])
+            .rpc({ commitment: "confirmed" });
+
+        before(async () => {
+          try {
+            await program.methods
+              .updateMintAccount({ name, symbol, uri })
+              .accountsStrict({
+                payer: mintAuthPublicKey,
+                authority: mintAuthPublicKey,
+                mint: mintPublicKey,
+                groupMint: null,
+                group: null,
+                member: null,
+                groupAuthority: null,
+                manager,
+                systemProgram: SystemProgram.programId,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+              })
+              .rpc({ commitment: "confirmed" });
+          } catch (err) {
+            missingGroupError = err;
+          }
+
+          try {
+            await updateMember({ name, symbol, uri }, null);
+          } catch (err) {
+            missingGroupAuthorityError = err;
+          }
+
+          await updateMember({ name, symbol, uri }, groupAuthorityPublicKey);
+
+          metadata = await getTokenMetadata(
+            connection,
+            mintPublicKey,
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID,
+          );
+
+          // lift the gating, keeping the group metadata as is
+          const groupMetadata = await getTokenMetadata(
+            connection,
+            groupMintPublicKey,
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID,
+          );
+          await program.methods
+            .updateGroupAccount({
+              name: groupMetadata.name,
+              symbol: groupMetadata.symbol,
+              uri: groupMetadata.uri,
+              maxSize: createGroupArgs.maxSize,
+              lockMaxSize: false,
+              advisoryMemberUpdateGate: false,
+            })
+            .accountsStrict({
+              payer: mintAuthPublicKey,
+              authority: groupAuthorityPublicKey,
+              group,
+              mint: groupMintPublicKey,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([groupAuthorityKeyPair])
+            .rpc({ commitment: "confirmed" });
+
+          await updateMember({ name: symbol, symbol, uri }, null);
+
+          ungatedMetadata = await getTokenMetadata(
+            connection,
+            mintPublicKey,
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID,
+          );
+        });
+
+        it("should require the group accounts", () => {
+          expect(missingGroupError).not.to.be.undefined;
+        });
+
+        it("should require the group authority of a gated group", () => {
+          expect(missingGroupAuthorityError).not.to.be.undefined;
+        });
+
+        it("should have updated name, symbol and uri", () => {
+          expect(metadata.name).to.eql(name);
+          expect(metadata.symbol).to.eql(symbol);
+          expect(metadata.uri).to.eql(uri);
+        });
+
+        it("should not require the group authority once ungated", () => {
+          expect(ungatedMetadata.name).to.eql(symbol);
+        });
+      });
+    });
+
+    describe("after trying to add another mint as a member", () => {
+      const mintKeyPair = Keypair.generate();
+
+      const mintAuthPublicKey = wallet.publicKey;
+      const mintPublicKey = mintKeyPair.publicKey;
+      const mintTokenAccount = getAssociatedTokenAddressSync(
+        mintPublicKey,
+        mintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const [member] = PublicKey.findProgramAddressSync(
+        [MEMBER_ACCOUNT_SEED, mintPublicKey.toBuffer()],
+        program.programId,
+      );
+      let logs: string[];
+      let errorCode: { code: string; number: number };
+
+      before(async () => {
+        const createMintAccountIx = await program.methods
+          .createMintAccount({
+            permanentDelegate: null,
+            soulbound: false,
+            name: faker.lorem.text(),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority: mintAuthPublicKey,
+            mint: mintPublicKey,
+            mintTokenAccount,
+            payer: mintAuthPublicKey,
+            receiver: mintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            manager,
+
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .instruction();
+
+        try {
+          await program.methods
+            .addMintToGroup()
+            .accountsStrict({
+              authority: groupAuthorityPublicKey,
+              group,
+              mint: mintPublicKey,
+              subGroup: null,
+              assetAuthority: mintAuthPublicKey,
+              assetTokenAccount: null,
+              payer: mintAuthPublicKey,
+              manager,
+              member,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], program.programId),
+            )
+            .preInstructions([createMintAccountIx])
+            .signers([mintKeyPair, groupAuthorityKeyPair])
             .rpc({
               skipPreflight: true,
               preflightCommitment: "confirmed",
@@ -954,12 +1749,16 @@ describe("wen_new_standard", () => {
               authority: groupAuthorityPublicKey,
               group,
               mint: mintPublicKey,
+              subGroup: null,
               payer: mintAuthPublicKey,
               manager,
               member,
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], program.programId),
+            )
             .signers([groupAuthorityKeyPair])
             .rpc({
               skipPreflight: true,
@@ -988,5 +1787,1514 @@ describe("wen_new_standard", () => {
         });
       });
     });
+
+    describe("after burning a member", () => {
+      const mintKeyPair = Keypair.generate();
+      const mintPublicKey = mintKeyPair.publicKey;
+
+      const mintAuthPublicKey = wallet.publicKey;
+      const mintTokenAccount = getAssociatedTokenAddressSync(
+        mintPublicKey,
+        mintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const [member] = PublicKey.findProgramAddressSync(
+        [MEMBER_ACCOUNT_SEED, mintPublicKey.toBuffer()],
+        program.programId,
+      );
+
+      let groupAccount;
+      let memberAccountInfo: AccountInfo<Buffer>;
+
+      before(async () => {
+        const createMintAccountIx = await program.methods
+          .createMintAccount({
+            permanentDelegate: null,
+            soulbound: false,
+            name: faker.lorem.text(),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority: mintAuthPublicKey,
+            mint: mintPublicKey,
+            mintTokenAccount,
+            payer: mintAuthPublicKey,
+            receiver: mintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            manager,
+
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .instruction();
+
+        await program.methods
+          .addMintToGroup()
+          .accountsStrict({
+            authority: groupAuthorityPublicKey,
+            group,
+            mint: mintPublicKey,
+            subGroup: null,
+            assetAuthority: mintAuthPublicKey,
+            assetTokenAccount: null,
+            payer: mintAuthPublicKey,
+            manager,
+            member,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry([groupMintPublicKey], program.programId),
+          )
+          .preInstructions([createMintAccountIx])
+          .signers([mintKeyPair, groupAuthorityKeyPair])
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        await program.methods
+          .burnMintAccount()
+          .accountsStrict({
+            mint: mintPublicKey,
+            mintTokenAccount,
+            payer: mintAuthPublicKey,
+            user: mintAuthPublicKey,
+            manager,
+            group,
+            member,
+            subGroup: null,
+            approveAccount: getApproveAccountPda(
+              mintPublicKey,
+              program.programId,
+            ),
+            extraMetasAccount: getExtraMetasAccountPda(
+              mintPublicKey,
+              program.programId,
+            ),
+            royaltyConfig: getRoyaltyConfigPda(
+              mintPublicKey,
+              program.programId,
+            ),
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry([groupMintPublicKey], program.programId),
+          )
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        groupAccount = await program.account.tokenGroup.fetch(
+          group,
+          "confirmed",
+        );
+        memberAccountInfo = await connection.getAccountInfo(
+          member,
+          "confirmed",
+        );
+      });
+
+      it("should decrease the group size", async () => {
+        expect(groupAccount.size).to.eql(0);
+      });
+      it("should close the member account", async () => {
+        expect(memberAccountInfo).to.be.null;
+      });
+    });
+
+    describe("after migrating to token group extensions", () => {
+      const mintKeyPair = Keypair.generate();
+      const mintPublicKey = mintKeyPair.publicKey;
+
+      const mintAuthPublicKey = wallet.publicKey;
+      const mintTokenAccount = getAssociatedTokenAddressSync(
+        mintPublicKey,
+        mintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const [member] = PublicKey.findProgramAddressSync(
+        [MEMBER_ACCOUNT_SEED, mintPublicKey.toBuffer()],
+        program.programId,
+      );
+
+      let groupMint: Mint;
+      let memberMint: Mint;
+      let memberMetadata: TokenMetadata;
+      let legacyMemberNumber: number;
+      let halfMigratedGroupAccount;
+      let groupAccountInfo: AccountInfo<Buffer>;
+      let memberAccountInfo: AccountInfo<Buffer>;
+
+      before(async () => {
+        const createMintAccountIx = await program.methods
+          .createMintAccount({
+            permanentDelegate: null,
+            soulbound: false,
+            name: faker.lorem.text(),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority: mintAuthPublicKey,
+            mint: mintPublicKey,
+            mintTokenAccount,
+            payer: mintAuthPublicKey,
+            receiver: mintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            manager,
+
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .instruction();
+
+        await program.methods
+          .addMintToGroup()
+          .accountsStrict({
+            authority: groupAuthorityPublicKey,
+            group,
+            mint: mintPublicKey,
+            subGroup: null,
+            assetAuthority: mintAuthPublicKey,
+            assetTokenAccount: null,
+            payer: mintAuthPublicKey,
+            manager,
+            member,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry([groupMintPublicKey], program.programId),
+          )
+          .preInstructions([createMintAccountIx])
+          .signers([mintKeyPair, groupAuthorityKeyPair])
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        const legacyMemberAccount =
+          await program.account.tokenGroupMember.fetch(member, "confirmed");
+        legacyMemberNumber = legacyMemberAccount.memberNumber;
+
+        await program.methods
+          .migrateGroup()
+          .accountsStrict({
+            authority: groupAuthorityPublicKey,
+            group,
+            mint: groupMintPublicKey,
+            payer,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([groupAuthorityKeyPair])
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        halfMigratedGroupAccount = await program.account.tokenGroup.fetch(
+          group,
+          "confirmed",
+        );
+
+        await program.methods
+          .migrateMember()
+          .accountsStrict({
+            authority: groupAuthorityPublicKey,
+            group,
+            groupMint: groupMintPublicKey,
+            member,
+            mint: mintPublicKey,
+            metadataAuthority: mintAuthPublicKey,
+            payer,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([groupAuthorityKeyPair])
+          .rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+        groupMint = await getMint(
+          connection,
+          groupMintPublicKey,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+        memberMint = await getMint(
+          connection,
+          mintPublicKey,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+        memberMetadata = await getTokenMetadata(
+          connection,
+          mintPublicKey,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+        groupAccountInfo = await connection.getAccountInfo(group, "confirmed");
+        memberAccountInfo = await connection.getAccountInfo(
+          member,
+          "confirmed",
+        );
+      });
+
+      describe("the group", () => {
+        it("should point to itself", async () => {
+          expect(
+            getGroupPointerState(groupMint).groupAddress.toBase58(),
+          ).to.eql(groupMintPublicKey.toBase58());
+        });
+        it("should have the group extension", async () => {
+          const groupState = getTokenGroupState(groupMint);
+          expect(groupState.updateAuthority.toBase58()).to.eql(
+            groupAuthorityPublicKey.toBase58(),
+          );
+          expect(groupState.maxSize.toString()).to.eql(
+            createGroupArgs.maxSize.toString(),
+          );
+          expect(groupState.size.toString()).to.eql("1");
+        });
+        it("should have no mint authority", async () => {
+          expect(groupMint.mintAuthority).to.be.null;
+        });
+        it("should keep the legacy group while migrating", async () => {
+          expect(halfMigratedGroupAccount.size).to.eql(1);
+        });
+        it("should close the legacy group account", async () => {
+          expect(groupAccountInfo).to.be.null;
+        });
+      });
+
+      describe("the member", () => {
+        it("should point to itself", async () => {
+          expect(
+            getGroupMemberPointerState(memberMint).memberAddress.toBase58(),
+          ).to.eql(mintPublicKey.toBase58());
+        });
+        it("should have the group member extension", async () => {
+          const memberState = getTokenGroupMemberState(memberMint);
+          expect(memberState.group.toBase58()).to.eql(
+            groupMintPublicKey.toBase58(),
+          );
+          expect(memberState.memberNumber.toString()).to.eql("1");
+        });
+        it("should have no mint authority", async () => {
+          expect(memberMint.mintAuthority).to.be.null;
+        });
+        it("should keep the legacy member number in its metadata", async () => {
+          expect(
+            memberMetadata.additionalMetadata.find(
+              ([key]) => key === "member_number",
+            )[1],
+          ).to.eql(legacyMemberNumber.toString());
+        });
+        it("should close the legacy member account", async () => {
+          expect(memberAccountInfo).to.be.null;
+        });
+      });
+    });
+  });
+
+  describe("group authority", () => {
+    const groupAuthorityKeyPair = Keypair.generate();
+    const groupAuthorityPublicKey = groupAuthorityKeyPair.publicKey;
+    const newAuthorityKeyPair = Keypair.generate();
+    const newAuthorityPublicKey = newAuthorityKeyPair.publicKey;
+    const otherKeyPair = Keypair.generate();
+
+    const groupMintKeyPair = Keypair.generate();
+    const groupMintPublicKey = groupMintKeyPair.publicKey;
+
+    const [group] = PublicKey.findProgramAddressSync(
+      [GROUP_ACCOUNT_SEED, groupMintPublicKey.toBuffer()],
+      program.programId,
+    );
+    const [proposal] = PublicKey.findProgramAddressSync(
+      [GROUP_AUTHORITY_PROPOSAL_SEED, groupMintPublicKey.toBuffer()],
+      program.programId,
+    );
+
+    const proposeGroupAuthority = () =>
+      program.methods
+        .proposeGroupAuthority(newAuthorityPublicKey)
+        .accountsStrict({
+          payer,
+          authority: groupAuthorityPublicKey,
+          mint: groupMintPublicKey,
+          group,
+          proposal,
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([groupAuthorityKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+    const fetchAuthorities = async () => {
+      const groupAccount = await program.account.tokenGroup.fetch(
+        group,
+        "confirmed",
+      );
+      const metadata = await getTokenMetadata(
+        connection,
+        groupMintPublicKey,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID,
+      );
+      return {
+        groupAuthority: groupAccount.updateAuthority.toBase58(),
+        metadataAuthority: metadata.updateAuthority.toBase58(),
+      };
+    };
+
+    before(async () => {
+      await program.methods
+        .createGroupAccount({
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+          maxSize: 1,
+          lockMaxSize: false,
+          advisoryMemberUpdateGate: false,
+        })
+        .accountsStrict({
+          mintTokenAccount: getAssociatedTokenAddressSync(
+            groupMintPublicKey,
+            groupAuthorityPublicKey,
+            true,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+          mint: groupMintPublicKey,
+          authority: groupAuthorityPublicKey,
+          receiver: groupAuthorityPublicKey,
+          group,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          payer,
+          manager,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([groupMintKeyPair, groupAuthorityKeyPair])
+        .rpc({ commitment: "confirmed" });
+    });
+
+    describe("after proposing a new authority", () => {
+      let proposalAccount;
+      let authorities;
+      let wrongSignerError;
+
+      before(async () => {
+        await proposeGroupAuthority();
+
+        try {
+          await program.methods
+            .acceptGroupAuthority()
+            .accountsStrict({
+              newAuthority: otherKeyPair.publicKey,
+              authority: groupAuthorityPublicKey,
+              mint: groupMintPublicKey,
+              group,
+              proposal,
+              manager,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([otherKeyPair])
+            .rpc({ commitment: "confirmed" });
+        } catch (err) {
+          wrongSignerError = err;
+        }
+
+        proposalAccount = await program.account.groupAuthorityProposal.fetch(
+          proposal,
+          "confirmed",
+        );
+        authorities = await fetchAuthorities();
+      });
+
+      it("should store the pending authority", () => {
+        expect(proposalAccount.pendingAuthority.toBase58()).to.eql(
+          newAuthorityPublicKey.toBase58(),
+        );
+        expect(proposalAccount.authority.toBase58()).to.eql(
+          groupAuthorityPublicKey.toBase58(),
+        );
+      });
+
+      it("should hold the group and metadata authority in the proposal", () => {
+        expect(authorities.groupAuthority).to.eql(proposal.toBase58());
+        expect(authorities.metadataAuthority).to.eql(proposal.toBase58());
+      });
+
+      it("should only be accepted by the pending authority", () => {
+        expect(wrongSignerError).not.to.be.undefined;
+      });
+    });
+
+    describe("after cancelling", () => {
+      let authorities;
+      let proposalAccountInfo: AccountInfo<Buffer>;
+      let wrongSignerError;
+
+      const cancelGroupAuthority = (authority: Keypair) =>
+        program.methods
+          .cancelGroupAuthority()
+          .accountsStrict({
+            authority: authority.publicKey,
+            mint: groupMintPublicKey,
+            group,
+            proposal,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc({ commitment: "confirmed" });
+
+      before(async () => {
+        try {
+          await cancelGroupAuthority(otherKeyPair);
+        } catch (err) {
+          wrongSignerError = err;
+        }
+
+        await cancelGroupAuthority(groupAuthorityKeyPair);
+
+        authorities = await fetchAuthorities();
+        proposalAccountInfo = await connection.getAccountInfo(
+          proposal,
+          "confirmed",
+        );
+      });
+
+      it("should only be cancelled by the proposer", () => {
+        expect(wrongSignerError).not.to.be.undefined;
+      });
+
+      it("should hand the group and metadata authority back", () => {
+        expect(authorities.groupAuthority).to.eql(
+          groupAuthorityPublicKey.toBase58(),
+        );
+        expect(authorities.metadataAuthority).to.eql(
+          groupAuthorityPublicKey.toBase58(),
+        );
+      });
+
+      it("should close the proposal", () => {
+        expect(proposalAccountInfo).to.be.null;
+      });
+    });
+
+    describe("after accepting", () => {
+      let authorities;
+      let proposalAccountInfo: AccountInfo<Buffer>;
+
+      before(async () => {
+        await proposeGroupAuthority();
+
+        await program.methods
+          .acceptGroupAuthority()
+          .accountsStrict({
+            newAuthority: newAuthorityPublicKey,
+            authority: groupAuthorityPublicKey,
+            mint: groupMintPublicKey,
+            group,
+            proposal,
+            manager,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([newAuthorityKeyPair])
+          .rpc({ commitment: "confirmed" });
+
+        authorities = await fetchAuthorities();
+        proposalAccountInfo = await connection.getAccountInfo(
+          proposal,
+          "confirmed",
+        );
+      });
+
+      it("should rotate the group update authority", () => {
+        expect(authorities.groupAuthority).to.eql(
+          newAuthorityPublicKey.toBase58(),
+        );
+      });
+
+      it("should rotate the metadata update authority", () => {
+        expect(authorities.metadataAuthority).to.eql(
+          newAuthorityPublicKey.toBase58(),
+        );
+      });
+
+      it("should close the proposal", () => {
+        expect(proposalAccountInfo).to.be.null;
+      });
+    });
+  });
+
+  describe("group membership", () => {
+    const groupMintKeyPair = Keypair.generate();
+    const groupMintPublicKey = groupMintKeyPair.publicKey;
+    const mintKeyPair = Keypair.generate();
+    const mintPublicKey = mintKeyPair.publicKey;
+    const holder = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    const [group] = PublicKey.findProgramAddressSync(
+      [GROUP_ACCOUNT_SEED, groupMintPublicKey.toBuffer()],
+      program.programId,
+    );
+    const [member] = PublicKey.findProgramAddressSync(
+      [MEMBER_ACCOUNT_SEED, mintPublicKey.toBuffer()],
+      program.programId,
+    );
+    const holderTokenAccount = getAssociatedTokenAddressSync(
+      mintPublicKey,
+      holder.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    let strangerError;
+    let memberAccount;
+
+    before(async () => {
+      await program.methods
+        .createGroupAccount({
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+          maxSize: 2,
+          lockMaxSize: false,
+          advisoryMemberUpdateGate: false,
+        })
+        .accountsStrict({
+          mintTokenAccount: getAssociatedTokenAddressSync(
+            groupMintPublicKey,
+            payer,
+            false,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+          mint: groupMintPublicKey,
+          authority: payer,
+          receiver: payer,
+          group,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          payer,
+          manager,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([groupMintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+      await program.methods
+        .createMintAccount({
+          permanentDelegate: null,
+          soulbound: false,
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+        })
+        .accountsStrict({
+          authority: payer,
+          mint: mintPublicKey,
+          mintTokenAccount: holderTokenAccount,
+          payer,
+          receiver: holder.publicKey,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([mintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+      try {
+        await program.methods
+          .addMintToGroup()
+          .accountsStrict({
+            authority: payer,
+            group,
+            mint: mintPublicKey,
+            subGroup: null,
+            assetAuthority: stranger.publicKey,
+            assetTokenAccount: null,
+            payer,
+            manager,
+            member,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry([groupMintPublicKey], program.programId),
+          )
+          .signers([stranger])
+          .rpc({ commitment: "confirmed" });
+      } catch (err) {
+        strangerError = err;
+      }
+
+      await program.methods
+        .addMintToGroup()
+        .accountsStrict({
+          authority: payer,
+          group,
+          mint: mintPublicKey,
+          subGroup: null,
+          assetAuthority: holder.publicKey,
+          assetTokenAccount: holderTokenAccount,
+          payer,
+          manager,
+          member,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          getGroupAncestry([groupMintPublicKey], program.programId),
+        )
+        .signers([holder])
+        .rpc({ commitment: "confirmed" });
+
+      memberAccount = await program.account.tokenGroupMember.fetch(
+        member,
+        "confirmed",
+      );
+    });
+
+    it("should require the consent of the asset", () => {
+      expect(strangerError).not.to.be.undefined;
+    });
+
+    it("should accept the consent of the holder", () => {
+      expect(memberAccount.mint.toBase58()).to.eql(mintPublicKey.toBase58());
+      expect(memberAccount.group.toBase58()).to.eql(group.toBase58());
+    });
+
+    describe("after transferring to another group", () => {
+      const newGroupMintKeyPair = Keypair.generate();
+      const newGroupMintPublicKey = newGroupMintKeyPair.publicKey;
+      const newAuthority = Keypair.generate();
+
+      const [newGroup] = PublicKey.findProgramAddressSync(
+        [GROUP_ACCOUNT_SEED, newGroupMintPublicKey.toBuffer()],
+        program.programId,
+      );
+
+      let transferEvent;
+      let transferredMemberAccount;
+      let groupAccount;
+      let newGroupAccount;
+
+      before(async () => {
+        await program.methods
+          .createGroupAccount({
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+            maxSize: 2,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
+          })
+          .accountsStrict({
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              newGroupMintPublicKey,
+              payer,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            mint: newGroupMintPublicKey,
+            authority: newAuthority.publicKey,
+            receiver: payer,
+            group: newGroup,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            payer,
+            manager,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([newGroupMintKeyPair, newAuthority])
+          .rpc({ commitment: "confirmed" });
+
+        const signature = await program.methods
+          .transferMember()
+          .accountsStrict({
+            authority: payer,
+            newAuthority: newAuthority.publicKey,
+            group,
+            newGroup,
+            member,
+            mint: mintPublicKey,
+            subGroup: null,
+            assetAuthority: holder.publicKey,
+            assetTokenAccount: holderTokenAccount,
+            manager,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry([groupMintPublicKey], program.programId).concat(
+              getGroupAncestry([newGroupMintPublicKey], program.programId),
+            ),
+          )
+          .signers([newAuthority, holder])
+          .rpc({ commitment: "confirmed" });
+
+        const transaction = await connection.getTransaction(signature, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        const eventParser = new anchor.EventParser(
+          program.programId,
+          program.coder,
+        );
+        [transferEvent] = [
+          ...eventParser.parseLogs(transaction.meta.logMessages),
+        ];
+
+        transferredMemberAccount =
+          await program.account.tokenGroupMember.fetch(member, "confirmed");
+        groupAccount = await program.account.tokenGroup.fetch(
+          group,
+          "confirmed",
+        );
+        newGroupAccount = await program.account.tokenGroup.fetch(
+          newGroup,
+          "confirmed",
+        );
+      });
+
+      it("should move the member to the new group", () => {
+        expect(transferredMemberAccount.group.toBase58()).to.eql(
+          newGroup.toBase58(),
+        );
+        expect(transferredMemberAccount.memberNumber).to.eql(1);
+      });
+
+      it("should update the size of both groups", () => {
+        expect(groupAccount.size).to.eql(0);
+        expect(newGroupAccount.size).to.eql(1);
+      });
+
+      it("should emit the old and new member numbers", () => {
+        expect(transferEvent.data.mint.toBase58()).to.eql(
+          mintPublicKey.toBase58(),
+        );
+        expect(transferEvent.data.oldGroup.toBase58()).to.eql(
+          group.toBase58(),
+        );
+        expect(transferEvent.data.newGroup.toBase58()).to.eql(
+          newGroup.toBase58(),
+        );
+        expect(transferEvent.data.oldMemberNumber).to.eql(
+          memberAccount.memberNumber,
+        );
+        expect(transferEvent.data.newMemberNumber).to.eql(1);
+      });
+    });
+  });
+
+  describe("nested groups", () => {
+    const franchiseMintKeyPair = Keypair.generate();
+    const franchiseMintPublicKey = franchiseMintKeyPair.publicKey;
+    const seasonMintKeyPair = Keypair.generate();
+    const seasonMintPublicKey = seasonMintKeyPair.publicKey;
+
+    const franchise = getGroupAccountPda(
+      franchiseMintPublicKey,
+      program.programId,
+    );
+    const season = getGroupAccountPda(seasonMintPublicKey, program.programId);
+
+    const createGroup = (mintKeyPair: Keypair) =>
+      program.methods
+        .createGroupAccount({
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+          maxSize: 10,
+          lockMaxSize: false,
+          advisoryMemberUpdateGate: false,
+        })
+        .accountsStrict({
+          mintTokenAccount: getAssociatedTokenAddressSync(
+            mintKeyPair.publicKey,
+            payer,
+            false,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+          mint: mintKeyPair.publicKey,
+          authority: payer,
+          receiver: payer,
+          group: getGroupAccountPda(mintKeyPair.publicKey, program.programId),
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          payer,
+          manager,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([mintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+    const addItem = async (groupMints: PublicKey[]) => {
+      const mintKeyPair = Keypair.generate();
+      await program.methods
+        .createMintAccount({
+          permanentDelegate: null,
+          soulbound: false,
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+        })
+        .accountsStrict({
+          authority: payer,
+          mint: mintKeyPair.publicKey,
+          mintTokenAccount: getAssociatedTokenAddressSync(
+            mintKeyPair.publicKey,
+            payer,
+            false,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+          payer,
+          receiver: payer,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([mintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+      await program.methods
+        .addMintToGroup()
+        .accountsStrict({
+          authority: payer,
+          group: getGroupAccountPda(groupMints[0], program.programId),
+          mint: mintKeyPair.publicKey,
+          subGroup: null,
+          assetAuthority: payer,
+          assetTokenAccount: null,
+          payer,
+          manager,
+          member: getMemberAccountPda(mintKeyPair.publicKey, program.programId),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(getGroupAncestry(groupMints, program.programId))
+        .rpc({ commitment: "confirmed" });
+    };
+
+    let seasonMemberAccount;
+    let franchiseAccount;
+    let seasonAccount;
+    let circularError;
+
+    before(async () => {
+      await createGroup(franchiseMintKeyPair);
+      await createGroup(seasonMintKeyPair);
+      await addItem([seasonMintPublicKey]);
+
+      await program.methods
+        .addMintToGroup()
+        .accountsStrict({
+          authority: payer,
+          group: franchise,
+          mint: seasonMintPublicKey,
+          subGroup: season,
+          assetAuthority: payer,
+          assetTokenAccount: null,
+          payer,
+          manager,
+          member: getMemberAccountPda(seasonMintPublicKey, program.programId),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          getGroupAncestry([franchiseMintPublicKey], program.programId),
+        )
+        .rpc({ commitment: "confirmed" });
+
+      await addItem([seasonMintPublicKey, franchiseMintPublicKey]);
+
+      try {
+        await program.methods
+          .addMintToGroup()
+          .accountsStrict({
+            authority: payer,
+            group: season,
+            mint: franchiseMintPublicKey,
+            subGroup: franchise,
+            assetAuthority: payer,
+            assetTokenAccount: null,
+            payer,
+            manager,
+            member: getMemberAccountPda(
+              franchiseMintPublicKey,
+              program.programId,
+            ),
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry(
+              [seasonMintPublicKey, franchiseMintPublicKey],
+              program.programId,
+            ),
+          )
+          .rpc({ commitment: "confirmed" });
+      } catch (err) {
+        circularError = err;
+      }
+
+      seasonMemberAccount = await program.account.tokenGroupMember.fetch(
+        getMemberAccountPda(seasonMintPublicKey, program.programId),
+        "confirmed",
+      );
+      franchiseAccount = await program.account.tokenGroup.fetch(
+        franchise,
+        "confirmed",
+      );
+      seasonAccount = await program.account.tokenGroup.fetch(
+        season,
+        "confirmed",
+      );
+    });
+
+    it("should add the sub group as a member of the parent group", () => {
+      expect(seasonMemberAccount.group.toBase58()).to.eql(franchise.toBase58());
+      expect(seasonMemberAccount.memberNumber).to.eql(1);
+    });
+
+    it("should roll the size of the sub group up into the parent group", () => {
+      expect(seasonAccount.size).to.eql(2);
+      expect(franchiseAccount.size).to.eql(3);
+    });
+
+    it("should reject nesting a group into its own sub group", () => {
+      expect(circularError).not.to.be.undefined;
+    });
+
+    describe("after burning the sub group mint", () => {
+      before(async () => {
+        await program.methods
+          .burnMintAccount()
+          .accountsStrict({
+            mint: seasonMintPublicKey,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              seasonMintPublicKey,
+              payer,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            payer,
+            user: payer,
+            manager,
+            group: franchise,
+            member: getMemberAccountPda(seasonMintPublicKey, program.programId),
+            subGroup: season,
+            approveAccount: getApproveAccountPda(
+              seasonMintPublicKey,
+              program.programId,
+            ),
+            extraMetasAccount: getExtraMetasAccountPda(
+              seasonMintPublicKey,
+              program.programId,
+            ),
+            royaltyConfig: getRoyaltyConfigPda(
+              seasonMintPublicKey,
+              program.programId,
+            ),
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc({ commitment: "confirmed" });
+
+        franchiseAccount = await program.account.tokenGroup.fetch(
+          franchise,
+          "confirmed",
+        );
+      });
+
+      it("should remove the sub group and its members from the parent group", () => {
+        expect(franchiseAccount.size).to.eql(0);
+      });
+    });
+  });
+
+  describe("group max size", () => {
+    const groupMintKeyPair = Keypair.generate();
+    const groupMintPublicKey = groupMintKeyPair.publicKey;
+    const group = getGroupAccountPda(groupMintPublicKey, program.programId);
+
+    const updateGroup = (maxSize: number | null, lockMaxSize: boolean) =>
+      program.methods
+        .updateGroupAccount({
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+          maxSize,
+          lockMaxSize,
+          advisoryMemberUpdateGate: false,
+        })
+        .accountsStrict({
+          payer,
+          authority: payer,
+          group,
+          mint: groupMintPublicKey,
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+
+    let unboundedGroupAccount;
+    let lockedGroupAccount;
+    let lockedError;
+
+    before(async () => {
+      await program.methods
+        .createGroupAccount({
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+          maxSize: null,
+          lockMaxSize: false,
+          advisoryMemberUpdateGate: false,
+        })
+        .accountsStrict({
+          mintTokenAccount: getAssociatedTokenAddressSync(
+            groupMintPublicKey,
+            payer,
+            false,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+          mint: groupMintPublicKey,
+          authority: payer,
+          receiver: payer,
+          group,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          payer,
+          manager,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([groupMintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+      unboundedGroupAccount = await program.account.tokenGroup.fetch(
+        group,
+        "confirmed",
+      );
+
+      await updateGroup(5, true);
+      // the locked max size can still be passed along with metadata updates
+      await updateGroup(5, false);
+      try {
+        await updateGroup(6, false);
+      } catch (err) {
+        lockedError = err;
+      }
+
+      lockedGroupAccount = await program.account.tokenGroup.fetch(
+        group,
+        "confirmed",
+      );
+    });
+
+    it("should create an unbounded group", () => {
+      expect(unboundedGroupAccount.maxSize).to.eql(4294967295);
+      expect(unboundedGroupAccount.maxSizeLocked).to.be.false;
+    });
+
+    it("should lock the max size", () => {
+      expect(lockedGroupAccount.maxSize).to.eql(5);
+      expect(lockedGroupAccount.maxSizeLocked).to.be.true;
+    });
+
+    it("should reject changing a locked max size", () => {
+      expect(lockedError).not.to.be.undefined;
+    });
+  });
+
+  describe("soulbound assets", () => {
+    const mintKeyPair = Keypair.generate();
+    const mintPublicKey = mintKeyPair.publicKey;
+    const holder = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    const holderTokenAccount = getAssociatedTokenAddressSync(
+      mintPublicKey,
+      holder.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const strangerTokenAccount = getAssociatedTokenAddressSync(
+      mintPublicKey,
+      stranger.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const extraMetasAccount = getExtraMetasAccountPda(
+      mintPublicKey,
+      program.programId,
+    );
+    const royaltyConfig = getRoyaltyConfigPda(mintPublicKey, program.programId);
+
+    let mint: Mint;
+    let transferError;
+    let royaltiesError;
+    let modifyRoyaltiesError;
+    let mintAccountInfo: AccountInfo<Buffer>;
+
+    before(async () => {
+      await program.methods
+        .createMintAccount({
+          permanentDelegate: null,
+          soulbound: true,
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+        })
+        .accountsStrict({
+          authority: payer,
+          mint: mintPublicKey,
+          mintTokenAccount: holderTokenAccount,
+          payer,
+          receiver: holder.publicKey,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([mintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+      mint = await getMint(
+        connection,
+        mintPublicKey,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      try {
+        await sendAndConfirmWNSTransaction(
+          connection,
+          [
+            createAssociatedTokenAccountInstruction(
+              payer,
+              strangerTokenAccount,
+              stranger.publicKey,
+              mintPublicKey,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            createTransferCheckedInstruction(
+              holderTokenAccount,
+              mintPublicKey,
+              strangerTokenAccount,
+              holder.publicKey,
+              1,
+              0,
+              [],
+              TOKEN_2022_PROGRAM_ID,
+            ),
+          ],
+          provider,
+          false,
+          [holder],
+        );
+      } catch (err) {
+        transferError = err;
+      }
+
+      try {
+        await program.methods
+          .addRoyalties({
+            creators: [{ address: payer, share: 10_000 }],
+            royaltyBasisPoints: 500,
+          })
+          .accountsStrict({
+            authority: payer,
+            mint: mintPublicKey,
+            payer,
+            extraMetasAccount,
+            royaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc({ commitment: "confirmed" });
+      } catch (err) {
+        royaltiesError = err;
+      }
+
+      try {
+        await program.methods
+          .modifyRoyalties({
+            creators: [{ address: payer, share: 10_000 }],
+            royaltyBasisPoints: 500,
+          })
+          .accountsStrict({
+            authority: payer,
+            mint: mintPublicKey,
+            payer,
+            royaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc({ commitment: "confirmed" });
+      } catch (err) {
+        modifyRoyaltiesError = err;
+      }
+
+      await program.methods
+        .burnMintAccount()
+        .accountsStrict({
+          mint: mintPublicKey,
+          mintTokenAccount: holderTokenAccount,
+          payer,
+          user: holder.publicKey,
+          manager,
+          group: null,
+          member: null,
+          subGroup: null,
+          approveAccount: getApproveAccountPda(
+            mintPublicKey,
+            program.programId,
+          ),
+          extraMetasAccount: getExtraMetasAccountPda(
+            mintPublicKey,
+            program.programId,
+          ),
+          royaltyConfig: getRoyaltyConfigPda(mintPublicKey, program.programId),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([holder])
+        .rpc({ commitment: "confirmed" });
+
+      mintAccountInfo = await connection.getAccountInfo(
+        mintPublicKey,
+        "confirmed",
+      );
+    });
+
+    it("should create a non transferable mint without a transfer hook", () => {
+      expect(getNonTransferable(mint)).to.not.be.null;
+      expect(getTransferHook(mint)).to.be.null;
+    });
+
+    it("should reject transfers", () => {
+      expect(transferError).not.to.be.undefined;
+    });
+
+    it("should reject royalties", () => {
+      expect(royaltiesError).not.to.be.undefined;
+      expect(modifyRoyaltiesError).not.to.be.undefined;
+    });
+
+    it("should still burn the mint", () => {
+      expect(mintAccountInfo).to.be.null;
+    });
+  });
+
+  describe("clawback", () => {
+    const mintKeyPair = Keypair.generate();
+    const mintPublicKey = mintKeyPair.publicKey;
+    const holder = Keypair.generate();
+    const recovery = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    const holderTokenAccount = getAssociatedTokenAddressSync(
+      mintPublicKey,
+      holder.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const recoveryTokenAccount = getAssociatedTokenAddressSync(
+      mintPublicKey,
+      recovery.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    let strangerError;
+    let clawbackEvent;
+    let holderAccount: Account;
+    let recoveryAccount: Account;
+    let mint: Mint;
+
+    before(async () => {
+      await program.methods
+        .createMintAccount({
+          permanentDelegate: manager,
+          soulbound: false,
+          name: faker.lorem.words({ max: 3, min: 2 }),
+          symbol: faker.lorem.word(),
+          uri: faker.internet.url(),
+        })
+        .accountsStrict({
+          authority: payer,
+          mint: mintPublicKey,
+          mintTokenAccount: holderTokenAccount,
+          payer,
+          receiver: holder.publicKey,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([mintKeyPair])
+        .rpc({ commitment: "confirmed" });
+
+      await program.methods
+        .addRoyalties({
+          creators: [{ address: payer, share: 10_000 }],
+          royaltyBasisPoints: 500,
+        })
+        .accountsStrict({
+          authority: payer,
+          mint: mintPublicKey,
+          payer,
+          extraMetasAccount: getExtraMetasAccountPda(
+            mintPublicKey,
+            program.programId,
+          ),
+          royaltyConfig: getRoyaltyConfigPda(mintPublicKey, program.programId),
+          manager,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+
+      await program.methods
+        .freezeMintAccount()
+        .accountsStrict({
+          delegateAuthority: holder.publicKey,
+          mint: mintPublicKey,
+          mintTokenAccount: holderTokenAccount,
+          user: holder.publicKey,
+          manager,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .preInstructions([
+          createApproveCheckedInstruction(
+            holderTokenAccount,
+            mintPublicKey,
+            holder.publicKey,
+            holder.publicKey,
+            1,
+            0,
+            [],
+            TOKEN_2022_PROGRAM_ID,
+          ),
+        ])
+        .signers([holder])
+        .rpc({ commitment: "confirmed" });
+
+      const clawbackAccounts = {
+        payer,
+        transferHookAuthority: null,
+        user: holder.publicKey,
+        recipient: recovery.publicKey,
+        mint: mintPublicKey,
+        mintTokenAccount: holderTokenAccount,
+        recipientTokenAccount: recoveryTokenAccount,
+        manager,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      };
+
+      try {
+        await program.methods
+          .clawback()
+          .accountsStrict({
+            ...clawbackAccounts,
+            authority: stranger.publicKey,
+          })
+          .signers([stranger])
+          .rpc({ commitment: "confirmed" });
+      } catch (err) {
+        strangerError = err;
+      }
+
+      const signature = await program.methods
+        .clawback()
+        .accountsStrict({ ...clawbackAccounts, authority: payer })
+        .rpc({ commitment: "confirmed" });
+
+      const transaction = await connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const eventParser = new anchor.EventParser(
+        program.programId,
+        program.coder,
+      );
+      [clawbackEvent] = [
+        ...eventParser.parseLogs(transaction.meta.logMessages),
+      ];
+
+      holderAccount = await getAccount(
+        connection,
+        holderTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID,
+      );
+      recoveryAccount = await getAccount(
+        connection,
+        recoveryTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID,
+      );
+      mint = await getMint(
+        connection,
+        mintPublicKey,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID,
+      );
+    });
+
+    it("should only be signed by the issuer", () => {
+      expect(strangerError).not.to.be.undefined;
+    });
+
+    it("should move the frozen asset to the recovery address", () => {
+      expect(holderAccount.amount.toString()).to.eql("0");
+      expect(holderAccount.isFrozen).to.be.false;
+      expect(recoveryAccount.amount.toString()).to.eql("1");
+    });
+
+    it("should restore the transfer hook", () => {
+      expect(getTransferHook(mint).programId.toBase58()).to.eql(
+        program.programId.toBase58(),
+      );
+    });
+
+    it("should emit the clawback", () => {
+      expect(clawbackEvent.data.mint.toBase58()).to.eql(
+        mintPublicKey.toBase58(),
+      );
+      expect(clawbackEvent.data.authority.toBase58()).to.eql(payer.toBase58());
+      expect(clawbackEvent.data.from.toBase58()).to.eql(
+        holder.publicKey.toBase58(),
+      );
+      expect(clawbackEvent.data.to.toBase58()).to.eql(
+        recovery.publicKey.toBase58(),
+      );
+      expect(clawbackEvent.data.amount.toString()).to.eql("1");
+    });
   });
 });
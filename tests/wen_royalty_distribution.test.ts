@@ -16,15 +16,22 @@ import {
 import {
   airdrop,
   createMintTokenKegIx,
+  createTransferFeeMint2022Ix,
   getApproveAccountPda,
   getDistributionAccountPda,
   getExtraMetasAccountPda,
   getGroupAccountPda,
+  getGroupAncestry,
+  getGroupPath,
   getListingAccountPda,
   getManagerAccountPda,
+  getMarketplaceConfigPda,
   getMemberAccountPda,
+  getProgramDataPda,
+  getRoyaltyConfigPda,
   mintToBuyerSellerIx,
   sendAndConfirmWNSTransaction,
+  waitForBlockTime,
 } from "./utils";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -33,6 +40,10 @@ import {
   TOKEN_PROGRAM_ID,
   createAssociatedTokenAccountInstruction,
   getAccount,
+  getTransferFeeConfig,
+  getTransferHook,
+  getMint,
+  calculateFee,
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { expect } from "chai";
@@ -54,6 +65,7 @@ describe("wen_royalty_distribution", () => {
   const wenWnsMarketplaceId = wenWnsMarketplace.programId;
 
   const manager = getManagerAccountPda(wnsProgramId);
+  const marketplaceConfig = getMarketplaceConfigPda(wenWnsMarketplaceId);
   const preflightConfig: {
     skipPreflight: boolean;
     preflightCommitment: Commitment;
@@ -70,11 +82,77 @@ describe("wen_royalty_distribution", () => {
         .initManagerAccount()
         .accountsStrict({
           payer: wallet.publicKey,
+          upgradeAuthority: wallet.publicKey,
           manager,
+          program: wnsProgramId,
+          programData: getProgramDataPda(wnsProgramId),
           systemProgram: SystemProgram.programId,
         })
         .rpc(preflightConfig);
     }
+    if (!(await connection.getAccountInfo(marketplaceConfig))) {
+      await wenWnsMarketplace.methods
+        .initConfig()
+        .accountsStrict({
+          payer: wallet.publicKey,
+          upgradeAuthority: wallet.publicKey,
+          config: marketplaceConfig,
+          program: wenWnsMarketplaceId,
+          programData: getProgramDataPda(wenWnsMarketplaceId),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc(preflightConfig);
+    }
+  });
+
+  describe("the marketplace config", () => {
+    const nonAdmin = Keypair.generate();
+
+    let nonAdminError;
+    let pausedConfig;
+    let unpausedConfig;
+
+    before(async () => {
+      try {
+        await wenWnsMarketplace.methods
+          .pause()
+          .accountsStrict({
+            admin: nonAdmin.publicKey,
+            config: marketplaceConfig,
+          })
+          .signers([nonAdmin])
+          .rpc(preflightConfig);
+      } catch (err) {
+        nonAdminError = err;
+      }
+
+      await wenWnsMarketplace.methods
+        .pause()
+        .accountsStrict({ admin: wallet.publicKey, config: marketplaceConfig })
+        .rpc(preflightConfig);
+      pausedConfig = await wenWnsMarketplace.account.marketplaceConfig.fetch(
+        marketplaceConfig,
+        "confirmed",
+      );
+
+      await wenWnsMarketplace.methods
+        .unpause()
+        .accountsStrict({ admin: wallet.publicKey, config: marketplaceConfig })
+        .rpc(preflightConfig);
+      unpausedConfig = await wenWnsMarketplace.account.marketplaceConfig.fetch(
+        marketplaceConfig,
+        "confirmed",
+      );
+    });
+
+    it("should only be paused by the admin", () => {
+      expect(nonAdminError).not.to.be.undefined;
+    });
+
+    it("should be paused and unpaused by the admin", () => {
+      expect(pausedConfig.paused).to.be.true;
+      expect(unpausedConfig.paused).to.be.false;
+    });
   });
 
   describe("a sale", () => {
@@ -141,14 +219,22 @@ describe("wen_royalty_distribution", () => {
         memberMintPublickey,
         wnsProgramId,
       );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
 
       const listingAmount = new anchor.BN(2 * LAMPORTS_PER_SOL);
       const royaltyBasisPoints = 1000;
       const royalty = listingAmount
         .mul(new anchor.BN(royaltyBasisPoints))
         .div(new anchor.BN(10_000));
-      const creator1SharePct = 60;
-      const creator2SharePct = 40;
+      const creator1ShareBps = 6000;
+      const creator2ShareBps = 4000;
 
       let distributionAccountInfo: AccountInfo<Buffer>;
       let distributionAccountData;
@@ -158,6 +244,8 @@ describe("wen_royalty_distribution", () => {
         await wnsProgram.methods
           .createGroupAccount({
             maxSize: 1,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
             name,
             symbol,
             uri,
@@ -210,6 +298,9 @@ describe("wen_royalty_distribution", () => {
             .accountsStrict({
               authority: groupMintAuthPublicKey,
               mint: memberMintPublickey,
+              subGroup: null,
+              assetAuthority: memberMintAuthPublicKey,
+              assetTokenAccount: null,
               payer: groupMintAuthPublicKey,
               group,
               manager,
@@ -217,23 +308,28 @@ describe("wen_royalty_distribution", () => {
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
             .instruction(),
           wnsProgram.methods
             .addRoyalties({
               creators: [
                 {
                   address: creator1.publicKey,
-                  share: creator1SharePct,
+                  share: creator1ShareBps,
                 },
-                { address: creator2.publicKey, share: creator2SharePct },
+                { address: creator2.publicKey, share: creator2ShareBps },
               ],
               royaltyBasisPoints,
             })
             .accountsStrict({
               extraMetasAccount,
+              royaltyConfig,
               authority: memberMintAuthPublicKey,
               mint: memberMintPublickey,
               payer: groupMintAuthPublicKey,
+              manager,
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
@@ -241,7 +337,13 @@ describe("wen_royalty_distribution", () => {
         ]);
 
         await wnsProgram.methods
-          .createMintAccount({ name, symbol, permanentDelegate: null, uri })
+          .createMintAccount({
+            name,
+            symbol,
+            permanentDelegate: null,
+            soulbound: false,
+            uri,
+          })
           .accountsStrict({
             payer: groupMintAuthPublicKey,
             manager,
@@ -283,6 +385,30 @@ describe("wen_royalty_distribution", () => {
             (distributionAccountData.paymentMint as PublicKey).toBase58(),
           ).to.eql(PublicKey.default.toBase58());
         });
+
+        it("should be at the current version", () => {
+          expect(distributionAccountData.version).to.eql(2);
+        });
+
+        it("should not migrate an up to date distribution account", async () => {
+          let migrateError;
+          try {
+            await wenDistributionProgram.methods
+              .migrateDistribution()
+              .accountsStrict({
+                payer: wallet.publicKey,
+                distribution,
+                systemProgram: SystemProgram.programId,
+              })
+              .rpc({
+                preflightCommitment: "confirmed",
+                commitment: "confirmed",
+              });
+          } catch (err) {
+            migrateError = err;
+          }
+          expect(migrateError).not.to.be.undefined;
+        });
       });
 
       describe("after listing for sale", () => {
@@ -304,6 +430,7 @@ describe("wen_royalty_distribution", () => {
             })
             .accountsStrict({
               listing,
+              config: marketplaceConfig,
               manager,
               payer: wallet.publicKey,
               seller: seller.publicKey,
@@ -372,6 +499,7 @@ describe("wen_royalty_distribution", () => {
         let sellerPreBalance: number;
         let buyerPreBalance: number;
         let distributionPostBalance: number;
+        let distributionAccountAfterBuy;
         let sellerPostBalance: number;
         let buyerPostBalance: number;
 
@@ -395,19 +523,26 @@ describe("wen_royalty_distribution", () => {
           await wenWnsMarketplace.methods
             .buy({
               buyAmount: listingAmount,
+              groupPathLen: 0,
             })
             .accountsStrict({
               approveAccount,
               extraMetasAccount,
+              royaltyConfig,
+              member,
+              groupRoyaltyConfig,
               distribution,
               manager,
               listing,
+              config: marketplaceConfig,
               payer: wallet.publicKey,
               buyer: buyer.publicKey,
               seller: seller.publicKey,
               buyerPaymentTokenAccount: null,
               sellerPaymentTokenAccount: null,
               distributionPaymentTokenAccount: null,
+              treasury: null,
+              treasuryPaymentTokenAccount: null,
               mint: memberMintPublickey,
               paymentMint: PublicKey.default,
               buyerTokenAccount: buyerMemberMintTokenAccount,
@@ -432,6 +567,11 @@ describe("wen_royalty_distribution", () => {
           distributionPostBalance =
             (await connection.getBalance(distribution, "confirmed")) -
             distributionPreBalance;
+          distributionAccountAfterBuy =
+            await wenDistributionProgram.account.distributionAccount.fetch(
+              distribution,
+              "confirmed",
+            );
           buyerPostBalance = await connection.getBalance(
             buyer.publicKey,
             "confirmed",
@@ -460,6 +600,21 @@ describe("wen_royalty_distribution", () => {
               royalty.toNumber(),
             ); // extra for rent
           });
+          it("should be fully accounted for between creators and dust", () => {
+            const claimed = distributionAccountAfterBuy.claimData.reduce(
+              (total, creator) => total.add(creator.claimAmount),
+              new anchor.BN(0),
+            );
+            expect(
+              claimed.add(distributionAccountAfterBuy.dustAmount).toString(),
+            ).to.eql(royalty.toString());
+          });
+          it("should be added to the total earned", () => {
+            expect(distributionAccountAfterBuy.totalEarned.toString()).to.eql(
+              royalty.toString(),
+            );
+            expect(distributionAccountAfterBuy.creatorCount).to.eql(2);
+          });
         });
 
         describe("the seller", () => {
@@ -489,9 +644,10 @@ describe("wen_royalty_distribution", () => {
         describe("creator 1", () => {
           let creator1PreBalance: number;
           let creator1PostBalance: number;
+          let creator1Entry;
           const expectedCreatorShare = royalty
-            .mul(new anchor.BN(creator1SharePct))
-            .div(new anchor.BN(100));
+            .mul(new anchor.BN(creator1ShareBps))
+            .div(new anchor.BN(10_000));
 
           before(async () => {
             creator1PreBalance = await connection.getBalance(
@@ -523,19 +679,39 @@ describe("wen_royalty_distribution", () => {
             creator1PostBalance =
               (await connection.getBalance(creator1.publicKey, "confirmed")) -
               creator1PreBalance;
+
+            const distributionAccount =
+              await wenDistributionProgram.account.distributionAccount.fetch(
+                distribution,
+                "confirmed",
+              );
+            creator1Entry = distributionAccount.claimData.find((creator) =>
+              creator.address.equals(creator1.publicKey),
+            );
           });
 
           it("should receive correct royalty funds", () => {
             expect(creator1PostBalance).to.eql(expectedCreatorShare.toNumber());
           });
+
+          it("should record the claim in the creator ledger", () => {
+            expect(creator1Entry.claimAmount.toString()).to.eql("0");
+            expect(creator1Entry.totalEarned.toString()).to.eql(
+              expectedCreatorShare.toString(),
+            );
+            expect(creator1Entry.totalClaimed.toString()).to.eql(
+              expectedCreatorShare.toString(),
+            );
+            expect(creator1Entry.lastClaimSlot.toNumber()).to.be.greaterThan(0);
+          });
         });
 
         describe("creator 2", () => {
           let creator2PreBalance: number;
           let creator2PostBalance: number;
           const expectedCreatorShare = royalty
-            .mul(new anchor.BN(creator2SharePct))
-            .div(new anchor.BN(100));
+            .mul(new anchor.BN(creator2ShareBps))
+            .div(new anchor.BN(10_000));
 
           before(async () => {
             creator2PreBalance = await connection.getBalance(
@@ -574,46 +750,281 @@ describe("wen_royalty_distribution", () => {
           });
         });
       });
-    });
 
-    describe("using SPL token as payment", () => {
-      const name = faker.lorem.words({ max: 3, min: 2 });
-      const symbol = faker.lorem.word();
-      const uri = faker.internet.url();
+      describe("after distributing to a creator with the crank", () => {
+        let tipError;
+        let tipByCreatorError;
+        let crankTipBasisPoints: number;
+        let creator1PreBalance: number;
+        let creator1PostBalance: number;
+
+        before(async () => {
+          const updateCrankTip = (authority: PublicKey, basisPoints: number) =>
+            wenDistributionProgram.methods
+              .updateCrankTip(basisPoints)
+              .accountsStrict({
+                authority,
+                groupMint: groupMintPublicKey,
+                group,
+                distribution,
+              });
+
+          try {
+            await updateCrankTip(groupMintAuthPublicKey, 51).rpc({
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+          } catch (err) {
+            tipError = err;
+          }
+
+          try {
+            await updateCrankTip(creator1.publicKey, 50)
+              .signers([creator1])
+              .rpc({
+                preflightCommitment: "confirmed",
+                commitment: "confirmed",
+              });
+          } catch (err) {
+            tipByCreatorError = err;
+          }
+
+          await updateCrankTip(groupMintAuthPublicKey, 50).rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+          crankTipBasisPoints = (
+            await wenDistributionProgram.account.distributionAccount.fetch(
+              distribution,
+              "confirmed",
+            )
+          ).crankTipBasisPoints;
+
+          creator1PreBalance = await connection.getBalance(
+            creator1.publicKey,
+            "confirmed",
+          );
+
+          await wenDistributionProgram.methods
+            .distributeToCreator()
+            .accountsStrict({
+              cranker: wallet.publicKey,
+              creator: creator1.publicKey,
+              distribution,
+              paymentMint: PublicKey.default,
+              distributionTokenAccount: null,
+              creatorTokenAccount: null,
+              crankerTokenAccount: null,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc({
+              skipPreflight: true,
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+
+          creator1PostBalance =
+            (await connection.getBalance(creator1.publicKey, "confirmed")) -
+            creator1PreBalance;
+        });
+
+        it("should reject a crank tip above the maximum", () => {
+          expect(tipError).not.to.be.undefined;
+        });
+
+        it("should only let the group update authority set the tip", () => {
+          expect(tipByCreatorError).not.to.be.undefined;
+          expect(crankTipBasisPoints).to.eql(50);
+        });
+
+        it("should not pay a creator that already claimed", () => {
+          expect(creator1PostBalance).to.eql(0);
+        });
+      });
+
+      describe("after claiming many distributions at once", () => {
+        let creator2PreBalance: number;
+        let creator2PostBalance: number;
+
+        before(async () => {
+          creator2PreBalance = await connection.getBalance(
+            creator2.publicKey,
+            "confirmed",
+          );
+
+          await wenWnsMarketplace.methods
+            .claimManyRoyalties()
+            .accountsStrict({
+              creator: creator2.publicKey,
+              wenDistributionProgram: wenDistributionProgramId,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              token2022Program: TOKEN_2022_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([
+              { pubkey: distribution, isSigner: false, isWritable: true },
+              {
+                pubkey: PublicKey.default,
+                isSigner: false,
+                isWritable: false,
+              },
+              {
+                pubkey: wenDistributionProgramId,
+                isSigner: false,
+                isWritable: false,
+              },
+              {
+                pubkey: wenDistributionProgramId,
+                isSigner: false,
+                isWritable: false,
+              },
+            ])
+            .signers([creator2])
+            .rpc({
+              skipPreflight: true,
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+
+          creator2PostBalance =
+            (await connection.getBalance(creator2.publicKey, "confirmed")) -
+            creator2PreBalance;
+        });
+
+        it("should skip distributions with nothing to claim", () => {
+          expect(creator2PostBalance).to.eql(0);
+        });
+      });
+
+      describe("after closing the empty distribution account", () => {
+        let closeByCreatorError;
+        let closedDistributionAccountInfo: AccountInfo<Buffer>;
+
+        before(async () => {
+          const closeDistribution = (authority: PublicKey) =>
+            wenDistributionProgram.methods.closeDistribution().accountsStrict({
+              authority,
+              groupMint: groupMintPublicKey,
+              group,
+              distribution,
+              paymentMint: PublicKey.default,
+              distributionTokenAccount: null,
+              destination: groupMintAuthPublicKey,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            });
+
+          try {
+            await closeDistribution(creator1.publicKey)
+              .signers([creator1])
+              .rpc({
+                preflightCommitment: "confirmed",
+                commitment: "confirmed",
+              });
+          } catch (err) {
+            closeByCreatorError = err;
+          }
+
+          await closeDistribution(groupMintAuthPublicKey).rpc({
+            skipPreflight: true,
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+
+          closedDistributionAccountInfo = await connection.getAccountInfo(
+            distribution,
+            "confirmed",
+          );
+        });
+
+        it("should only be allowed for the group update authority", () => {
+          expect(closeByCreatorError).not.to.be.undefined;
+        });
+
+        it("should close the distribution account", () => {
+          expect(closedDistributionAccountInfo).to.be.null;
+        });
+      });
+
+      describe("after burning the sold asset", () => {
+        let approveAccountPreBurnInfo: AccountInfo<Buffer>;
+        let approveAccountInfo: AccountInfo<Buffer>;
+        let extraMetasAccountInfo: AccountInfo<Buffer>;
+        let royaltyConfigAccountInfo: AccountInfo<Buffer>;
+
+        before(async () => {
+          approveAccountPreBurnInfo = await connection.getAccountInfo(
+            approveAccount,
+            "confirmed",
+          );
+
+          await wnsProgram.methods
+            .burnMintAccount()
+            .accountsStrict({
+              mint: memberMintPublickey,
+              mintTokenAccount: buyerMemberMintTokenAccount,
+              payer: buyer.publicKey,
+              user: buyer.publicKey,
+              manager,
+              group,
+              member,
+              subGroup: null,
+              approveAccount,
+              extraMetasAccount,
+              royaltyConfig,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
+            .signers([buyer])
+            .rpc({
+              skipPreflight: true,
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+
+          [
+            approveAccountInfo,
+            extraMetasAccountInfo,
+            royaltyConfigAccountInfo,
+          ] = await Promise.all(
+            [approveAccount, extraMetasAccount, royaltyConfig].map((account) =>
+              connection.getAccountInfo(account, "confirmed"),
+            ),
+          );
+        });
+
+        it("should have had an approve account from the sale", () => {
+          expect(approveAccountPreBurnInfo).to.not.be.null;
+        });
+
+        it("should close the approve account", () => {
+          expect(approveAccountInfo).to.be.null;
+        });
+
+        it("should close the extra metas and royalty config accounts", () => {
+          expect(extraMetasAccountInfo).to.be.null;
+          expect(royaltyConfigAccountInfo).to.be.null;
+        });
+      });
+    });
 
+    describe("using royalties inherited from the group", () => {
       const groupMintKeypair = Keypair.generate();
       const memberMintKeypair = Keypair.generate();
-      const paymentMintKeypair = Keypair.generate();
-
       const groupMintPublicKey = groupMintKeypair.publicKey;
       const memberMintPublickey = memberMintKeypair.publicKey;
-      const paymentMintPublickey = paymentMintKeypair.publicKey;
-
-      const groupMintAuthPublicKey = wallet.publicKey;
-      const memberMintAuthPublicKey = seller.publicKey;
-      const paymentMintAuthPublicKey = wallet.publicKey;
 
-      const groupMintTokenAccount = getAssociatedTokenAddressSync(
-        groupMintPublicKey,
-        groupMintAuthPublicKey,
-        false,
-        TOKEN_2022_PROGRAM_ID,
-      );
+      const authority = wallet.publicKey;
 
       const sellerMemberMintTokenAccount = getAssociatedTokenAddressSync(
         memberMintPublickey,
-        memberMintAuthPublicKey,
-        false,
-        TOKEN_2022_PROGRAM_ID,
-      );
-
-      const sellerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
-        paymentMintPublickey,
         seller.publicKey,
         false,
-        TOKEN_PROGRAM_ID,
+        TOKEN_2022_PROGRAM_ID,
       );
-
       const buyerMemberMintTokenAccount = getAssociatedTokenAddressSync(
         memberMintPublickey,
         buyer.publicKey,
@@ -621,25 +1032,12 @@ describe("wen_royalty_distribution", () => {
         TOKEN_2022_PROGRAM_ID,
       );
 
-      const buyerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
-        paymentMintPublickey,
-        buyer.publicKey,
-        false,
-        TOKEN_PROGRAM_ID,
-      );
-
       const group = getGroupAccountPda(groupMintPublicKey, wnsProgramId);
       const distribution = getDistributionAccountPda(
         groupMintPublicKey,
-        paymentMintPublickey,
+        PublicKey.default,
         wenDistributionProgramId,
       );
-
-      const listing = getListingAccountPda(
-        seller.publicKey,
-        memberMintPublickey,
-        wenWnsMarketplaceId,
-      );
       const member = getMemberAccountPda(memberMintPublickey, wnsProgramId);
       const extraMetasAccount = getExtraMetasAccountPda(
         memberMintPublickey,
@@ -649,70 +1047,56 @@ describe("wen_royalty_distribution", () => {
         memberMintPublickey,
         wnsProgramId,
       );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+      const listing = getListingAccountPda(
+        seller.publicKey,
+        memberMintPublickey,
+        wenWnsMarketplaceId,
+      );
 
-      const listingAmount = new anchor.BN(500 * 10 ** 6);
-      const royaltyBasisPoints = 1000;
+      const listingAmount = new anchor.BN(LAMPORTS_PER_SOL);
+      const royaltyBasisPoints = 500;
       const royalty = listingAmount
         .mul(new anchor.BN(royaltyBasisPoints))
         .div(new anchor.BN(10_000));
-      const creator1SharePct = 60;
-      const creator2SharePct = 40;
 
-      let distributionAccountInfo: AccountInfo<Buffer>;
-      let distributionAccountData;
+      let distributionPreBalance: number;
+      let distributionPostBalance: number;
+      let buyerTokenAccountData: Account;
+      let approveAccountData;
+      let transferHookProgramId: PublicKey;
 
       before(async () => {
-        const { ixs: createMintIxs } = await createMintTokenKegIx(
-          connection,
-          paymentMintPublickey,
-          paymentMintAuthPublicKey,
-          paymentMintAuthPublicKey,
-        );
-
-        await sendAndConfirmWNSTransaction(
-          connection,
-          createMintIxs,
-          provider,
-          true,
-          [paymentMintKeypair],
-        );
-
-        const { ixs: mintToIxs } = mintToBuyerSellerIx(
-          paymentMintPublickey,
-          paymentMintAuthPublicKey,
-          paymentMintAuthPublicKey,
-          buyer.publicKey,
-          buyerPaymentMintTokenAccount,
-          seller.publicKey,
-          sellerPaymentMintTokenAccount,
-        );
-
-        await sendAndConfirmWNSTransaction(
-          connection,
-          mintToIxs,
-          provider,
-          true,
-          [],
-        );
-      });
-
-      before(async () => {
-        // CREATE GROUP ACCOUNT
+        // CREATE GROUP ACCOUNT WITH ITS DISTRIBUTION AND ROYALTIES
         await wnsProgram.methods
           .createGroupAccount({
             maxSize: 1,
-            name,
-            symbol,
-            uri,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
           })
           .accountsStrict({
-            authority: groupMintAuthPublicKey,
+            authority,
             group,
             manager,
             mint: groupMintPublicKey,
-            mintTokenAccount: groupMintTokenAccount,
-            payer: groupMintAuthPublicKey,
-            receiver: groupMintAuthPublicKey,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              groupMintPublicKey,
+              authority,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            payer: authority,
+            receiver: authority,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
@@ -720,63 +1104,64 @@ describe("wen_royalty_distribution", () => {
           .signers([groupMintKeypair])
           .rpc(preflightConfig);
 
-        // CREATE DISTRIBUTION ACCOUNT
         await wenDistributionProgram.methods
-          .initializeDistribution(paymentMintPublickey)
+          .initializeDistribution(PublicKey.default)
           .accountsStrict({
-            payer: groupMintAuthPublicKey,
+            payer: authority,
             groupMint: groupMintPublicKey,
             distributionAccount: distribution,
             systemProgram: SystemProgram.programId,
           })
           .rpc(preflightConfig);
 
-        distributionAccountInfo = await connection.getAccountInfo(
-          distribution,
-          "confirmed",
-        );
-        distributionAccountData = wenDistributionProgram.coder.accounts.decode(
-          "distributionAccount",
-          distributionAccountInfo.data,
-        );
-      });
-
-      before(async () => {
-        const name = faker.lorem.words({ max: 3, min: 2 });
-        const symbol = faker.lorem.word();
-        const uri = faker.internet.url();
+        await wnsProgram.methods
+          .addGroupRoyalties({
+            royaltyBasisPoints,
+            creators: [{ address: creator1.publicKey, share: 10_000 }],
+          })
+          .accountsStrict({
+            payer: authority,
+            authority,
+            mint: groupMintPublicKey,
+            group,
+            royaltyConfig: groupRoyaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc(preflightConfig);
 
-        // CREATE MINT ACCOUNT, ADD MINT TO GROUP, ADD ROYALTIES
+        // CREATE MINT ACCOUNT, ADD MINT TO GROUP, INHERIT GROUP ROYALTIES
         const ixs = await Promise.all([
           wnsProgram.methods
             .addMintToGroup()
             .accountsStrict({
-              authority: groupMintAuthPublicKey,
+              authority,
               mint: memberMintPublickey,
-              payer: groupMintAuthPublicKey,
+              subGroup: null,
+              assetAuthority: seller.publicKey,
+              assetTokenAccount: null,
+              payer: authority,
               group,
               manager,
               member,
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
             .instruction(),
           wnsProgram.methods
-            .addRoyalties({
-              creators: [
-                {
-                  address: creator1.publicKey,
-                  share: creator1SharePct,
-                },
-                { address: creator2.publicKey, share: creator2SharePct },
-              ],
-              royaltyBasisPoints,
-            })
+            .inheritRoyalties()
             .accountsStrict({
-              extraMetasAccount,
-              authority: memberMintAuthPublicKey,
+              payer: authority,
+              authority: seller.publicKey,
               mint: memberMintPublickey,
-              payer: groupMintAuthPublicKey,
+              member,
+              groupRoyaltyConfig,
+              extraMetasAccount,
+              manager,
               systemProgram: SystemProgram.programId,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
@@ -784,14 +1169,20 @@ describe("wen_royalty_distribution", () => {
         ]);
 
         await wnsProgram.methods
-          .createMintAccount({ name, symbol, permanentDelegate: null, uri })
+          .createMintAccount({
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            permanentDelegate: null,
+            soulbound: false,
+            uri: faker.internet.url(),
+          })
           .accountsStrict({
-            payer: groupMintAuthPublicKey,
+            payer: authority,
             manager,
             mintTokenAccount: sellerMemberMintTokenAccount,
-            authority: memberMintAuthPublicKey,
+            authority: seller.publicKey,
             mint: memberMintPublickey,
-            receiver: memberMintAuthPublicKey,
+            receiver: seller.publicKey,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
@@ -802,160 +1193,2112 @@ describe("wen_royalty_distribution", () => {
           .postInstructions(ixs)
           .signers([memberMintKeypair, seller])
           .rpc(preflightConfig);
-      });
-
-      describe("after initializing distribution", () => {
-        it("should have a distribution account", () => {
-          expect(distributionAccountInfo).to.not.be.undefined;
-        });
-
-        it("should be owned by the distribution program", () => {
-          expect(distributionAccountInfo.owner.toBase58()).to.eql(
-            wenDistributionProgramId.toBase58(),
-          );
-        });
-
-        it("should point the correct group mint account address", () => {
-          expect(
-            (distributionAccountData.groupMint as PublicKey).toBase58(),
-          ).to.eql(groupMintPublicKey.toBase58());
-        });
-
-        it("should point the correct payment mint account address", () => {
-          expect(
-            (distributionAccountData.paymentMint as PublicKey).toBase58(),
-          ).to.eql(paymentMintPublickey.toBase58());
-        });
-      });
-
-      describe("after listing for sale", () => {
-        let listingAccountInfo: AccountInfo<Buffer>;
-        let sellerTokenAccountData: Account;
-        let listingAccountData;
-
-        before(async () => {
-          await wenWnsMarketplace.methods
-            .list({
-              listingAmount,
-              paymentMint: paymentMintPublickey,
-            })
-            .accountsStrict({
-              listing,
-              manager,
-              payer: wallet.publicKey,
-              seller: seller.publicKey,
-              mint: memberMintPublickey,
-              sellerTokenAccount: sellerMemberMintTokenAccount,
-              systemProgram: SystemProgram.programId,
-              wnsProgram: wnsProgramId,
-              tokenProgram: TOKEN_2022_PROGRAM_ID,
-            })
-            .signers([seller])
-            .rpc({
-              skipPreflight: true,
-              preflightCommitment: "confirmed",
-              commitment: "confirmed",
-            });
 
-          listingAccountInfo = await connection.getAccountInfo(
-            listing,
-            "confirmed",
-          );
-          listingAccountData = wenWnsMarketplace.coder.accounts.decode(
-            "listing",
-            listingAccountInfo.data,
-          );
-          sellerTokenAccountData = await getAccount(
+        transferHookProgramId = getTransferHook(
+          await getMint(
             connection,
-            sellerMemberMintTokenAccount,
+            memberMintPublickey,
             "confirmed",
             TOKEN_2022_PROGRAM_ID,
-          );
-        });
-
-        it("should have a listing account", () => {
-          expect(listingAccountData).to.not.be.undefined;
-        });
+          ),
+        ).programId;
+
+        // LIST AND BUY
+        await wenWnsMarketplace.methods
+          .list({
+            listingAmount,
+            paymentMint: PublicKey.default,
+          })
+          .accountsStrict({
+            listing,
+            config: marketplaceConfig,
+            manager,
+            payer: authority,
+            seller: seller.publicKey,
+            mint: memberMintPublickey,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            systemProgram: SystemProgram.programId,
+            wnsProgram: wnsProgramId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([seller])
+          .rpc(preflightConfig);
 
-        it("should be owned by sale program", () => {
-          expect(listingAccountInfo.owner).to.eql(wenWnsMarketplaceId);
-        });
+        distributionPreBalance = await connection.getBalance(
+          distribution,
+          "confirmed",
+        );
 
-        it("should point the listing account as delegate of NFT", () => {
-          expect(sellerTokenAccountData.delegate.toBase58()).to.eql(
-            listing.toBase58(),
-          );
-        });
+        await wenWnsMarketplace.methods
+          .buy({
+            buyAmount: listingAmount,
+            groupPathLen: 0,
+          })
+          .accountsStrict({
+            approveAccount,
+            extraMetasAccount,
+            royaltyConfig,
+            member,
+            groupRoyaltyConfig,
+            distribution,
+            manager,
+            listing,
+            config: marketplaceConfig,
+            payer: authority,
+            buyer: buyer.publicKey,
+            seller: seller.publicKey,
+            buyerPaymentTokenAccount: null,
+            sellerPaymentTokenAccount: null,
+            distributionPaymentTokenAccount: null,
+            treasury: null,
+            treasuryPaymentTokenAccount: null,
+            mint: memberMintPublickey,
+            paymentMint: PublicKey.default,
+            buyerTokenAccount: buyerMemberMintTokenAccount,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            wnsProgram: wnsProgramId,
+            distributionProgram: wenDistributionProgramId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
+          ])
+          .signers([buyer])
+          .rpc(preflightConfig);
 
-        it("should freeze the NFT", () => {
-          expect(sellerTokenAccountData.isFrozen).to.be.true;
-        });
+        distributionPostBalance =
+          (await connection.getBalance(distribution, "confirmed")) -
+          distributionPreBalance;
+        buyerTokenAccountData = await getAccount(
+          connection,
+          buyerMemberMintTokenAccount,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+        approveAccountData = await wnsProgram.account.approveAccount.fetch(
+          approveAccount,
+          "confirmed",
+        );
       });
 
-      describe("after a sale", () => {
-        const buyerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
-          paymentMintPublickey,
-          buyer.publicKey,
-          false,
-          TOKEN_PROGRAM_ID,
+      it("should point the transfer hook of the member at wns", () => {
+        expect(transferHookProgramId.toBase58()).to.eql(
+          wnsProgramId.toBase58(),
         );
+      });
 
-        const sellerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
-          paymentMintPublickey,
-          seller.publicKey,
-          false,
-          TOKEN_PROGRAM_ID,
-        );
+      it("should send the group royalties to the distribution vault", () => {
+        expect(distributionPostBalance).to.eql(royalty.toNumber());
+      });
 
-        const distributionPaymentMintTokenAccount =
-          getAssociatedTokenAddressSync(
-            paymentMintPublickey,
-            distribution,
-            true,
-            TOKEN_PROGRAM_ID,
-          );
+      it("should use the approve account in the transfer hook", () => {
+        expect(approveAccountData.slot.toString()).to.eql("0");
+      });
 
-        let sellerPreBalance: number;
-        let buyerPreBalance: number;
-        let distributionPostBalance: number;
-        let sellerPostBalance: number;
-        let buyerPostBalance: number;
+      it("should transfer the asset to the buyer", () => {
+        expect(buyerTokenAccountData.amount.toString()).to.eql("1");
+      });
+    });
 
-        let sellerTokenAccountData: Account;
-        let buyerTokenAccountData: Account;
+    describe("using royalties inherited by a member of a sub group", () => {
+      const groupMintKeypair = Keypair.generate();
+      const subGroupMintKeypair = Keypair.generate();
+      const memberMintKeypair = Keypair.generate();
+      const groupMintPublicKey = groupMintKeypair.publicKey;
+      const subGroupMintPublicKey = subGroupMintKeypair.publicKey;
+      const memberMintPublickey = memberMintKeypair.publicKey;
 
-        before(async () => {
-          buyerPreBalance = parseInt(
-            (
-              await getAccount(
-                connection,
-                buyerPaymentMintTokenAccount,
-                "confirmed",
-                TOKEN_PROGRAM_ID,
-              )
-            ).amount.toString(),
-          );
-          sellerPreBalance = parseInt(
-            (
-              await getAccount(
-                connection,
-                sellerPaymentMintTokenAccount,
-                "confirmed",
-                TOKEN_PROGRAM_ID,
-              )
-            ).amount.toString(),
-          );
+      const authority = wallet.publicKey;
 
-          await wenWnsMarketplace.methods
-            .buy({
-              buyAmount: listingAmount,
-            })
-            .accountsStrict({
-              approveAccount,
-              extraMetasAccount,
+      const sellerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        seller.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const buyerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const group = getGroupAccountPda(groupMintPublicKey, wnsProgramId);
+      const subGroup = getGroupAccountPda(subGroupMintPublicKey, wnsProgramId);
+      const distribution = getDistributionAccountPda(
+        groupMintPublicKey,
+        PublicKey.default,
+        wenDistributionProgramId,
+      );
+      const member = getMemberAccountPda(memberMintPublickey, wnsProgramId);
+      const extraMetasAccount = getExtraMetasAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const approveAccount = getApproveAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+      const listing = getListingAccountPda(
+        seller.publicKey,
+        memberMintPublickey,
+        wenWnsMarketplaceId,
+      );
+      const groupPath = getGroupPath([subGroupMintPublicKey], wnsProgramId);
+
+      const listingAmount = new anchor.BN(LAMPORTS_PER_SOL);
+      const royaltyBasisPoints = 500;
+      const royalty = listingAmount
+        .mul(new anchor.BN(royaltyBasisPoints))
+        .div(new anchor.BN(10_000));
+
+      let distributionPostBalance: number;
+      let buyerTokenAccountData: Account;
+      let groupPathLenError;
+
+      const createGroup = (mintKeypair: Keypair) =>
+        wnsProgram.methods
+          .createGroupAccount({
+            maxSize: 10,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority,
+            group: getGroupAccountPda(mintKeypair.publicKey, wnsProgramId),
+            manager,
+            mint: mintKeypair.publicKey,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              mintKeypair.publicKey,
+              authority,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            payer: authority,
+            receiver: authority,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([mintKeypair])
+          .rpc(preflightConfig);
+
+      const buy = (groupPathLen: number) =>
+        wenWnsMarketplace.methods
+          .buy({
+            buyAmount: listingAmount,
+            groupPathLen,
+          })
+          .accountsStrict({
+            approveAccount,
+            extraMetasAccount,
+            royaltyConfig,
+            member,
+            groupRoyaltyConfig,
+            distribution,
+            manager,
+            listing,
+            config: marketplaceConfig,
+            payer: authority,
+            buyer: buyer.publicKey,
+            seller: seller.publicKey,
+            buyerPaymentTokenAccount: null,
+            sellerPaymentTokenAccount: null,
+            distributionPaymentTokenAccount: null,
+            treasury: null,
+            treasuryPaymentTokenAccount: null,
+            mint: memberMintPublickey,
+            paymentMint: PublicKey.default,
+            buyerTokenAccount: buyerMemberMintTokenAccount,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            wnsProgram: wnsProgramId,
+            distributionProgram: wenDistributionProgramId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(groupPath)
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+          ])
+          .signers([buyer])
+          .rpc(preflightConfig);
+
+      before(async () => {
+        // CREATE GROUP WITH ITS DISTRIBUTION AND ROYALTIES, NEST A SUB GROUP
+        await createGroup(groupMintKeypair);
+        await createGroup(subGroupMintKeypair);
+
+        await wenDistributionProgram.methods
+          .initializeDistribution(PublicKey.default)
+          .accountsStrict({
+            payer: authority,
+            groupMint: groupMintPublicKey,
+            distributionAccount: distribution,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(preflightConfig);
+
+        await wnsProgram.methods
+          .addGroupRoyalties({
+            royaltyBasisPoints,
+            creators: [{ address: creator1.publicKey, share: 10_000 }],
+          })
+          .accountsStrict({
+            payer: authority,
+            authority,
+            mint: groupMintPublicKey,
+            group,
+            royaltyConfig: groupRoyaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc(preflightConfig);
+
+        await wnsProgram.methods
+          .addMintToGroup()
+          .accountsStrict({
+            authority,
+            mint: subGroupMintPublicKey,
+            subGroup,
+            assetAuthority: authority,
+            assetTokenAccount: null,
+            payer: authority,
+            group,
+            manager,
+            member: getMemberAccountPda(subGroupMintPublicKey, wnsProgramId),
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            getGroupAncestry([groupMintPublicKey], wnsProgramId),
+          )
+          .rpc(preflightConfig);
+
+        // CREATE MINT ACCOUNT IN THE SUB GROUP, INHERIT THE GROUP ROYALTIES
+        const ixs = await Promise.all([
+          wnsProgram.methods
+            .addMintToGroup()
+            .accountsStrict({
+              authority,
+              mint: memberMintPublickey,
+              subGroup: null,
+              assetAuthority: seller.publicKey,
+              assetTokenAccount: null,
+              payer: authority,
+              group: subGroup,
+              manager,
+              member,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry(
+                [subGroupMintPublicKey, groupMintPublicKey],
+                wnsProgramId,
+              ),
+            )
+            .instruction(),
+          wnsProgram.methods
+            .inheritRoyalties()
+            .accountsStrict({
+              payer: authority,
+              authority: seller.publicKey,
+              mint: memberMintPublickey,
+              member,
+              groupRoyaltyConfig,
+              extraMetasAccount,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(groupPath)
+            .instruction(),
+        ]);
+
+        await wnsProgram.methods
+          .createMintAccount({
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            permanentDelegate: null,
+            soulbound: false,
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            payer: authority,
+            manager,
+            mintTokenAccount: sellerMemberMintTokenAccount,
+            authority: seller.publicKey,
+            mint: memberMintPublickey,
+            receiver: seller.publicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+          ])
+          .postInstructions(ixs)
+          .signers([memberMintKeypair, seller])
+          .rpc(preflightConfig);
+
+        // LIST AND BUY
+        await wenWnsMarketplace.methods
+          .list({
+            listingAmount,
+            paymentMint: PublicKey.default,
+          })
+          .accountsStrict({
+            listing,
+            config: marketplaceConfig,
+            manager,
+            payer: authority,
+            seller: seller.publicKey,
+            mint: memberMintPublickey,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            systemProgram: SystemProgram.programId,
+            wnsProgram: wnsProgramId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([seller])
+          .rpc(preflightConfig);
+
+        try {
+          await buy(groupPath.length + 1);
+        } catch (err) {
+          groupPathLenError = err;
+        }
+
+        const distributionPreBalance = await connection.getBalance(
+          distribution,
+          "confirmed",
+        );
+
+        await buy(groupPath.length);
+
+        distributionPostBalance =
+          (await connection.getBalance(distribution, "confirmed")) -
+          distributionPreBalance;
+        buyerTokenAccountData = await getAccount(
+          connection,
+          buyerMemberMintTokenAccount,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+      });
+
+      it("should reject a group path past the remaining accounts", () => {
+        expect(groupPathLenError).not.to.be.undefined;
+      });
+
+      it("should send the group royalties to the distribution vault", () => {
+        expect(distributionPostBalance).to.eql(royalty.toNumber());
+      });
+
+      it("should transfer the asset to the buyer", () => {
+        expect(buyerTokenAccountData.amount.toString()).to.eql("1");
+      });
+    });
+
+    describe("using a split as creator", () => {
+      const groupMintKeypair = Keypair.generate();
+      const splitGroupMintKeypair = Keypair.generate();
+      const memberMintKeypair = Keypair.generate();
+      const groupMintPublicKey = groupMintKeypair.publicKey;
+      const splitGroupMintPublicKey = splitGroupMintKeypair.publicKey;
+      const memberMintPublickey = memberMintKeypair.publicKey;
+
+      const authority = wallet.publicKey;
+
+      const group = getGroupAccountPda(groupMintPublicKey, wnsProgramId);
+      const splitGroup = getGroupAccountPda(
+        splitGroupMintPublicKey,
+        wnsProgramId,
+      );
+      const distribution = getDistributionAccountPda(
+        groupMintPublicKey,
+        PublicKey.default,
+        wenDistributionProgramId,
+      );
+      const splitDistribution = getDistributionAccountPda(
+        splitGroupMintPublicKey,
+        PublicKey.default,
+        wenDistributionProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+      const splitGroupRoyaltyConfig = getRoyaltyConfigPda(
+        splitGroupMintPublicKey,
+        wnsProgramId,
+      );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const member = getMemberAccountPda(memberMintPublickey, wnsProgramId);
+      const extraMetasAccount = getExtraMetasAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+
+      const royalty = new anchor.BN(LAMPORTS_PER_SOL);
+      const creator1ShareBps = 6000;
+      const creator2ShareBps = 4000;
+
+      let otherGroupError;
+      let distributeError;
+      let distributionAccountData;
+      let splitDistributionAccountData;
+
+      before(async () => {
+        // CREATE PARENT AND SPLIT GROUPS WITH THEIR DISTRIBUTION ACCOUNTS
+        for (const groupKeypair of [groupMintKeypair, splitGroupMintKeypair]) {
+          const groupMint = groupKeypair.publicKey;
+          await wnsProgram.methods
+            .createGroupAccount({
+              maxSize: 1,
+              lockMaxSize: false,
+              advisoryMemberUpdateGate: false,
+              name: faker.lorem.words({ max: 3, min: 2 }),
+              symbol: faker.lorem.word(),
+              uri: faker.internet.url(),
+            })
+            .accountsStrict({
+              authority,
+              group: getGroupAccountPda(groupMint, wnsProgramId),
+              manager,
+              mint: groupMint,
+              mintTokenAccount: getAssociatedTokenAddressSync(
+                groupMint,
+                authority,
+                false,
+                TOKEN_2022_PROGRAM_ID,
+              ),
+              payer: authority,
+              receiver: authority,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([groupKeypair])
+            .rpc(preflightConfig);
+
+          await wenDistributionProgram.methods
+            .initializeDistribution(PublicKey.default)
+            .accountsStrict({
+              payer: authority,
+              groupMint,
+              distributionAccount: getDistributionAccountPda(
+                groupMint,
+                PublicKey.default,
+                wenDistributionProgramId,
+              ),
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc(preflightConfig);
+        }
+
+        // THE SPLIT PAYS ITS CREATORS THROUGH ITS GROUP ROYALTIES
+        await wnsProgram.methods
+          .addGroupRoyalties({
+            royaltyBasisPoints: 1000,
+            creators: [
+              { address: creator1.publicKey, share: creator1ShareBps },
+              { address: creator2.publicKey, share: creator2ShareBps },
+            ],
+          })
+          .accountsStrict({
+            payer: authority,
+            authority,
+            mint: splitGroupMintPublicKey,
+            group: splitGroup,
+            royaltyConfig: splitGroupRoyaltyConfig,
+            manager,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc(preflightConfig);
+
+        // CREATE MINT ACCOUNT, ADD MINT TO GROUP, ROYALTIES GO TO THE SPLIT
+        const ixs = await Promise.all([
+          wnsProgram.methods
+            .addMintToGroup()
+            .accountsStrict({
+              authority,
+              mint: memberMintPublickey,
+              subGroup: null,
+              assetAuthority: authority,
+              assetTokenAccount: null,
+              payer: authority,
+              group,
+              manager,
+              member,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
+            .instruction(),
+          wnsProgram.methods
+            .addRoyalties({
+              creators: [{ address: splitDistribution, share: 10_000 }],
+              royaltyBasisPoints: 1000,
+            })
+            .accountsStrict({
+              extraMetasAccount,
+              royaltyConfig,
+              authority,
+              mint: memberMintPublickey,
+              payer: authority,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .instruction(),
+        ]);
+
+        await wnsProgram.methods
+          .createMintAccount({
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            permanentDelegate: null,
+            soulbound: false,
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            payer: authority,
+            manager,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              memberMintPublickey,
+              authority,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            authority,
+            mint: memberMintPublickey,
+            receiver: authority,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
+          ])
+          .postInstructions(ixs)
+          .signers([memberMintKeypair])
+          .rpc(preflightConfig);
+
+        // ROYALTIES OF THE MEMBER CAN'T BE CREDITED TO ANOTHER GROUP
+        try {
+          await wenDistributionProgram.methods
+            .updateDistribution({ amount: royalty, groupPathLen: 0 })
+            .accountsStrict({
+              authority,
+              mint: memberMintPublickey,
+              royaltyConfig,
+              groupRoyaltyConfig: splitGroupRoyaltyConfig,
+              member,
+              paymentMint: PublicKey.default,
+              distributionAccount: splitDistribution,
+              distributionTokenAccount: null,
+              authorityTokenAccount: null,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              paymentTokenProgram: null,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc({
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+        } catch (err) {
+          otherGroupError = err;
+        }
+
+        // ROYALTIES OF A SALE ARE CREDITED TO THE SPLIT
+        await wenDistributionProgram.methods
+          .updateDistribution({ amount: royalty, groupPathLen: 0 })
+          .accountsStrict({
+            authority,
+            mint: memberMintPublickey,
+            royaltyConfig,
+            groupRoyaltyConfig,
+            member,
+            paymentMint: PublicKey.default,
+            distributionAccount: distribution,
+            distributionTokenAccount: null,
+            authorityTokenAccount: null,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(preflightConfig);
+
+        try {
+          await wenDistributionProgram.methods
+            .distributeToCreator()
+            .accountsStrict({
+              cranker: authority,
+              creator: splitDistribution,
+              distribution,
+              paymentMint: PublicKey.default,
+              distributionTokenAccount: null,
+              creatorTokenAccount: null,
+              crankerTokenAccount: null,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc({
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+        } catch (err) {
+          distributeError = err;
+        }
+
+        await wenDistributionProgram.methods
+          .forwardToSplit()
+          .accountsStrict({
+            payer: authority,
+            paymentMint: PublicKey.default,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            [
+              [distribution, groupRoyaltyConfig],
+              [splitDistribution, splitGroupRoyaltyConfig],
+            ].flatMap(([splitPathDistribution, splitPathRoyaltyConfig]) => [
+              {
+                pubkey: splitPathDistribution,
+                isSigner: false,
+                isWritable: true,
+              },
+              {
+                pubkey: splitPathRoyaltyConfig,
+                isSigner: false,
+                isWritable: false,
+              },
+              {
+                pubkey: wenDistributionProgramId,
+                isSigner: false,
+                isWritable: false,
+              },
+            ]),
+          )
+          .rpc(preflightConfig);
+
+        distributionAccountData =
+          await wenDistributionProgram.account.distributionAccount.fetch(
+            distribution,
+            "confirmed",
+          );
+        splitDistributionAccountData =
+          await wenDistributionProgram.account.distributionAccount.fetch(
+            splitDistribution,
+            "confirmed",
+          );
+      });
+
+      it("should reject royalties of a mint from another group", () => {
+        expect(otherGroupError).not.to.be.undefined;
+      });
+
+      it("should not distribute royalties of a split directly", () => {
+        expect(distributeError).not.to.be.undefined;
+      });
+
+      it("should clear the split balance of the parent distribution", () => {
+        const splitEntry = distributionAccountData.claimData.find((creator) =>
+          creator.address.equals(splitDistribution),
+        );
+        expect(splitEntry.claimAmount.toString()).to.eql("0");
+        expect(splitEntry.totalClaimed.toString()).to.eql(royalty.toString());
+      });
+
+      it("should credit the creators of the split", () => {
+        expect(splitDistributionAccountData.totalEarned.toString()).to.eql(
+          royalty.toString(),
+        );
+        [
+          { creator: creator1, share: creator1ShareBps },
+          { creator: creator2, share: creator2ShareBps },
+        ].forEach(({ creator, share }) => {
+          const entry = splitDistributionAccountData.claimData.find((entry) =>
+            entry.address.equals(creator.publicKey),
+          );
+          expect(entry.claimAmount.toString()).to.eql(
+            royalty
+              .mul(new anchor.BN(share))
+              .div(new anchor.BN(10_000))
+              .toString(),
+          );
+        });
+      });
+    });
+
+    describe("using a vesting schedule", () => {
+      const groupMintKeypair = Keypair.generate();
+      const memberMintKeypair = Keypair.generate();
+      const groupMintPublicKey = groupMintKeypair.publicKey;
+      const memberMintPublickey = memberMintKeypair.publicKey;
+
+      const authority = wallet.publicKey;
+
+      const group = getGroupAccountPda(groupMintPublicKey, wnsProgramId);
+      const distribution = getDistributionAccountPda(
+        groupMintPublicKey,
+        PublicKey.default,
+        wenDistributionProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const member = getMemberAccountPda(memberMintPublickey, wnsProgramId);
+      const extraMetasAccount = getExtraMetasAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const approveAccount = getApproveAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const listing = getListingAccountPda(
+        authority,
+        memberMintPublickey,
+        wenWnsMarketplaceId,
+      );
+      const sellerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        authority,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const buyerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const royalty = new anchor.BN(LAMPORTS_PER_SOL);
+      const listingAmount = new anchor.BN(LAMPORTS_PER_SOL);
+      const day = 24 * 60 * 60;
+
+      const updateVestingSchedule = (
+        start: number,
+        cliff: number,
+        duration: number,
+      ) =>
+        wenDistributionProgram.methods
+          .updateVestingSchedule({
+            start: new anchor.BN(start),
+            cliff: new anchor.BN(cliff),
+            duration: new anchor.BN(duration),
+          })
+          .accountsStrict({
+            authority,
+            groupMint: groupMintPublicKey,
+            group,
+            distribution,
+          });
+
+      const fetchCreator1Entry = async () => {
+        const distributionAccount =
+          await wenDistributionProgram.account.distributionAccount.fetch(
+            distribution,
+            "confirmed",
+          );
+        return distributionAccount.claimData.find((creator) =>
+          creator.address.equals(creator1.publicKey),
+        );
+      };
+
+      let invalidScheduleError;
+      let futureScheduleError;
+      let creator1ClaimedBeforeCliff: number;
+      let creator1EntryBeforeCliff;
+      let creator1EntryAfterRemovingSchedule;
+      let creator1EntryAfterScheduleEnd;
+      let distributionBalanceAfterScheduleEnd: number;
+      let buyerTokenAccountData: Account;
+
+      before(async () => {
+        await wnsProgram.methods
+          .createGroupAccount({
+            maxSize: 1,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            authority,
+            group,
+            manager,
+            mint: groupMintPublicKey,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              groupMintPublicKey,
+              authority,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            payer: authority,
+            receiver: authority,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([groupMintKeypair])
+          .rpc(preflightConfig);
+
+        await wenDistributionProgram.methods
+          .initializeDistribution(PublicKey.default)
+          .accountsStrict({
+            payer: authority,
+            groupMint: groupMintPublicKey,
+            distributionAccount: distribution,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(preflightConfig);
+
+        // CREATE MINT ACCOUNT, ADD MINT TO GROUP, ADD ROYALTIES
+        const ixs = await Promise.all([
+          wnsProgram.methods
+            .addMintToGroup()
+            .accountsStrict({
+              authority,
+              mint: memberMintPublickey,
+              subGroup: null,
+              assetAuthority: authority,
+              assetTokenAccount: null,
+              payer: authority,
+              group,
+              manager,
+              member,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
+            .instruction(),
+          wnsProgram.methods
+            .addRoyalties({
+              creators: [{ address: creator1.publicKey, share: 10_000 }],
+              royaltyBasisPoints: 1000,
+            })
+            .accountsStrict({
+              extraMetasAccount,
+              royaltyConfig,
+              authority,
+              mint: memberMintPublickey,
+              payer: authority,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .instruction(),
+        ]);
+
+        await wnsProgram.methods
+          .createMintAccount({
+            name: faker.lorem.words({ max: 3, min: 2 }),
+            symbol: faker.lorem.word(),
+            permanentDelegate: null,
+            soulbound: false,
+            uri: faker.internet.url(),
+          })
+          .accountsStrict({
+            payer: authority,
+            manager,
+            mintTokenAccount: getAssociatedTokenAddressSync(
+              memberMintPublickey,
+              authority,
+              false,
+              TOKEN_2022_PROGRAM_ID,
+            ),
+            authority,
+            mint: memberMintPublickey,
+            receiver: authority,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
+          ])
+          .postInstructions(ixs)
+          .signers([memberMintKeypair])
+          .rpc(preflightConfig);
+
+        const now = await connection.getBlockTime(
+          await connection.getSlot("confirmed"),
+        );
+        try {
+          await updateVestingSchedule(now, 2 * day, day).rpc({
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+        } catch (err) {
+          invalidScheduleError = err;
+        }
+
+        try {
+          await updateVestingSchedule(now + day, day, 2 * day).rpc({
+            preflightCommitment: "confirmed",
+            commitment: "confirmed",
+          });
+        } catch (err) {
+          futureScheduleError = err;
+        }
+
+        // NOTHING VESTS BEFORE THE CLIFF
+        await updateVestingSchedule(now, day, 2 * day).rpc(preflightConfig);
+
+        await wenDistributionProgram.methods
+          .updateDistribution({ amount: royalty, groupPathLen: 0 })
+          .accountsStrict({
+            authority,
+            mint: memberMintPublickey,
+            royaltyConfig,
+            groupRoyaltyConfig,
+            member,
+            paymentMint: PublicKey.default,
+            distributionAccount: distribution,
+            distributionTokenAccount: null,
+            authorityTokenAccount: null,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(preflightConfig);
+
+        const creator1PreBalance = await connection.getBalance(
+          creator1.publicKey,
+          "confirmed",
+        );
+
+        await wenWnsMarketplace.methods
+          .claimRoyalty()
+          .accountsStrict({
+            payer: wallet.publicKey,
+            creator: creator1.publicKey,
+            distribution,
+            paymentMint: PublicKey.default,
+            creatorPaymentTokenAccount: null,
+            distributionPaymentTokenAccount: null,
+            wenDistributionProgram: wenDistributionProgramId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creator1])
+          .rpc(preflightConfig);
+
+        creator1ClaimedBeforeCliff =
+          (await connection.getBalance(creator1.publicKey, "confirmed")) -
+          creator1PreBalance;
+        creator1EntryBeforeCliff = await fetchCreator1Entry();
+
+        // REMOVING THE SCHEDULE RELEASES EVERYTHING
+        await updateVestingSchedule(0, 0, 0).rpc(preflightConfig);
+        creator1EntryAfterRemovingSchedule = await fetchCreator1Entry();
+
+        // SELL ONCE A SHORT SCHEDULE HAS ENDED
+        const start = await connection.getBlockTime(
+          await connection.getSlot("confirmed"),
+        );
+        const duration = 5;
+        await updateVestingSchedule(start, 0, duration).rpc(preflightConfig);
+        await waitForBlockTime(connection, start + duration);
+
+        await wenWnsMarketplace.methods
+          .list({
+            listingAmount,
+            paymentMint: PublicKey.default,
+          })
+          .accountsStrict({
+            listing,
+            config: marketplaceConfig,
+            manager,
+            payer: authority,
+            seller: authority,
+            mint: memberMintPublickey,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            systemProgram: SystemProgram.programId,
+            wnsProgram: wnsProgramId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc(preflightConfig);
+
+        const distributionPreBalance = await connection.getBalance(
+          distribution,
+          "confirmed",
+        );
+
+        await wenWnsMarketplace.methods
+          .buy({
+            buyAmount: listingAmount,
+            groupPathLen: 0,
+          })
+          .accountsStrict({
+            approveAccount,
+            extraMetasAccount,
+            royaltyConfig,
+            member,
+            groupRoyaltyConfig,
+            distribution,
+            manager,
+            listing,
+            config: marketplaceConfig,
+            payer: authority,
+            buyer: buyer.publicKey,
+            seller: authority,
+            buyerPaymentTokenAccount: null,
+            sellerPaymentTokenAccount: null,
+            distributionPaymentTokenAccount: null,
+            treasury: null,
+            treasuryPaymentTokenAccount: null,
+            mint: memberMintPublickey,
+            paymentMint: PublicKey.default,
+            buyerTokenAccount: buyerMemberMintTokenAccount,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            wnsProgram: wnsProgramId,
+            distributionProgram: wenDistributionProgramId,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+          ])
+          .signers([buyer])
+          .rpc(preflightConfig);
+
+        distributionBalanceAfterScheduleEnd =
+          (await connection.getBalance(distribution, "confirmed")) -
+          distributionPreBalance;
+        creator1EntryAfterScheduleEnd = await fetchCreator1Entry();
+        buyerTokenAccountData = await getAccount(
+          connection,
+          buyerMemberMintTokenAccount,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+      });
+
+      it("should reject a cliff after the end of the schedule", () => {
+        expect(invalidScheduleError).not.to.be.undefined;
+      });
+
+      it("should reject a schedule starting in the future", () => {
+        expect(futureScheduleError).not.to.be.undefined;
+      });
+
+      it("should not pay out royalties before the cliff", () => {
+        expect(creator1ClaimedBeforeCliff).to.eql(0);
+        expect(creator1EntryBeforeCliff.claimAmount.toString()).to.eql("0");
+        expect(creator1EntryBeforeCliff.unvestedAmount.toString()).to.eql(
+          royalty.toString(),
+        );
+      });
+
+      it("should release unvested royalties when the schedule is removed", () => {
+        expect(
+          creator1EntryAfterRemovingSchedule.claimAmount.toString(),
+        ).to.eql(royalty.toString());
+        expect(
+          creator1EntryAfterRemovingSchedule.unvestedAmount.toString(),
+        ).to.eql("0");
+      });
+
+      it("should sell the asset after the end of the schedule", () => {
+        expect(buyerTokenAccountData.amount.toString()).to.eql("1");
+      });
+
+      it("should make royalties after the end of the schedule claimable", () => {
+        expect(distributionBalanceAfterScheduleEnd).to.be.greaterThan(0);
+        expect(creator1EntryAfterScheduleEnd.claimAmount.toString()).to.eql(
+          royalty.addn(distributionBalanceAfterScheduleEnd).toString(),
+        );
+        expect(creator1EntryAfterScheduleEnd.unvestedAmount.toString()).to.eql(
+          "0",
+        );
+      });
+    });
+
+    describe("using SPL token as payment", () => {
+      const name = faker.lorem.words({ max: 3, min: 2 });
+      const symbol = faker.lorem.word();
+      const uri = faker.internet.url();
+
+      const groupMintKeypair = Keypair.generate();
+      const memberMintKeypair = Keypair.generate();
+      const paymentMintKeypair = Keypair.generate();
+
+      const groupMintPublicKey = groupMintKeypair.publicKey;
+      const memberMintPublickey = memberMintKeypair.publicKey;
+      const paymentMintPublickey = paymentMintKeypair.publicKey;
+
+      const groupMintAuthPublicKey = wallet.publicKey;
+      const memberMintAuthPublicKey = seller.publicKey;
+      const paymentMintAuthPublicKey = wallet.publicKey;
+
+      const groupMintTokenAccount = getAssociatedTokenAddressSync(
+        groupMintPublicKey,
+        groupMintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const sellerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        memberMintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const sellerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+        paymentMintPublickey,
+        seller.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+      );
+
+      const buyerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const buyerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+        paymentMintPublickey,
+        buyer.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+      );
+
+      const group = getGroupAccountPda(groupMintPublicKey, wnsProgramId);
+      const distribution = getDistributionAccountPda(
+        groupMintPublicKey,
+        paymentMintPublickey,
+        wenDistributionProgramId,
+      );
+
+      const listing = getListingAccountPda(
+        seller.publicKey,
+        memberMintPublickey,
+        wenWnsMarketplaceId,
+      );
+      const member = getMemberAccountPda(memberMintPublickey, wnsProgramId);
+      const extraMetasAccount = getExtraMetasAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const approveAccount = getApproveAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+
+      const listingAmount = new anchor.BN(500 * 10 ** 6);
+      const royaltyBasisPoints = 1000;
+      const royalty = listingAmount
+        .mul(new anchor.BN(royaltyBasisPoints))
+        .div(new anchor.BN(10_000));
+      const creator1ShareBps = 6000;
+      const creator2ShareBps = 4000;
+
+      let distributionAccountInfo: AccountInfo<Buffer>;
+      let distributionAccountData;
+
+      before(async () => {
+        const { ixs: createMintIxs } = await createMintTokenKegIx(
+          connection,
+          paymentMintPublickey,
+          paymentMintAuthPublicKey,
+          paymentMintAuthPublicKey,
+        );
+
+        await sendAndConfirmWNSTransaction(
+          connection,
+          createMintIxs,
+          provider,
+          true,
+          [paymentMintKeypair],
+        );
+
+        const { ixs: mintToIxs } = mintToBuyerSellerIx(
+          paymentMintPublickey,
+          paymentMintAuthPublicKey,
+          paymentMintAuthPublicKey,
+          buyer.publicKey,
+          buyerPaymentMintTokenAccount,
+          seller.publicKey,
+          sellerPaymentMintTokenAccount,
+        );
+
+        await sendAndConfirmWNSTransaction(
+          connection,
+          mintToIxs,
+          provider,
+          true,
+          [],
+        );
+      });
+
+      before(async () => {
+        // CREATE GROUP ACCOUNT
+        await wnsProgram.methods
+          .createGroupAccount({
+            maxSize: 1,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
+            name,
+            symbol,
+            uri,
+          })
+          .accountsStrict({
+            authority: groupMintAuthPublicKey,
+            group,
+            manager,
+            mint: groupMintPublicKey,
+            mintTokenAccount: groupMintTokenAccount,
+            payer: groupMintAuthPublicKey,
+            receiver: groupMintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([groupMintKeypair])
+          .rpc(preflightConfig);
+
+        // CREATE DISTRIBUTION ACCOUNT
+        await wenDistributionProgram.methods
+          .initializeDistribution(paymentMintPublickey)
+          .accountsStrict({
+            payer: groupMintAuthPublicKey,
+            groupMint: groupMintPublicKey,
+            distributionAccount: distribution,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(preflightConfig);
+
+        distributionAccountInfo = await connection.getAccountInfo(
+          distribution,
+          "confirmed",
+        );
+        distributionAccountData = wenDistributionProgram.coder.accounts.decode(
+          "distributionAccount",
+          distributionAccountInfo.data,
+        );
+      });
+
+      before(async () => {
+        const name = faker.lorem.words({ max: 3, min: 2 });
+        const symbol = faker.lorem.word();
+        const uri = faker.internet.url();
+
+        // CREATE MINT ACCOUNT, ADD MINT TO GROUP, ADD ROYALTIES
+        const ixs = await Promise.all([
+          wnsProgram.methods
+            .addMintToGroup()
+            .accountsStrict({
+              authority: groupMintAuthPublicKey,
+              mint: memberMintPublickey,
+              subGroup: null,
+              assetAuthority: memberMintAuthPublicKey,
+              assetTokenAccount: null,
+              payer: groupMintAuthPublicKey,
+              group,
+              manager,
+              member,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
+            .instruction(),
+          wnsProgram.methods
+            .addRoyalties({
+              creators: [
+                {
+                  address: creator1.publicKey,
+                  share: creator1ShareBps,
+                },
+                { address: creator2.publicKey, share: creator2ShareBps },
+              ],
+              royaltyBasisPoints,
+            })
+            .accountsStrict({
+              extraMetasAccount,
+              royaltyConfig,
+              authority: memberMintAuthPublicKey,
+              mint: memberMintPublickey,
+              payer: groupMintAuthPublicKey,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .instruction(),
+        ]);
+
+        await wnsProgram.methods
+          .createMintAccount({
+            name,
+            symbol,
+            permanentDelegate: null,
+            soulbound: false,
+            uri,
+          })
+          .accountsStrict({
+            payer: groupMintAuthPublicKey,
+            manager,
+            mintTokenAccount: sellerMemberMintTokenAccount,
+            authority: memberMintAuthPublicKey,
+            mint: memberMintPublickey,
+            receiver: memberMintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
+          ])
+          .postInstructions(ixs)
+          .signers([memberMintKeypair, seller])
+          .rpc(preflightConfig);
+      });
+
+      describe("after initializing distribution", () => {
+        it("should have a distribution account", () => {
+          expect(distributionAccountInfo).to.not.be.undefined;
+        });
+
+        it("should be owned by the distribution program", () => {
+          expect(distributionAccountInfo.owner.toBase58()).to.eql(
+            wenDistributionProgramId.toBase58(),
+          );
+        });
+
+        it("should point the correct group mint account address", () => {
+          expect(
+            (distributionAccountData.groupMint as PublicKey).toBase58(),
+          ).to.eql(groupMintPublicKey.toBase58());
+        });
+
+        it("should point the correct payment mint account address", () => {
+          expect(
+            (distributionAccountData.paymentMint as PublicKey).toBase58(),
+          ).to.eql(paymentMintPublickey.toBase58());
+        });
+      });
+
+      describe("after listing for sale", () => {
+        let listingAccountInfo: AccountInfo<Buffer>;
+        let sellerTokenAccountData: Account;
+        let listingAccountData;
+
+        before(async () => {
+          await wenWnsMarketplace.methods
+            .list({
+              listingAmount,
+              paymentMint: paymentMintPublickey,
+            })
+            .accountsStrict({
+              listing,
+              config: marketplaceConfig,
+              manager,
+              payer: wallet.publicKey,
+              seller: seller.publicKey,
+              mint: memberMintPublickey,
+              sellerTokenAccount: sellerMemberMintTokenAccount,
+              systemProgram: SystemProgram.programId,
+              wnsProgram: wnsProgramId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([seller])
+            .rpc({
+              skipPreflight: true,
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+
+          listingAccountInfo = await connection.getAccountInfo(
+            listing,
+            "confirmed",
+          );
+          listingAccountData = wenWnsMarketplace.coder.accounts.decode(
+            "listing",
+            listingAccountInfo.data,
+          );
+          sellerTokenAccountData = await getAccount(
+            connection,
+            sellerMemberMintTokenAccount,
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID,
+          );
+        });
+
+        it("should have a listing account", () => {
+          expect(listingAccountData).to.not.be.undefined;
+        });
+
+        it("should be owned by sale program", () => {
+          expect(listingAccountInfo.owner).to.eql(wenWnsMarketplaceId);
+        });
+
+        it("should point the listing account as delegate of NFT", () => {
+          expect(sellerTokenAccountData.delegate.toBase58()).to.eql(
+            listing.toBase58(),
+          );
+        });
+
+        it("should freeze the NFT", () => {
+          expect(sellerTokenAccountData.isFrozen).to.be.true;
+        });
+      });
+
+      describe("after a sale", () => {
+        const buyerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+          paymentMintPublickey,
+          buyer.publicKey,
+          false,
+          TOKEN_PROGRAM_ID,
+        );
+
+        const sellerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+          paymentMintPublickey,
+          seller.publicKey,
+          false,
+          TOKEN_PROGRAM_ID,
+        );
+
+        const distributionPaymentMintTokenAccount =
+          getAssociatedTokenAddressSync(
+            paymentMintPublickey,
+            distribution,
+            true,
+            TOKEN_PROGRAM_ID,
+          );
+
+        let sellerPreBalance: number;
+        let buyerPreBalance: number;
+        let distributionPostBalance: number;
+        let sellerPostBalance: number;
+        let buyerPostBalance: number;
+
+        let sellerTokenAccountData: Account;
+        let buyerTokenAccountData: Account;
+
+        before(async () => {
+          buyerPreBalance = parseInt(
+            (
+              await getAccount(
+                connection,
+                buyerPaymentMintTokenAccount,
+                "confirmed",
+                TOKEN_PROGRAM_ID,
+              )
+            ).amount.toString(),
+          );
+          sellerPreBalance = parseInt(
+            (
+              await getAccount(
+                connection,
+                sellerPaymentMintTokenAccount,
+                "confirmed",
+                TOKEN_PROGRAM_ID,
+              )
+            ).amount.toString(),
+          );
+
+          await wenWnsMarketplace.methods
+            .buy({
+              buyAmount: listingAmount,
+              groupPathLen: 0,
+            })
+            .accountsStrict({
+              approveAccount,
+              extraMetasAccount,
+              royaltyConfig,
+              member,
+              groupRoyaltyConfig,
+              distribution,
+              manager,
+              listing,
+              config: marketplaceConfig,
+              payer: wallet.publicKey,
+              buyer: buyer.publicKey,
+              seller: seller.publicKey,
+              buyerPaymentTokenAccount: buyerPaymentMintTokenAccount,
+              sellerPaymentTokenAccount: sellerPaymentMintTokenAccount,
+              distributionPaymentTokenAccount:
+                distributionPaymentMintTokenAccount,
+              treasury: null,
+              treasuryPaymentTokenAccount: null,
+              mint: memberMintPublickey,
+              paymentMint: paymentMintPublickey,
+              buyerTokenAccount: buyerMemberMintTokenAccount,
+              sellerTokenAccount: sellerMemberMintTokenAccount,
+              wnsProgram: wnsProgramId,
+              distributionProgram: wenDistributionProgramId,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              paymentTokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .preInstructions([
+              ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
+              createAssociatedTokenAccountInstruction(
+                wallet.publicKey,
+                distributionPaymentMintTokenAccount,
+                distribution,
+                paymentMintPublickey,
+                TOKEN_PROGRAM_ID,
+              ),
+            ])
+            .signers([buyer])
+            .rpc({
+              skipPreflight: true,
+              preflightCommitment: "confirmed",
+              commitment: "confirmed",
+            });
+
+          distributionPostBalance = parseInt(
+            (
+              await getAccount(
+                connection,
+                distributionPaymentMintTokenAccount,
+                "confirmed",
+                TOKEN_PROGRAM_ID,
+              )
+            ).amount.toString(),
+          );
+          buyerPostBalance = parseInt(
+            (
+              await getAccount(
+                connection,
+                buyerPaymentMintTokenAccount,
+                "confirmed",
+                TOKEN_PROGRAM_ID,
+              )
+            ).amount.toString(),
+          );
+          sellerPostBalance =
+            parseInt(
+              (
+                await getAccount(
+                  connection,
+                  sellerPaymentMintTokenAccount,
+                  "confirmed",
+                  TOKEN_PROGRAM_ID,
+                )
+              ).amount.toString(),
+            ) - sellerPreBalance;
+
+          sellerTokenAccountData = await getAccount(
+            connection,
+            sellerMemberMintTokenAccount,
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID,
+          );
+          buyerTokenAccountData = await getAccount(
+            connection,
+            buyerMemberMintTokenAccount,
+            "confirmed",
+            TOKEN_2022_PROGRAM_ID,
+          );
+        });
+
+        describe("royalties", () => {
+          it("should be sent to the distribution vault", () => {
+            expect(distributionPostBalance).to.eql(royalty.toNumber());
+          });
+        });
+
+        describe("the seller", () => {
+          it("receive the payment minus royalties", () => {
+            expect(sellerPostBalance).to.eql(
+              listingAmount.sub(royalty).toNumber(),
+            );
+          });
+          it("should not be the owner anymore", () => {
+            expect(sellerTokenAccountData.amount.toString()).to.eql("0");
+          });
+        });
+
+        describe("the buyer", () => {
+          it("sent the payment", () => {
+            console.log;
+            expect(buyerPostBalance).to.eql(
+              buyerPreBalance - listingAmount.toNumber(),
+            );
+          });
+          it("should be the owner", () => {
+            expect(buyerTokenAccountData.amount.toString()).to.eql("1");
+          });
+        });
+      });
+
+      describe("after claiming", () => {
+        const distributionPaymentMintTokenAccount =
+          getAssociatedTokenAddressSync(
+            paymentMintPublickey,
+            distribution,
+            true,
+            TOKEN_PROGRAM_ID,
+          );
+
+        describe("creator 1", () => {
+          const creator1PaymentMintTokenAccount = getAssociatedTokenAddressSync(
+            paymentMintPublickey,
+            creator1.publicKey,
+            true,
+            TOKEN_PROGRAM_ID,
+          );
+
+          let creator1PostBalance: number;
+          const expectedCreatorShare = royalty
+            .mul(new anchor.BN(creator1ShareBps))
+            .div(new anchor.BN(10_000));
+
+          before(async () => {
+            await wenWnsMarketplace.methods
+              .claimRoyalty()
+              .accountsStrict({
+                payer: wallet.publicKey,
+                creator: creator1.publicKey,
+                distribution,
+                paymentMint: paymentMintPublickey,
+                creatorPaymentTokenAccount: creator1PaymentMintTokenAccount,
+                distributionPaymentTokenAccount:
+                  distributionPaymentMintTokenAccount,
+                wenDistributionProgram: wenDistributionProgramId,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+              })
+              .preInstructions([
+                createAssociatedTokenAccountInstruction(
+                  wallet.publicKey,
+                  creator1PaymentMintTokenAccount,
+                  creator1.publicKey,
+                  paymentMintPublickey,
+                  TOKEN_PROGRAM_ID,
+                ),
+              ])
+              .signers([creator1])
+              .rpc({
+                skipPreflight: true,
+                preflightCommitment: "confirmed",
+                commitment: "confirmed",
+              });
+
+            creator1PostBalance = parseInt(
+              (
+                await getAccount(
+                  connection,
+                  creator1PaymentMintTokenAccount,
+                  "confirmed",
+                  TOKEN_PROGRAM_ID,
+                )
+              ).amount.toString(),
+            );
+          });
+
+          it("should receive correct royalty funds", () => {
+            expect(creator1PostBalance).to.eql(expectedCreatorShare.toNumber());
+          });
+        });
+
+        describe("creator 2", () => {
+          const creator2PaymentMintTokenAccount = getAssociatedTokenAddressSync(
+            paymentMintPublickey,
+            creator2.publicKey,
+            true,
+            TOKEN_PROGRAM_ID,
+          );
+
+          let creator2PostBalance: number;
+          const expectedCreatorShare = royalty
+            .mul(new anchor.BN(creator2ShareBps))
+            .div(new anchor.BN(10_000));
+
+          before(async () => {
+            await wenWnsMarketplace.methods
+              .claimRoyalty()
+              .accountsStrict({
+                payer: wallet.publicKey,
+                creator: creator2.publicKey,
+                distribution,
+                paymentMint: paymentMintPublickey,
+                creatorPaymentTokenAccount: creator2PaymentMintTokenAccount,
+                distributionPaymentTokenAccount:
+                  distributionPaymentMintTokenAccount,
+                wenDistributionProgram: wenDistributionProgramId,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+              })
+              .preInstructions([
+                createAssociatedTokenAccountInstruction(
+                  wallet.publicKey,
+                  creator2PaymentMintTokenAccount,
+                  creator2.publicKey,
+                  paymentMintPublickey,
+                  TOKEN_PROGRAM_ID,
+                ),
+              ])
+              .signers([creator2])
+              .rpc({
+                skipPreflight: true,
+                preflightCommitment: "confirmed",
+                commitment: "confirmed",
+              });
+
+            creator2PostBalance = parseInt(
+              (
+                await getAccount(
+                  connection,
+                  creator2PaymentMintTokenAccount,
+                  "confirmed",
+                  TOKEN_PROGRAM_ID,
+                )
+              ).amount.toString(),
+            );
+          });
+
+          it("should receive correct royalty funds", () => {
+            expect(creator2PostBalance).to.eql(expectedCreatorShare.toNumber());
+          });
+        });
+      });
+    });
+
+    describe("using a Token-2022 payment mint with a transfer fee", () => {
+      const name = faker.lorem.words({ max: 3, min: 2 });
+      const symbol = faker.lorem.word();
+      const uri = faker.internet.url();
+
+      const groupMintKeypair = Keypair.generate();
+      const memberMintKeypair = Keypair.generate();
+      const paymentMintKeypair = Keypair.generate();
+
+      const groupMintPublicKey = groupMintKeypair.publicKey;
+      const memberMintPublickey = memberMintKeypair.publicKey;
+      const paymentMintPublickey = paymentMintKeypair.publicKey;
+
+      const groupMintAuthPublicKey = wallet.publicKey;
+      const memberMintAuthPublicKey = seller.publicKey;
+      const paymentMintAuthPublicKey = wallet.publicKey;
+
+      const groupMintTokenAccount = getAssociatedTokenAddressSync(
+        groupMintPublicKey,
+        groupMintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const sellerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        memberMintAuthPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const buyerMemberMintTokenAccount = getAssociatedTokenAddressSync(
+        memberMintPublickey,
+        buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const sellerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+        paymentMintPublickey,
+        seller.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      const buyerPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+        paymentMintPublickey,
+        buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const group = getGroupAccountPda(groupMintPublicKey, wnsProgramId);
+      const distribution = getDistributionAccountPda(
+        groupMintPublicKey,
+        paymentMintPublickey,
+        wenDistributionProgramId,
+      );
+      const distributionPaymentMintTokenAccount = getAssociatedTokenAddressSync(
+        paymentMintPublickey,
+        distribution,
+        true,
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+      const listing = getListingAccountPda(
+        seller.publicKey,
+        memberMintPublickey,
+        wenWnsMarketplaceId,
+      );
+      const member = getMemberAccountPda(memberMintPublickey, wnsProgramId);
+      const extraMetasAccount = getExtraMetasAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const approveAccount = getApproveAccountPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const royaltyConfig = getRoyaltyConfigPda(
+        memberMintPublickey,
+        wnsProgramId,
+      );
+      const groupRoyaltyConfig = getRoyaltyConfigPda(
+        groupMintPublicKey,
+        wnsProgramId,
+      );
+
+      const transferFeeBasisPoints = 100;
+      const listingAmount = new anchor.BN(500 * 10 ** 6);
+      const royaltyBasisPoints = 1000;
+      const royalty = listingAmount
+        .mul(new anchor.BN(royaltyBasisPoints))
+        .div(new anchor.BN(10_000));
+      const creator1ShareBps = 6000;
+      const creator2ShareBps = 4000;
+
+      let royaltyTransferFee: number;
+
+      before(async () => {
+        const { ixs: createMintIxs } = await createTransferFeeMint2022Ix(
+          connection,
+          paymentMintPublickey,
+          paymentMintAuthPublicKey,
+          paymentMintAuthPublicKey,
+          transferFeeBasisPoints,
+          BigInt(10_000 * 10 ** 6),
+        );
+        const { ixs: mintToIxs } = mintToBuyerSellerIx(
+          paymentMintPublickey,
+          paymentMintAuthPublicKey,
+          paymentMintAuthPublicKey,
+          buyer.publicKey,
+          buyerPaymentMintTokenAccount,
+          seller.publicKey,
+          sellerPaymentMintTokenAccount,
+          TOKEN_2022_PROGRAM_ID,
+        );
+
+        await sendAndConfirmWNSTransaction(
+          connection,
+          [...createMintIxs, ...mintToIxs],
+          provider,
+          true,
+          [paymentMintKeypair],
+        );
+
+        const paymentMint = await getMint(
+          connection,
+          paymentMintPublickey,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID,
+        );
+        royaltyTransferFee = Number(
+          calculateFee(
+            getTransferFeeConfig(paymentMint).newerTransferFee,
+            BigInt(royalty.toString()),
+          ),
+        );
+      });
+
+      before(async () => {
+        // CREATE GROUP ACCOUNT AND DISTRIBUTION ACCOUNT
+        await wnsProgram.methods
+          .createGroupAccount({
+            maxSize: 1,
+            lockMaxSize: false,
+            advisoryMemberUpdateGate: false,
+            name,
+            symbol,
+            uri,
+          })
+          .accountsStrict({
+            authority: groupMintAuthPublicKey,
+            group,
+            manager,
+            mint: groupMintPublicKey,
+            mintTokenAccount: groupMintTokenAccount,
+            payer: groupMintAuthPublicKey,
+            receiver: groupMintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([groupMintKeypair])
+          .rpc(preflightConfig);
+
+        await wenDistributionProgram.methods
+          .initializeDistribution(paymentMintPublickey)
+          .accountsStrict({
+            payer: groupMintAuthPublicKey,
+            groupMint: groupMintPublicKey,
+            distributionAccount: distribution,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(preflightConfig);
+      });
+
+      before(async () => {
+        // CREATE MINT ACCOUNT, ADD MINT TO GROUP, ADD ROYALTIES
+        const ixs = await Promise.all([
+          wnsProgram.methods
+            .addMintToGroup()
+            .accountsStrict({
+              authority: groupMintAuthPublicKey,
+              mint: memberMintPublickey,
+              subGroup: null,
+              assetAuthority: memberMintAuthPublicKey,
+              assetTokenAccount: null,
+              payer: groupMintAuthPublicKey,
+              group,
+              manager,
+              member,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(
+              getGroupAncestry([groupMintPublicKey], wnsProgramId),
+            )
+            .instruction(),
+          wnsProgram.methods
+            .addRoyalties({
+              creators: [
+                {
+                  address: creator1.publicKey,
+                  share: creator1ShareBps,
+                },
+                { address: creator2.publicKey, share: creator2ShareBps },
+              ],
+              royaltyBasisPoints,
+            })
+            .accountsStrict({
+              extraMetasAccount,
+              royaltyConfig,
+              authority: memberMintAuthPublicKey,
+              mint: memberMintPublickey,
+              payer: groupMintAuthPublicKey,
+              manager,
+              systemProgram: SystemProgram.programId,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .instruction(),
+        ]);
+
+        await wnsProgram.methods
+          .createMintAccount({
+            name,
+            symbol,
+            permanentDelegate: null,
+            soulbound: false,
+            uri,
+          })
+          .accountsStrict({
+            payer: groupMintAuthPublicKey,
+            manager,
+            mintTokenAccount: sellerMemberMintTokenAccount,
+            authority: memberMintAuthPublicKey,
+            mint: memberMintPublickey,
+            receiver: memberMintAuthPublicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
+          ])
+          .postInstructions(ixs)
+          .signers([memberMintKeypair, seller])
+          .rpc(preflightConfig);
+
+        // LIST FOR SALE
+        await wenWnsMarketplace.methods
+          .list({
+            listingAmount,
+            paymentMint: paymentMintPublickey,
+          })
+          .accountsStrict({
+            listing,
+            config: marketplaceConfig,
+            manager,
+            payer: wallet.publicKey,
+            seller: seller.publicKey,
+            mint: memberMintPublickey,
+            sellerTokenAccount: sellerMemberMintTokenAccount,
+            systemProgram: SystemProgram.programId,
+            wnsProgram: wnsProgramId,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([seller])
+          .rpc(preflightConfig);
+      });
+
+      describe("after a sale", () => {
+        let distributionPostBalance: number;
+        let distributionAccountData;
+
+        before(async () => {
+          await wenWnsMarketplace.methods
+            .buy({
+              buyAmount: listingAmount,
+              groupPathLen: 0,
+            })
+            .accountsStrict({
+              approveAccount,
+              extraMetasAccount,
+              royaltyConfig,
+              member,
+              groupRoyaltyConfig,
               distribution,
               manager,
               listing,
+              config: marketplaceConfig,
               payer: wallet.publicKey,
               buyer: buyer.publicKey,
               seller: seller.publicKey,
@@ -963,6 +3306,8 @@ describe("wen_royalty_distribution", () => {
               sellerPaymentTokenAccount: sellerPaymentMintTokenAccount,
               distributionPaymentTokenAccount:
                 distributionPaymentMintTokenAccount,
+              treasury: null,
+              treasuryPaymentTokenAccount: null,
               mint: memberMintPublickey,
               paymentMint: paymentMintPublickey,
               buyerTokenAccount: buyerMemberMintTokenAccount,
@@ -971,7 +3316,7 @@ describe("wen_royalty_distribution", () => {
               distributionProgram: wenDistributionProgramId,
               associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
-              paymentTokenProgram: TOKEN_PROGRAM_ID,
+              paymentTokenProgram: TOKEN_2022_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
             })
             .preInstructions([
@@ -981,15 +3326,11 @@ describe("wen_royalty_distribution", () => {
                 distributionPaymentMintTokenAccount,
                 distribution,
                 paymentMintPublickey,
-                TOKEN_PROGRAM_ID,
+                TOKEN_2022_PROGRAM_ID,
               ),
             ])
             .signers([buyer])
-            .rpc({
-              skipPreflight: true,
-              preflightCommitment: "confirmed",
-              commitment: "confirmed",
-            });
+            .rpc(preflightConfig);
 
           distributionPostBalance = parseInt(
             (
@@ -997,207 +3338,29 @@ describe("wen_royalty_distribution", () => {
                 connection,
                 distributionPaymentMintTokenAccount,
                 "confirmed",
-                TOKEN_PROGRAM_ID,
-              )
-            ).amount.toString(),
-          );
-          buyerPostBalance = parseInt(
-            (
-              await getAccount(
-                connection,
-                buyerPaymentMintTokenAccount,
-                "confirmed",
-                TOKEN_PROGRAM_ID,
+                TOKEN_2022_PROGRAM_ID,
               )
             ).amount.toString(),
           );
-          sellerPostBalance =
-            parseInt(
-              (
-                await getAccount(
-                  connection,
-                  sellerPaymentMintTokenAccount,
-                  "confirmed",
-                  TOKEN_PROGRAM_ID,
-                )
-              ).amount.toString(),
-            ) - sellerPreBalance;
-
-          sellerTokenAccountData = await getAccount(
-            connection,
-            sellerMemberMintTokenAccount,
-            "confirmed",
-            TOKEN_2022_PROGRAM_ID,
-          );
-          buyerTokenAccountData = await getAccount(
-            connection,
-            buyerMemberMintTokenAccount,
-            "confirmed",
-            TOKEN_2022_PROGRAM_ID,
-          );
-        });
-
-        describe("royalties", () => {
-          it("should be sent to the distribution vault", () => {
-            expect(distributionPostBalance).to.eql(royalty.toNumber());
-          });
-        });
-
-        describe("the seller", () => {
-          it("receive the payment minus royalties", () => {
-            expect(sellerPostBalance).to.eql(
-              listingAmount.sub(royalty).toNumber(),
-            );
-          });
-          it("should not be the owner anymore", () => {
-            expect(sellerTokenAccountData.amount.toString()).to.eql("0");
-          });
-        });
-
-        describe("the buyer", () => {
-          it("sent the payment", () => {
-            console.log;
-            expect(buyerPostBalance).to.eql(
-              buyerPreBalance - listingAmount.toNumber(),
+          distributionAccountData =
+            await wenDistributionProgram.account.distributionAccount.fetch(
+              distribution,
+              "confirmed",
             );
-          });
-          it("should be the owner", () => {
-            expect(buyerTokenAccountData.amount.toString()).to.eql("1");
-          });
         });
-      });
-
-      describe("after claiming", () => {
-        const distributionPaymentMintTokenAccount =
-          getAssociatedTokenAddressSync(
-            paymentMintPublickey,
-            distribution,
-            true,
-            TOKEN_PROGRAM_ID,
-          );
 
-        describe("creator 1", () => {
-          const creator1PaymentMintTokenAccount = getAssociatedTokenAddressSync(
-            paymentMintPublickey,
-            creator1.publicKey,
-            true,
-            TOKEN_PROGRAM_ID,
+        it("should send the royalties minus the transfer fee to the vault", () => {
+          expect(distributionPostBalance).to.eql(
+            royalty.toNumber() - royaltyTransferFee,
           );
-
-          let creator1PostBalance: number;
-          const expectedCreatorShare = royalty
-            .mul(new anchor.BN(creator1SharePct))
-            .div(new anchor.BN(100));
-
-          before(async () => {
-            await wenWnsMarketplace.methods
-              .claimRoyalty()
-              .accountsStrict({
-                payer: wallet.publicKey,
-                creator: creator1.publicKey,
-                distribution,
-                paymentMint: paymentMintPublickey,
-                creatorPaymentTokenAccount: creator1PaymentMintTokenAccount,
-                distributionPaymentTokenAccount:
-                  distributionPaymentMintTokenAccount,
-                wenDistributionProgram: wenDistributionProgramId,
-                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                systemProgram: SystemProgram.programId,
-              })
-              .preInstructions([
-                createAssociatedTokenAccountInstruction(
-                  wallet.publicKey,
-                  creator1PaymentMintTokenAccount,
-                  creator1.publicKey,
-                  paymentMintPublickey,
-                  TOKEN_PROGRAM_ID,
-                ),
-              ])
-              .signers([creator1])
-              .rpc({
-                skipPreflight: true,
-                preflightCommitment: "confirmed",
-                commitment: "confirmed",
-              });
-
-            creator1PostBalance = parseInt(
-              (
-                await getAccount(
-                  connection,
-                  creator1PaymentMintTokenAccount,
-                  "confirmed",
-                  TOKEN_PROGRAM_ID,
-                )
-              ).amount.toString(),
-            );
-          });
-
-          it("should receive correct royalty funds", () => {
-            expect(creator1PostBalance).to.eql(expectedCreatorShare.toNumber());
-          });
         });
 
-        describe("creator 2", () => {
-          const creator2PaymentMintTokenAccount = getAssociatedTokenAddressSync(
-            paymentMintPublickey,
-            creator2.publicKey,
-            true,
-            TOKEN_PROGRAM_ID,
+        it("should only credit creators with the amount received", () => {
+          const credited = distributionAccountData.claimData.reduce(
+            (total, creator) => total.add(creator.claimAmount),
+            distributionAccountData.dustAmount,
           );
-
-          let creator2PostBalance: number;
-          const expectedCreatorShare = royalty
-            .mul(new anchor.BN(creator2SharePct))
-            .div(new anchor.BN(100));
-
-          before(async () => {
-            await wenWnsMarketplace.methods
-              .claimRoyalty()
-              .accountsStrict({
-                payer: wallet.publicKey,
-                creator: creator2.publicKey,
-                distribution,
-                paymentMint: paymentMintPublickey,
-                creatorPaymentTokenAccount: creator2PaymentMintTokenAccount,
-                distributionPaymentTokenAccount:
-                  distributionPaymentMintTokenAccount,
-                wenDistributionProgram: wenDistributionProgramId,
-                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                systemProgram: SystemProgram.programId,
-              })
-              .preInstructions([
-                createAssociatedTokenAccountInstruction(
-                  wallet.publicKey,
-                  creator2PaymentMintTokenAccount,
-                  creator2.publicKey,
-                  paymentMintPublickey,
-                  TOKEN_PROGRAM_ID,
-                ),
-              ])
-              .signers([creator2])
-              .rpc({
-                skipPreflight: true,
-                preflightCommitment: "confirmed",
-                commitment: "confirmed",
-              });
-
-            creator2PostBalance = parseInt(
-              (
-                await getAccount(
-                  connection,
-                  creator2PaymentMintTokenAccount,
-                  "confirmed",
-                  TOKEN_PROGRAM_ID,
-                )
-              ).amount.toString(),
-            );
-          });
-
-          it("should receive correct royalty funds", () => {
-            expect(creator2PostBalance).to.eql(expectedCreatorShare.toNumber());
-          });
+          expect(credited.toNumber()).to.eql(distributionPostBalance);
         });
       });
     });
@@ -7,6 +7,7 @@ use super::get::{run as get_mint_account, GetArgs};
 use super::metadata::{metadata_subcommand, MetadataSubCommand};
 use super::royalty::{royalty_subcommand, RoyaltySubCommand};
 use super::thaw::{run as thaw_mint_account, ThawArgs};
+use super::update::{run as update_mint_account, UpdateArgs};
 
 use anyhow::Result;
 use clap::{Args, Subcommand};
@@ -22,6 +23,9 @@ pub enum Commands {
     #[clap(name = "create")]
     /// Create a new asset
     Create(CreateArgs),
+    #[clap(name = "update")]
+    /// Update an asset
+    Update(UpdateArgs),
     #[clap(name = "get")]
     /// Fetch an asset
     Get(GetArgs),
@@ -47,6 +51,9 @@ pub async fn subcommand(context: Context, subcommand: AssetSubcommand) -> Result
         Commands::Create(args) => {
             create_mint_account(context, args).await?;
         }
+        Commands::Update(args) => {
+            update_mint_account(context, args).await?;
+        }
         Commands::Get(args) => {
             get_mint_account(context, args).await?;
         }
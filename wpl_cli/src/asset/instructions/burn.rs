@@ -9,9 +9,19 @@ use solana_sdk::{
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
-use wen_new_standard::instructions::BurnMintAccount;
+use wen_new_standard::{
+    accounts::{TokenGroup, TokenGroupMember},
+    instructions::BurnMintAccount,
+};
 
-use crate::{utils::derive_manager_account, Context};
+use crate::{
+    utils::{
+        derive_approve_account, derive_extra_metas_account, derive_manager_account,
+        derive_member_account, derive_royalty_config_account, get_group_ancestry,
+        get_sub_group_account,
+    },
+    Context,
+};
 
 #[derive(Debug, Parser, Clone)]
 pub struct BurnArgs {
@@ -35,16 +45,43 @@ pub async fn run(context: Context, args: BurnArgs) -> Result<()> {
     );
     let manager = derive_manager_account();
 
+    // close every wns account tied to the asset along with the mint
+    let member_pubkey = derive_member_account(&mint_pubkey);
+    let member_data = client
+        .get_account_data(&member_pubkey)
+        .await
+        .unwrap_or(vec![]);
+    let (group, member, ancestry) = if !member_data.is_empty() {
+        let member = TokenGroupMember::from_bytes(&member_data)?;
+        let group_data = client.get_account_data(&member.group).await?;
+        let group_mint = TokenGroup::from_bytes(&group_data)?.mint;
+        let ancestry = get_group_ancestry(&client, &group_mint).await?;
+        (Some(member.group), Some(member_pubkey), ancestry)
+    } else {
+        (None, None, vec![])
+    };
+
+    let sub_group = get_sub_group_account(&client, &mint_pubkey).await;
+    let approve_account = derive_approve_account(&mint_pubkey);
+    let extra_metas_account = derive_extra_metas_account(&mint_pubkey);
+    let royalty_config = derive_royalty_config_account(&mint_pubkey);
+
     let burn_mint_account = BurnMintAccount {
         user: keypair_pubkey,
         payer: keypair_pubkey,
         manager,
         mint: mint_pubkey,
         mint_token_account,
+        group,
+        member,
+        sub_group,
+        approve_account,
+        extra_metas_account,
+        royalty_config,
         token_program: TOKEN_2022_PROGRAM_ID,
     };
 
-    let burn_mint_account_ix = burn_mint_account.instruction();
+    let burn_mint_account_ix = burn_mint_account.instruction_with_remaining_accounts(&ancestry);
 
     let transaction_message = VersionedMessage::V0(TransactionMessage::try_compile(
         &payer,
@@ -34,6 +34,9 @@ pub struct CreateArgs {
     /// Optional permanent delegate
     #[arg(short, long, value_parser = clap::value_parser!(Pubkey))]
     pub permanent_delegate: Option<Pubkey>,
+    /// Non transferable asset, e.g. credentials and tickets
+    #[arg(long, default_value_t = false)]
+    pub soulbound: bool,
 }
 
 pub async fn run(context: Context, args: CreateArgs) -> Result<()> {
@@ -76,6 +79,7 @@ pub async fn run(context: Context, args: CreateArgs) -> Result<()> {
             symbol: args.symbol,
             uri: args.uri,
             permanent_delegate: args.permanent_delegate,
+            soulbound: args.soulbound,
         });
 
     let transaction_message = VersionedMessage::V0(TransactionMessage::try_compile(
@@ -7,7 +7,7 @@ use spl_token_2022::{
     state::Mint,
 };
 use spl_token_metadata_interface::state::TokenMetadata;
-use wen_new_standard::accounts::TokenGroupMember;
+use wen_new_standard::accounts::{TokenGroup, TokenGroupMember};
 
 use crate::{utils::derive_member_account, Context};
 
@@ -40,6 +40,32 @@ pub async fn run(context: Context, args: GetArgs) -> Result<()> {
         let member = TokenGroupMember::from_bytes(&member_data)?;
         log::info!("Asset under collection PDA: {:?}", member.group.to_string());
         log::info!("Asset member count: {:?}", member.member_number);
+
+        // walk up the member accounts of the collection mints for nested collections
+        log::info!("Asset ancestry ------");
+        let mut group = member.group;
+        loop {
+            let group_data = client.get_account_data(&group).await.unwrap_or(vec![]);
+            if group_data.is_empty() {
+                log::info!("(Collection PDA) {:?}", group.to_string());
+                break;
+            }
+            let group_mint = TokenGroup::from_bytes(&group_data)?.mint;
+            log::info!(
+                "(Collection) {:?} -- (Collection PDA) {:?}",
+                group_mint.to_string(),
+                group.to_string()
+            );
+
+            let group_member_data = client
+                .get_account_data(&derive_member_account(&group_mint))
+                .await
+                .unwrap_or(vec![]);
+            if group_member_data.is_empty() {
+                break;
+            }
+            group = TokenGroupMember::from_bytes(&group_member_data)?.group;
+        }
     }
     log::info!("Asset name: {:?}", metadata.name);
     log::info!("Asset symbol: {:?}", metadata.symbol);
@@ -11,6 +11,7 @@ use wen_new_standard::instructions::{RemoveMetadata, RemoveMetadataInstructionAr
 
 use crate::{
     asset::{parse_remove_metadata_pairs, MetadataArgs},
+    utils::derive_manager_account,
     Context,
 };
 
@@ -27,6 +28,7 @@ pub async fn run(context: Context, args: MetadataArgs) -> Result<()> {
         authority: keypair_pubkey,
         mint: mint_pubkey,
         token_program: TOKEN_2022_PROGRAM_ID,
+        manager: derive_manager_account(),
         system_program: SYSTEM_PROGRAM_ID,
     };
 
@@ -5,6 +5,7 @@ pub mod get;
 pub mod metadata;
 pub mod royalty;
 pub mod thaw;
+pub mod update;
 
 pub use burn::*;
 pub use create::*;
@@ -13,3 +14,4 @@ pub use get::*;
 pub use metadata::*;
 pub use royalty::*;
 pub use thaw::*;
+pub use update::*;
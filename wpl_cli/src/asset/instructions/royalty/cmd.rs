@@ -1,12 +1,13 @@
 use crate::Context;
 
 use super::add::run as add_royalties;
+use super::inherit::run as inherit_royalties;
 use super::modify::run as modify_royalties;
 
 use anyhow::Result;
 use clap::{Args, Subcommand};
 
-use super::data::RoyaltyArgs;
+use super::data::{InheritRoyaltyArgs, RoyaltyArgs};
 
 #[derive(Debug, Clone, Args)]
 pub struct RoyaltySubCommand {
@@ -21,6 +22,8 @@ pub enum Commands {
     Add(RoyaltyArgs),
     /// Modify royalties for an asset
     Modify(RoyaltyArgs),
+    /// Enforce the royalties an asset inherits from its collection
+    Inherit(InheritRoyaltyArgs),
 }
 
 pub async fn subcommand(context: Context, subcommand: RoyaltySubCommand) -> Result<()> {
@@ -31,6 +34,9 @@ pub async fn subcommand(context: Context, subcommand: RoyaltySubCommand) -> Resu
         Commands::Modify(args) => {
             modify_royalties(context, args).await?;
         }
+        Commands::Inherit(args) => {
+            inherit_royalties(context, args).await?;
+        }
     }
 
     Ok(())
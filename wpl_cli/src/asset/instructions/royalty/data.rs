@@ -19,6 +19,16 @@ pub struct RoyaltyArgs {
     pub config_path: PathBuf,
 }
 
+#[derive(Debug, Parser, Clone)]
+pub struct InheritRoyaltyArgs {
+    /// Asset address
+    #[arg(short, long, value_parser = clap::value_parser!(Pubkey))]
+    pub mint: Pubkey,
+    /// Collection address the royalties are inherited from
+    #[arg(short, long, value_parser = clap::value_parser!(Pubkey))]
+    pub group_mint: Pubkey,
+}
+
 pub fn parse_update_royalties_args(config_path: PathBuf) -> Result<UpdateRoyaltiesArgs> {
     if Path::new(&config_path).exists() {
         let royalty_config = File::open(config_path)?;
@@ -9,7 +9,11 @@ use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use wen_new_standard::instructions::{AddRoyalties, AddRoyaltiesInstructionArgs};
 
 use super::super::RoyaltyArgs;
-use crate::{asset::parse_update_royalties_args, utils::derive_extra_metas_account, Context};
+use crate::{
+    asset::parse_update_royalties_args,
+    utils::{derive_extra_metas_account, derive_manager_account, derive_royalty_config_account},
+    Context,
+};
 
 pub async fn run(context: Context, args: RoyaltyArgs) -> Result<()> {
     let Context { client, keypair } = context;
@@ -20,13 +24,16 @@ pub async fn run(context: Context, args: RoyaltyArgs) -> Result<()> {
     let keypair_pubkey = keypair.pubkey();
 
     let extra_metas_account = derive_extra_metas_account(&mint_pubkey);
+    let royalty_config = derive_royalty_config_account(&mint_pubkey);
     let add_royalties = AddRoyalties {
         payer: keypair_pubkey,
         authority: keypair_pubkey,
         mint: mint_pubkey,
         token_program: TOKEN_2022_PROGRAM_ID,
+        manager: derive_manager_account(),
         system_program: SYSTEM_PROGRAM_ID,
         extra_metas_account,
+        royalty_config,
     };
 
     let update_royalties_args = parse_update_royalties_args(args.config_path)?;
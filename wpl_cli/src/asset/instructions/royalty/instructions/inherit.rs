@@ -0,0 +1,63 @@
+use anyhow::Result;
+use solana_program::system_program::ID as SYSTEM_PROGRAM_ID;
+use solana_sdk::{
+    message::{v0::Message as TransactionMessage, VersionedMessage},
+    signer::Signer,
+    transaction::VersionedTransaction,
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use wen_new_standard::instructions::InheritRoyalties;
+
+use super::super::InheritRoyaltyArgs;
+use crate::{
+    utils::{
+        derive_extra_metas_account, derive_manager_account, derive_member_account,
+        derive_royalty_config_account, get_group_path,
+    },
+    Context,
+};
+
+pub async fn run(context: Context, args: InheritRoyaltyArgs) -> Result<()> {
+    let Context { client, keypair } = context;
+    let payer = keypair.pubkey();
+    let recent_blockhash = client.get_latest_blockhash().await?;
+
+    let mint_pubkey = args.mint;
+    let keypair_pubkey = keypair.pubkey();
+
+    let group_path = get_group_path(&client, &mint_pubkey, &args.group_mint).await?;
+
+    let inherit_royalties = InheritRoyalties {
+        payer: keypair_pubkey,
+        authority: keypair_pubkey,
+        mint: mint_pubkey,
+        member: derive_member_account(&mint_pubkey),
+        group_royalty_config: derive_royalty_config_account(&args.group_mint),
+        extra_metas_account: derive_extra_metas_account(&mint_pubkey),
+        manager: derive_manager_account(),
+        system_program: SYSTEM_PROGRAM_ID,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    let inherit_royalties_ix = inherit_royalties.instruction_with_remaining_accounts(&group_path);
+
+    let transaction_message = VersionedMessage::V0(TransactionMessage::try_compile(
+        &payer,
+        &[inherit_royalties_ix],
+        &[],
+        recent_blockhash,
+    )?);
+
+    let transaction = VersionedTransaction::try_new(transaction_message, &[&keypair])?;
+
+    let signature = client.send_and_confirm_transaction(&transaction).await?;
+
+    log::info!(
+        "Inherited royalties of collection {:?} for asset {:?} successfully! Signature: {:?}",
+        args.group_mint.to_string(),
+        mint_pubkey.to_string(),
+        signature
+    );
+
+    Ok(())
+}
@@ -11,7 +11,11 @@ use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use wen_new_standard::instructions::{ModifyRoyalties, ModifyRoyaltiesInstructionArgs};
 
 use super::super::RoyaltyArgs;
-use crate::{asset::parse_update_royalties_args, Context};
+use crate::{
+    asset::parse_update_royalties_args,
+    utils::{derive_manager_account, derive_royalty_config_account},
+    Context,
+};
 
 pub async fn run(context: Context, args: RoyaltyArgs) -> Result<()> {
     let Context { client, keypair } = context;
@@ -21,11 +25,14 @@ pub async fn run(context: Context, args: RoyaltyArgs) -> Result<()> {
     let mint_pubkey = args.mint;
     let keypair_pubkey = keypair.pubkey();
 
+    let royalty_config = derive_royalty_config_account(&mint_pubkey);
     let modify_royalties = ModifyRoyalties {
         payer: keypair_pubkey,
         authority: keypair_pubkey,
         mint: mint_pubkey,
+        royalty_config,
         token_program: TOKEN_2022_PROGRAM_ID,
+        manager: derive_manager_account(),
         system_program: SYSTEM_PROGRAM_ID,
     };
 
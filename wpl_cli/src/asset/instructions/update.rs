@@ -0,0 +1,80 @@
+use anyhow::Result;
+
+use clap::Parser;
+use solana_program::system_program::ID as SYSTEM_PROGRAM_ID;
+use solana_sdk::{
+    message::{v0::Message as TransactionMessage, VersionedMessage},
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::VersionedTransaction,
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use wen_new_standard::instructions::{UpdateMintAccount, UpdateMintAccountInstructionArgs};
+
+use crate::{
+    utils::{derive_group_account, derive_manager_account, derive_member_account},
+    Context,
+};
+
+#[derive(Debug, Parser, Clone)]
+pub struct UpdateArgs {
+    /// Name of the asset
+    #[arg(short, long)]
+    pub name: String,
+    /// Symbol of the asset
+    #[arg(short, long)]
+    pub symbol: String,
+    /// URI of the asset
+    #[arg(short, long)]
+    pub uri: String,
+    /// Asset address
+    #[arg(short, long, value_parser = clap::value_parser!(Pubkey))]
+    pub mint: Pubkey,
+    /// Collection mint of the asset, the keypair has to be the collection authority as well
+    #[arg(short, long, value_parser = clap::value_parser!(Pubkey))]
+    pub group_mint: Option<Pubkey>,
+}
+
+pub async fn run(context: Context, args: UpdateArgs) -> Result<()> {
+    let Context { client, keypair } = context;
+    let payer = keypair.pubkey();
+    let recent_blockhash = client.get_latest_blockhash().await?;
+
+    let mint_pubkey = args.mint;
+    let keypair_pubkey = keypair.pubkey();
+
+    let update_mint_account = UpdateMintAccount {
+        payer: keypair_pubkey,
+        authority: keypair_pubkey,
+        mint: mint_pubkey,
+        group_mint: args.group_mint,
+        group: args.group_mint.as_ref().map(derive_group_account),
+        member: args.group_mint.map(|_| derive_member_account(&mint_pubkey)),
+        group_authority: args.group_mint.map(|_| keypair_pubkey),
+        manager: derive_manager_account(),
+        system_program: SYSTEM_PROGRAM_ID,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    let update_mint_account_ix =
+        update_mint_account.instruction(UpdateMintAccountInstructionArgs {
+            name: args.name,
+            symbol: args.symbol,
+            uri: args.uri,
+        });
+
+    let transaction_message = VersionedMessage::V0(TransactionMessage::try_compile(
+        &payer,
+        &[update_mint_account_ix],
+        &[],
+        recent_blockhash,
+    )?);
+
+    let transaction = VersionedTransaction::try_new(transaction_message, &[&keypair])?;
+
+    let signature = client.send_and_confirm_transaction(&transaction).await?;
+
+    log::info!("Asset updated successfully! Signature: {:?}", signature);
+
+    Ok(())
+}
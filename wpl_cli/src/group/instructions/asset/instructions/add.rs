@@ -23,6 +23,8 @@ pub async fn run(context: Context, args: AssetArgs) -> Result<()> {
     let group = derive_group_account(&group_mint_pubkey);
     let member = derive_member_account(&member_mint_pubkey);
     let manager = derive_manager_account();
+    let sub_group = get_sub_group_account(&client, &member_mint_pubkey).await;
+    let ancestry = get_group_ancestry(&client, &group_mint_pubkey).await?;
 
     let add_mint_to_group = AddMintToGroup {
         payer: keypair_pubkey,
@@ -31,11 +33,15 @@ pub async fn run(context: Context, args: AssetArgs) -> Result<()> {
         manager,
         member,
         mint: member_mint_pubkey,
+        sub_group,
+        // keypair consents for the asset as its metadata update authority
+        asset_authority: keypair_pubkey,
+        asset_token_account: None,
         token_program: TOKEN_2022_PROGRAM_ID,
         system_program: SYSTEM_PROGRAM_ID,
     };
 
-    let add_mint_to_group_ix = add_mint_to_group.instruction();
+    let add_mint_to_group_ix = add_mint_to_group.instruction_with_remaining_accounts(&ancestry);
 
     let transaction_message = VersionedMessage::V0(TransactionMessage::try_compile(
         &payer,
@@ -32,8 +32,18 @@ pub struct CreateArgs {
     #[arg(short, long)]
     pub uri: String,
     /// Maximum size of the group
-    #[arg(short = 'S', long)]
-    pub size: u32,
+    #[arg(short = 'S', long, required_unless_present = "unbounded")]
+    pub size: Option<u32>,
+    /// Group without a maximum size, e.g. open editions
+    #[arg(long, conflicts_with = "size", default_value_t = false)]
+    pub unbounded: bool,
+    /// Lock the maximum size of the group for good
+    #[arg(short = 'L', long, default_value_t = false)]
+    pub lock_size: bool,
+    /// Require the group update authority to sign along with asset updates through wns, advisory
+    /// only as assets stay updatable through the token extensions program
+    #[arg(short = 'G', long, default_value_t = false)]
+    pub advisory_member_update_gate: bool,
     /// Receiver address of the collection
     #[arg(short = 'R', long, value_parser = clap::value_parser!(Pubkey))]
     pub receiver: Option<Pubkey>,
@@ -80,6 +90,8 @@ pub async fn run(context: Context, args: CreateArgs) -> Result<()> {
         create_group_account.instruction(CreateGroupAccountInstructionArgs {
             name: args.name,
             max_size: args.size,
+            lock_max_size: args.lock_size,
+            advisory_member_update_gate: args.advisory_member_update_gate,
             symbol: args.symbol,
             uri: args.uri,
         });
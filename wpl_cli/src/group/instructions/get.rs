@@ -46,13 +46,28 @@ pub async fn run(context: Context, args: GetArgs) -> Result<()> {
         }
     }
     if group_data.len() > 0 {
+        // collection accounts created before the group flags are shorter, their flags read as unset
+        let mut group_data = group_data;
+        group_data.resize(TokenGroup::LEN, 0);
         let group = TokenGroup::from_bytes(&group_data)?;
         log::info!(
             "Collection update authority: {:?}",
             group.update_authority.to_string()
         );
         log::info!("Collection current size: {:?}", group.size);
-        log::info!("Collection maximum size: {:?}", group.max_size);
+        if group.max_size == u32::MAX {
+            log::info!("Collection maximum size: unbounded");
+        } else {
+            log::info!("Collection maximum size: {:?}", group.max_size);
+        }
+        log::info!(
+            "Collection maximum size locked: {:?}",
+            group.max_size_locked
+        );
+        log::info!(
+            "Collection member updates gated (advisory): {:?}",
+            group.advisory_member_update_gate
+        );
     }
 
     Ok(())
@@ -11,7 +11,10 @@ use solana_sdk::{
 use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use wen_new_standard::instructions::{UpdateGroupAccount, UpdateGroupAccountInstructionArgs};
 
-use crate::{utils::derive_group_account, Context};
+use crate::{
+    utils::{derive_group_account, derive_manager_account},
+    Context,
+};
 
 #[derive(Debug, Parser, Clone)]
 pub struct UpdateArgs {
@@ -25,8 +28,18 @@ pub struct UpdateArgs {
     #[arg(short, long)]
     pub uri: String,
     /// Maximum size of the group
-    #[arg(short = 'S', long)]
-    pub size: u32,
+    #[arg(short = 'S', long, required_unless_present = "unbounded")]
+    pub size: Option<u32>,
+    /// Group without a maximum size, e.g. open editions
+    #[arg(long, conflicts_with = "size", default_value_t = false)]
+    pub unbounded: bool,
+    /// Lock the maximum size of the group for good
+    #[arg(short = 'L', long, default_value_t = false)]
+    pub lock_size: bool,
+    /// Require the group update authority to sign along with asset updates through wns, advisory
+    /// only as assets stay updatable through the token extensions program
+    #[arg(short = 'G', long, default_value_t = false)]
+    pub advisory_member_update_gate: bool,
     /// Group account (collection) mint to be updated
     #[arg(short, long, value_parser = clap::value_parser!(Pubkey))]
     pub mint: Pubkey,
@@ -48,6 +61,7 @@ pub async fn run(context: Context, args: UpdateArgs) -> Result<()> {
         group,
         mint: mint_pubkey,
         token_program: TOKEN_2022_PROGRAM_ID,
+        manager: derive_manager_account(),
         system_program: SYSTEM_PROGRAM_ID,
     };
 
@@ -55,6 +69,8 @@ pub async fn run(context: Context, args: UpdateArgs) -> Result<()> {
         update_group_account.instruction(UpdateGroupAccountInstructionArgs {
             name: args.name,
             max_size: args.size,
+            lock_max_size: args.lock_size,
+            advisory_member_update_gate: args.advisory_member_update_gate,
             symbol: args.symbol,
             uri: args.uri,
         });
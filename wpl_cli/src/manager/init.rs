@@ -1,11 +1,14 @@
 use anyhow::Result;
-use solana_program::system_program::ID as SYSTEM_PROGRAM_ID;
+use solana_program::{
+    bpf_loader_upgradeable::ID as BPF_LOADER_UPGRADEABLE_ID, pubkey::Pubkey,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+};
 use solana_sdk::{
     message::{v0, VersionedMessage},
     signer::Signer,
     transaction::VersionedTransaction,
 };
-use wen_new_standard::instructions::InitManagerAccount;
+use wen_new_standard::{instructions::InitManagerAccount, ID as WEN_NEW_STANDARD_PROGRAM_ID};
 
 use crate::{utils::derive_manager_account, Context};
 
@@ -15,10 +18,18 @@ pub async fn run(context: Context) -> Result<()> {
     let recent_blockhash = client.get_latest_blockhash().await?;
 
     let manager = derive_manager_account();
+    // the keypair has to be the upgrade authority of the program
+    let (program_data, _) = Pubkey::find_program_address(
+        &[WEN_NEW_STANDARD_PROGRAM_ID.as_ref()],
+        &BPF_LOADER_UPGRADEABLE_ID,
+    );
 
     let init_manager_account = InitManagerAccount {
         payer,
+        upgrade_authority: payer,
         manager,
+        program: WEN_NEW_STANDARD_PROGRAM_ID,
+        program_data,
         system_program: SYSTEM_PROGRAM_ID,
     };
 
@@ -4,14 +4,19 @@ use anyhow::{anyhow, Context, Result};
 use bs58::decode;
 use fs::read_to_string as read_path;
 use serde_json::from_str as parse_json_str;
-use solana_sdk::{pubkey::Pubkey, signature::Keypair};
-use wen_new_standard::ID as WEN_NEW_STANDARD_PROGRAM_ID;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Keypair};
+use wen_new_standard::{
+    accounts::{TokenGroup, TokenGroupMember},
+    ID as WEN_NEW_STANDARD_PROGRAM_ID,
+};
 
 pub const MANAGER_SEED: &[u8] = b"manager";
 pub const GROUP_ACCOUNT_SEED: &[u8] = b"group";
 pub const MEMBER_ACCOUNT_SEED: &[u8] = b"member";
 pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
 pub const APPROVE_ACCOUNT_SEED: &[u8] = b"approve-account";
+pub const ROYALTY_CONFIG_SEED: &[u8] = b"royalty-config";
 
 pub fn parse_keypair(keypair_path: &String) -> Result<Keypair> {
     let secret_string: String = read_path(keypair_path).context("Can't find key file")?;
@@ -54,3 +59,96 @@ pub fn derive_extra_metas_account(mint: &Pubkey) -> Pubkey {
     )
     .0
 }
+
+pub fn derive_approve_account(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[APPROVE_ACCOUNT_SEED, mint.as_ref()],
+        &WEN_NEW_STANDARD_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn derive_royalty_config_account(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ROYALTY_CONFIG_SEED, mint.as_ref()],
+        &WEN_NEW_STANDARD_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Ancestry of the collection of `group_mint`, passed along to keep the size of parent collections in
+/// sync: the member account of every collection mint followed by its parent collection, up to the
+/// member account of the top level collection mint
+pub async fn get_group_ancestry(
+    client: &RpcClient,
+    group_mint: &Pubkey,
+) -> Result<Vec<AccountMeta>> {
+    let mut ancestry = vec![];
+    let mut mint = *group_mint;
+    loop {
+        let member_pubkey = derive_member_account(&mint);
+        ancestry.push(AccountMeta::new_readonly(member_pubkey, false));
+        let member_data = client
+            .get_account_data(&member_pubkey)
+            .await
+            .unwrap_or(vec![]);
+        if member_data.is_empty() {
+            return Ok(ancestry);
+        }
+
+        let member = TokenGroupMember::from_bytes(&member_data)?;
+        ancestry.push(AccountMeta::new(member.group, false));
+        let group_data = client
+            .get_account_data(&member.group)
+            .await
+            .unwrap_or(vec![]);
+        if group_data.is_empty() {
+            return Ok(ancestry);
+        }
+
+        mint = TokenGroup::from_bytes(&group_data)?.mint;
+    }
+}
+
+/// Group path of the asset `mint` up to the collection of `group_mint`, passed along to resolve the
+/// royalties of the collection: the collection account, the royalty config of the collection mint and
+/// the member account of the collection mint for every sub collection in between, nearest first
+pub async fn get_group_path(
+    client: &RpcClient,
+    mint: &Pubkey,
+    group_mint: &Pubkey,
+) -> Result<Vec<AccountMeta>> {
+    let group = derive_group_account(group_mint);
+    let mut group_path = vec![];
+    let mut member_pubkey = derive_member_account(mint);
+    loop {
+        let member_data = client
+            .get_account_data(&member_pubkey)
+            .await
+            .unwrap_or(vec![]);
+        if member_data.is_empty() {
+            return Ok(group_path);
+        }
+
+        let parent = TokenGroupMember::from_bytes(&member_data)?.group;
+        if parent == group {
+            return Ok(group_path);
+        }
+
+        let group_data = client.get_account_data(&parent).await?;
+        let parent_mint = TokenGroup::from_bytes(&group_data)?.mint;
+        member_pubkey = derive_member_account(&parent_mint);
+        group_path.push(AccountMeta::new_readonly(parent, false));
+        group_path.push(AccountMeta::new_readonly(
+            derive_royalty_config_account(&parent_mint),
+            false,
+        ));
+        group_path.push(AccountMeta::new_readonly(member_pubkey, false));
+    }
+}
+
+/// Collection account of `mint` when the asset is a collection itself, nested as a sub collection
+pub async fn get_sub_group_account(client: &RpcClient, mint: &Pubkey) -> Option<Pubkey> {
+    let group = derive_group_account(mint);
+    client.get_account(&group).await.ok().map(|_| group)
+}